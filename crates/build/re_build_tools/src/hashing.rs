@@ -1,6 +1,6 @@
 use std::fmt::Write;
+use std::fs;
 use std::path::{Path, PathBuf};
-use std::{fs, io};
 
 use anyhow::Context as _;
 use sha2::{Digest, Sha256};
@@ -17,6 +17,31 @@ fn encode_hex(bytes: &[u8]) -> String {
     s
 }
 
+/// Replaces all `\r\n` line endings with `\n`.
+///
+/// Otherwise the same files would hash differently in Windows and Unix checkouts.
+fn normalize_line_endings(bytes: &[u8]) -> Vec<u8> {
+    let mut normalized = Vec::with_capacity(bytes.len());
+    let mut bytes = bytes.iter().peekable();
+    while let Some(&b) = bytes.next() {
+        if b == b'\r' && bytes.peek() == Some(&&b'\n') {
+            continue;
+        }
+        normalized.push(b);
+    }
+    normalized
+}
+
+/// Feeds the contents of the file at `path` to `hasher`, with normalized line endings.
+///
+/// Panics if the file can't be read.
+fn hash_file_contents(hasher: &mut Sha256, path: &Path) {
+    let contents = fs::read(path)
+        .with_context(|| format!("couldn't read {path:?}"))
+        .unwrap();
+    hasher.update(normalize_line_endings(&contents));
+}
+
 /// Recursively walks the directory at `path` in filename order.
 ///
 /// If `extensions` is specified, only files with the right extensions will be iterated.
@@ -59,6 +84,8 @@ pub fn iter_dir_filtered<'a>(
 /// Given a file path, computes the sha256 hash of its contents and returns an hexadecimal string
 /// for it.
 ///
+/// Line endings are normalized beforehand, so `\r\n` and `\n` hash the same.
+///
 /// This will automatically emit a `rerun-if-changed` clause for the specified file.
 ///
 /// Panics if the file doesn't exist.
@@ -66,12 +93,7 @@ pub fn compute_file_hash(path: impl AsRef<Path>) -> String {
     let mut hasher = Sha256::new();
 
     let path = path.as_ref();
-    let mut file = fs::File::open(path)
-        .with_context(|| format!("couldn't open {path:?}"))
-        .unwrap();
-    io::copy(&mut file, &mut hasher)
-        .with_context(|| format!("couldn't copy from {path:?}"))
-        .unwrap();
+    hash_file_contents(&mut hasher, path);
 
     rerun_if_changed(path);
 
@@ -83,6 +105,8 @@ pub fn compute_file_hash(path: impl AsRef<Path>) -> String {
 ///
 /// This includes files in sub-directories (i.e. it's recursive).
 ///
+/// Line endings are normalized beforehand, so `\r\n` and `\n` hash the same.
+///
 /// This will automatically emit a `rerun-if-changed` clause for all the files that were hashed.
 ///
 /// If `extensions` is specified, only files with the right extensions will be iterated.
@@ -92,12 +116,7 @@ pub fn compute_dir_hash<'a>(path: impl AsRef<Path>, extensions: Option<&'a [&'a
 
     let path = path.as_ref();
     for filepath in iter_dir(path, extensions) {
-        let mut file = fs::File::open(&filepath)
-            .with_context(|| format!("couldn't open {filepath:?}"))
-            .unwrap();
-        io::copy(&mut file, &mut hasher)
-            .with_context(|| format!("couldn't copy from {filepath:?}"))
-            .unwrap();
+        hash_file_contents(&mut hasher, &filepath);
 
         rerun_if_changed(filepath);
     }
@@ -110,6 +129,8 @@ pub fn compute_dir_hash<'a>(path: impl AsRef<Path>, extensions: Option<&'a [&'a
 ///
 /// This includes files in sub-directories (i.e. it's recursive).
 ///
+/// Line endings are normalized beforehand, so `\r\n` and `\n` hash the same.
+///
 /// This will automatically emit a `rerun-if-changed` clause for all the files that were hashed.
 pub fn compute_dir_filtered_hash<'a>(
     path: impl AsRef<Path>,
@@ -119,12 +140,7 @@ pub fn compute_dir_filtered_hash<'a>(
 
     let path = path.as_ref();
     for filepath in iter_dir_filtered(path, custom_filter) {
-        let mut file = fs::File::open(&filepath)
-            .with_context(|| format!("couldn't open {filepath:?}"))
-            .unwrap();
-        io::copy(&mut file, &mut hasher)
-            .with_context(|| format!("couldn't copy from {filepath:?}"))
-            .unwrap();
+        hash_file_contents(&mut hasher, &filepath);
 
         rerun_if_changed(filepath);
     }
@@ -162,11 +178,13 @@ pub fn compute_crate_hash(pkg_name: impl AsRef<str>) -> String {
 
 /// Given a bunch of strings, computes the sha256 hash of their contents (in the order they
 /// were passed in) and returns an hexadecimal string for it.
+///
+/// Line endings are normalized beforehand, so `\r\n` and `\n` hash the same.
 pub fn compute_strings_hash(strs: &[&str]) -> String {
     let mut hasher = Sha256::new();
 
     for s in strs {
-        hasher.update(s);
+        hasher.update(normalize_line_endings(s.as_bytes()));
     }
 
     encode_hex(hasher.finalize().as_slice())
@@ -215,3 +233,45 @@ pub fn read_versioning_hash(path: impl AsRef<Path>) -> Option<String> {
             .find_map(|line| (!line.trim().starts_with('#')).then(|| line.trim().to_owned()))
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_line_endings_dont_affect_hashes() {
+        let unix = "table Foo {\n  x: float;\n}\n";
+        let windows = "table Foo {\r\n  x: float;\r\n}\r\n";
+
+        assert_eq!(
+            compute_strings_hash(&[unix]),
+            compute_strings_hash(&[windows])
+        );
+        assert_ne!(compute_strings_hash(&[unix]), compute_strings_hash(&["\r"]));
+
+        let unix_dir = tempfile::tempdir().unwrap();
+        let windows_dir = tempfile::tempdir().unwrap();
+        fs::write(unix_dir.path().join("foo.fbs"), unix).unwrap();
+        fs::write(windows_dir.path().join("foo.fbs"), windows).unwrap();
+
+        assert_eq!(
+            compute_file_hash(unix_dir.path().join("foo.fbs")),
+            compute_file_hash(windows_dir.path().join("foo.fbs"))
+        );
+        assert_eq!(
+            compute_dir_hash(unix_dir.path(), Some(&["fbs"])),
+            compute_dir_hash(windows_dir.path(), Some(&["fbs"]))
+        );
+        assert_eq!(
+            compute_dir_filtered_hash(unix_dir.path(), |_| true),
+            compute_dir_filtered_hash(windows_dir.path(), |_| true)
+        );
+    }
+
+    #[test]
+    fn test_normalize_line_endings() {
+        assert_eq!(normalize_line_endings(b"a\r\nb\r\n"), b"a\nb\n");
+        assert_eq!(normalize_line_endings(b"a\rb\r"), b"a\rb\r");
+        assert_eq!(normalize_line_endings(b"a\r\r\nb"), b"a\r\nb");
+    }
+}
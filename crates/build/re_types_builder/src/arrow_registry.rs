@@ -348,7 +348,7 @@ impl From<DataType> for LazyDatatype {
 
 impl LazyDatatype {
     /// Recursively resolves the datatype using the specified `registry`.
-    fn resolve(&self, registry: &ArrowRegistry) -> DataType {
+    pub(crate) fn resolve(&self, registry: &ArrowRegistry) -> DataType {
         match self {
             Self::Null => DataType::Null,
             Self::Boolean => DataType::Boolean,
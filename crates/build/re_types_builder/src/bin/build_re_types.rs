@@ -9,7 +9,7 @@
 use re_build_tools::{
     read_versioning_hash, set_output_cargo_build_instructions, write_versioning_hash,
 };
-use re_types_builder::{compute_re_types_hash, SourceLocations};
+use re_types_builder::{compute_re_types_hash, CodegenMode, ObjectFilter, SourceLocations};
 
 use camino::Utf8Path;
 
@@ -22,6 +22,7 @@ const PYTHON_OUTPUT_DIR_PATH: &str = "rerun_py/rerun_sdk/rerun";
 const PYTHON_TESTING_OUTPUT_DIR_PATH: &str = "rerun_py/tests/test_types";
 const DOCS_CONTENT_DIR_PATH: &str = "docs/content/reference/types";
 const SNIPPETS_REF_DIR_PATH: &str = "docs/snippets/";
+const BFBS_CACHE_DIR_PATH: &str = "target/re_types_builder/bfbs";
 
 /// This uses [`rayon::scope`] to spawn all closures as tasks
 /// running in parallel. It blocks until all tasks are done.
@@ -30,7 +31,7 @@ macro_rules! join {
     ($($task:expr),*) => {{
         #![allow(clippy::redundant_closure_call)]
         ::rayon::scope(|scope| {
-            $(scope.spawn(|_| ($task)());)*
+            $(scope.spawn(|_| { ($task)(); });)*
         })
     }}
 }
@@ -46,11 +47,27 @@ fn main() {
 
     let mut always_run = false;
     let mut check = false;
+    let mut strict = false;
+    let mut only = std::collections::BTreeSet::new();
 
     for arg in std::env::args().skip(1) {
+        if let Some(fqname) = arg.strip_prefix("--only=") {
+            // Regenerating a single type is always explicit, so there's no point checking the hash.
+            always_run = true;
+            only.insert(fqname.to_owned());
+            continue;
+        }
+
         match arg.as_str() {
             "--help" => {
-                println!("Usage: [--help] [--force] [--profile]");
+                println!(
+                    "Usage: [--help] [--force] [--check] [--strict] [--profile] [--only=<fqname>…]"
+                );
+                println!();
+                println!(
+                    "--only=<fqname>: only regenerate the Rust, C++ and Python code of the given \
+                    type (e.g. `rerun.archetypes.Points3D`). Can be repeated."
+                );
                 return;
             }
             "--force" => always_run = true,
@@ -58,6 +75,7 @@ fn main() {
                 always_run = true;
                 check = true;
             }
+            "--strict" => strict = true,
 
             #[cfg(feature = "tracing")]
             "--profile" => profiler.start(),
@@ -93,6 +111,7 @@ fn main() {
     let python_testing_output_dir_path = workspace_dir.join(PYTHON_TESTING_OUTPUT_DIR_PATH);
     let docs_content_dir_path = workspace_dir.join(DOCS_CONTENT_DIR_PATH);
     let snippets_ref_dir_path = workspace_dir.join(SNIPPETS_REF_DIR_PATH);
+    let bfbs_cache_dir_path = workspace_dir.join(BFBS_CACHE_DIR_PATH);
 
     let cur_hash = read_versioning_hash(&re_types_source_hash_path);
     re_log::debug!("cur_hash: {cur_hash:?}");
@@ -121,14 +140,38 @@ fn main() {
         re_log::info!("Missing {re_types_source_hash_path:?} (first time running codegen)");
     }
 
+    let mode = if check {
+        CodegenMode::CheckOnly
+    } else {
+        CodegenMode::Write
+    };
+
     re_log::info!("Running codegen…");
     let (report, reporter) = re_types_builder::report::init();
 
     re_log::info!("Generating flatbuffers code…");
-    re_types_builder::generate_fbs(&reporter, &definitions_dir_path, check);
+    re_types_builder::generate_fbs(&reporter, &definitions_dir_path, mode, None);
+
+    let (objects, arrow_registry) = re_types_builder::generate_lang_agnostic_cached(
+        &reporter,
+        definitions_dir_path,
+        entrypoint_path,
+        bfbs_cache_dir_path,
+    );
+    objects.report_unknown_attributes(&reporter, strict);
 
-    let (objects, arrow_registry) =
-        re_types_builder::generate_lang_agnostic(&reporter, definitions_dir_path, entrypoint_path);
+    let filter = if only.is_empty() {
+        ObjectFilter::All
+    } else {
+        for fqname in &only {
+            assert!(
+                objects.get(fqname).is_some(),
+                "--only: unknown type {fqname:?}"
+            );
+        }
+        ObjectFilter::Only(only)
+    };
+    let is_filtered = filter != ObjectFilter::All;
 
     re_tracing::profile_scope!("Language-specific code-gen");
     join!(
@@ -137,14 +180,18 @@ fn main() {
             cpp_output_dir_path,
             &objects,
             &arrow_registry,
-            check,
+            &filter,
+            mode,
+            None,
         ),
         || re_types_builder::generate_rust_code(
             &reporter,
             workspace_dir,
             &objects,
             &arrow_registry,
-            check,
+            &filter,
+            mode,
+            None,
         ),
         || re_types_builder::generate_python_code(
             &reporter,
@@ -152,27 +199,38 @@ fn main() {
             python_testing_output_dir_path,
             &objects,
             &arrow_registry,
-            check,
-        ),
-        || re_types_builder::generate_docs(
-            &reporter,
-            docs_content_dir_path,
-            &objects,
-            &arrow_registry,
-            check,
-        ),
-        || re_types_builder::generate_snippets_ref(
-            &reporter,
-            snippets_ref_dir_path,
-            &objects,
-            &arrow_registry,
-            check,
+            &filter,
+            mode,
+            None,
         ),
+        || if !is_filtered {
+            re_types_builder::generate_docs(
+                &reporter,
+                docs_content_dir_path,
+                &objects,
+                &arrow_registry,
+                mode,
+                None,
+            );
+        },
+        || if !is_filtered {
+            re_types_builder::generate_snippets_ref(
+                &reporter,
+                snippets_ref_dir_path,
+                &objects,
+                &arrow_registry,
+                mode,
+                None,
+            );
+        },
     );
 
     report.finalize();
 
-    write_versioning_hash(re_types_source_hash_path, new_hash);
+    // Everything else might still be out of date after a partial run.
+    if !is_filtered {
+        write_versioning_hash(re_types_source_hash_path, new_hash);
+    }
 
     re_log::info!("Done.");
 }
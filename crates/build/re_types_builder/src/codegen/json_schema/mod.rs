@@ -0,0 +1,315 @@
+//! Generate a machine-readable JSON description of the whole type system.
+//!
+//! Everything ends up in a single `types.json` file, which lists every archetype, component,
+//! datatype and view along with their fields, Arrow datatypes and attributes (i.e. affixes).
+//! This is meant for downstream tooling (validators, linters, catalogs…), not for humans.
+
+use std::collections::BTreeMap;
+
+use arrow::datatypes::{DataType, Field, UnionMode};
+use camino::Utf8PathBuf;
+
+use crate::{
+    codegen::autogen_warning, ArrowRegistry, Attributes, CodeGenerator, ElementType,
+    GeneratedFiles, Object, ObjectClass, ObjectField, ObjectKind, Objects, Reporter, Type,
+};
+
+/// Name of the generated file, relative to the output path.
+pub const JSON_SCHEMA_FILENAME: &str = "types.json";
+
+pub struct JsonSchemaCodeGenerator {
+    output_path: Utf8PathBuf,
+}
+
+impl JsonSchemaCodeGenerator {
+    pub fn new(output_path: impl Into<Utf8PathBuf>) -> Self {
+        Self {
+            output_path: output_path.into(),
+        }
+    }
+}
+
+impl CodeGenerator for JsonSchemaCodeGenerator {
+    fn generate(
+        &mut self,
+        _reporter: &Reporter,
+        objects: &Objects,
+        arrow_registry: &ArrowRegistry,
+    ) -> GeneratedFiles {
+        re_tracing::profile_function!();
+
+        let schema = SchemaJson {
+            comment: autogen_warning!(),
+            types: objects
+                .values()
+                .map(|obj| object_json(arrow_registry, obj))
+                .collect(),
+        };
+
+        let mut contents = serde_json::to_string_pretty(&schema).unwrap();
+        contents.push('\n');
+
+        let mut files_to_write = GeneratedFiles::default();
+        files_to_write.insert(self.output_path.join(JSON_SCHEMA_FILENAME), contents);
+        files_to_write
+    }
+}
+
+// ---
+
+#[derive(serde::Serialize)]
+struct SchemaJson<'a> {
+    /// JSON has no comments: this is where the `DO NOT EDIT` warning goes.
+    #[serde(rename = "$comment")]
+    comment: String,
+
+    /// All objects, sorted by fully-qualified name.
+    types: Vec<ObjectJson<'a>>,
+}
+
+#[derive(serde::Serialize)]
+struct ObjectJson<'a> {
+    fqname: &'a str,
+    name: &'a str,
+    kind: &'static str,
+    class: &'static str,
+    is_arrow_transparent: bool,
+
+    /// Only set for datatypes and components, which are what actually ends up in Arrow arrays.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    datatype: Option<serde_json::Value>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    deprecation_notice: Option<String>,
+
+    attrs: BTreeMap<&'a str, Option<&'a str>>,
+
+    /// Ordered by their `order` attribute (structs), or by declaration order (enums & unions).
+    fields: Vec<FieldJson<'a>>,
+}
+
+#[derive(serde::Serialize)]
+struct FieldJson<'a> {
+    name: &'a str,
+
+    #[serde(rename = "type")]
+    typ: String,
+
+    order: u32,
+    is_nullable: bool,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    enum_value: Option<u8>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    datatype: Option<serde_json::Value>,
+
+    attrs: BTreeMap<&'a str, Option<&'a str>>,
+}
+
+fn object_json<'a>(arrow_registry: &ArrowRegistry, obj: &'a Object) -> ObjectJson<'a> {
+    let datatype = matches!(obj.kind, ObjectKind::Datatype | ObjectKind::Component)
+        .then(|| arrow_registry.try_get(&obj.fqname))
+        .flatten()
+        .map(|datatype| datatype_json(&datatype.into()));
+
+    ObjectJson {
+        fqname: &obj.fqname,
+        name: &obj.name,
+        kind: obj.kind.plural_snake_case(),
+        class: match obj.class {
+            ObjectClass::Struct => "struct",
+            ObjectClass::Enum => "enum",
+            ObjectClass::Union => "union",
+        },
+        is_arrow_transparent: obj.is_arrow_transparent(),
+        datatype,
+        deprecation_notice: obj.deprecation_notice(),
+        attrs: attrs_json(&obj.attrs),
+        fields: obj
+            .fields
+            .iter()
+            .map(|field| field_json(arrow_registry, field))
+            .collect(),
+    }
+}
+
+fn field_json<'a>(arrow_registry: &ArrowRegistry, field: &'a ObjectField) -> FieldJson<'a> {
+    FieldJson {
+        name: &field.name,
+        typ: type_name(&field.typ),
+        order: field.order,
+        is_nullable: field.is_nullable,
+        enum_value: field.enum_value,
+        datatype: field
+            .datatype
+            .as_ref()
+            .map(|datatype| datatype_json(&datatype.resolve(arrow_registry).into())),
+        attrs: attrs_json(&field.attrs),
+    }
+}
+
+fn attrs_json(attrs: &Attributes) -> BTreeMap<&str, Option<&str>> {
+    attrs.iter().collect()
+}
+
+/// The name of a flatbuffers type, e.g. `float32`, `[uint8; 4]` or `rerun.datatypes.Vec2D`.
+fn type_name(typ: &Type) -> String {
+    match typ {
+        Type::Unit => "unit".to_owned(),
+        Type::UInt8 => "uint8".to_owned(),
+        Type::UInt16 => "uint16".to_owned(),
+        Type::UInt32 => "uint32".to_owned(),
+        Type::UInt64 => "uint64".to_owned(),
+        Type::Int8 => "int8".to_owned(),
+        Type::Int16 => "int16".to_owned(),
+        Type::Int32 => "int32".to_owned(),
+        Type::Int64 => "int64".to_owned(),
+        Type::Bool => "bool".to_owned(),
+        Type::Float16 => "float16".to_owned(),
+        Type::Float32 => "float32".to_owned(),
+        Type::Float64 => "float64".to_owned(),
+        Type::String => "string".to_owned(),
+        Type::Array { elem_type, length } => {
+            format!("[{}; {length}]", element_type_name(elem_type))
+        }
+        Type::Vector { elem_type } => format!("[{}]", element_type_name(elem_type)),
+        Type::Object(fqname) => fqname.clone(),
+    }
+}
+
+fn element_type_name(elem_type: &ElementType) -> String {
+    type_name(&elem_type.clone().into())
+}
+
+/// Describes an Arrow datatype as JSON.
+///
+/// Primitive types are plain strings (e.g. `"float32"`), nested types are objects keyed by their
+/// kind (e.g. `{"fixed_size_list": …, "length": 2}`).
+fn datatype_json(datatype: &DataType) -> serde_json::Value {
+    use serde_json::json;
+
+    match datatype {
+        DataType::Null => json!("null"),
+        DataType::Boolean => json!("boolean"),
+        DataType::Int8 => json!("int8"),
+        DataType::Int16 => json!("int16"),
+        DataType::Int32 => json!("int32"),
+        DataType::Int64 => json!("int64"),
+        DataType::UInt8 => json!("uint8"),
+        DataType::UInt16 => json!("uint16"),
+        DataType::UInt32 => json!("uint32"),
+        DataType::UInt64 => json!("uint64"),
+        DataType::Float16 => json!("float16"),
+        DataType::Float32 => json!("float32"),
+        DataType::Float64 => json!("float64"),
+        DataType::Binary => json!("binary"),
+        DataType::LargeBinary => json!("large_binary"),
+        DataType::Utf8 => json!("utf8"),
+        DataType::LargeUtf8 => json!("large_utf8"),
+        DataType::FixedSizeBinary(length) => json!({ "fixed_size_binary": length }),
+        DataType::List(inner) => json!({ "list": field_datatype_json(inner) }),
+        DataType::LargeList(inner) => json!({ "large_list": field_datatype_json(inner) }),
+        DataType::FixedSizeList(inner, length) => json!({
+            "fixed_size_list": field_datatype_json(inner),
+            "length": length,
+        }),
+        DataType::Struct(fields) => json!({
+            "struct": fields
+                .iter()
+                .map(|field| field_datatype_json(field))
+                .collect::<Vec<_>>(),
+        }),
+        DataType::Union(union_fields, union_mode) => json!({
+            "union": union_fields
+                .iter()
+                .map(|(type_id, field)| {
+                    let mut field = field_datatype_json(field);
+                    field["type_id"] = json!(type_id);
+                    field
+                })
+                .collect::<Vec<_>>(),
+            "mode": match union_mode {
+                UnionMode::Sparse => "sparse",
+                UnionMode::Dense => "dense",
+            },
+        }),
+        _ => {
+            unimplemented!(
+                "For the JSON schema, you need to implement arrow datatype {:#?}",
+                datatype
+            );
+        }
+    }
+}
+
+fn field_datatype_json(field: &Field) -> serde_json::Value {
+    serde_json::json!({
+        "name": field.name(),
+        "nullable": field.is_nullable(),
+        "datatype": datatype_json(field.data_type()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_datatype_json() {
+        let datatype = DataType::FixedSizeList(
+            std::sync::Arc::new(Field::new("item", DataType::Float32, false)),
+            2,
+        );
+        assert_eq!(
+            datatype_json(&datatype),
+            serde_json::json!({
+                "fixed_size_list": { "name": "item", "nullable": false, "datatype": "float32" },
+                "length": 2,
+            })
+        );
+    }
+
+    /// Runs [`crate::generate_json_schema`] on the real definitions.
+    ///
+    /// Requires `flatc`, just like the rest of the codegen.
+    #[test]
+    fn test_generated_schema_contains_position2d() {
+        let (_report, reporter) = crate::report::init();
+        let Some((objects, arrow_registry)) = crate::generate_lang_agnostic_for_tests(&reporter)
+        else {
+            return;
+        };
+
+        let tempdir = tempfile::tempdir().unwrap();
+        let output_dir = camino::Utf8Path::from_path(tempdir.path()).unwrap();
+        let diffs = crate::generate_json_schema(
+            &reporter,
+            output_dir,
+            &objects,
+            &arrow_registry,
+            crate::CodegenMode::Write,
+            None,
+        );
+        assert!(diffs.is_empty());
+
+        let contents = std::fs::read_to_string(output_dir.join(JSON_SCHEMA_FILENAME)).unwrap();
+        let schema: serde_json::Value = serde_json::from_str(&contents).unwrap();
+
+        let position2d = schema["types"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|typ| typ["fqname"] == "rerun.components.Position2D")
+            .expect("Position2D is missing from the JSON schema");
+
+        assert_eq!(position2d["name"], "Position2D");
+        assert_eq!(position2d["kind"], "components");
+        assert_eq!(position2d["is_arrow_transparent"], true);
+        assert_eq!(position2d["datatype"]["length"], 2);
+        assert_eq!(
+            position2d["fields"][0]["type"], "rerun.datatypes.Vec2D",
+            "{position2d:#}"
+        );
+    }
+}
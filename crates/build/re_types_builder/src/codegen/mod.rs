@@ -6,6 +6,7 @@ pub enum Target {
     Cpp,
     Python,
     Rust,
+    TypeScript,
 
     /// Markdown files shown at <https://rerun.io/docs/reference/types>.
     ///
@@ -53,11 +54,15 @@ use self::common::StringExt;
 mod cpp;
 mod docs;
 mod fbs;
+mod json_schema;
 mod python;
 mod rust;
+mod typescript;
 
 pub use self::cpp::CppCodeGenerator;
 pub use self::docs::{DocsCodeGenerator, SnippetsRefCodeGenerator};
 pub use self::fbs::FbsCodeGenerator;
+pub use self::json_schema::JsonSchemaCodeGenerator;
 pub use self::python::PythonCodeGenerator;
 pub use self::rust::RustCodeGenerator;
+pub use self::typescript::TypeScriptCodeGenerator;
@@ -493,6 +493,7 @@ fn quote_enum(
 
     let quoted_doc = quote_obj_docs(reporter, objects, obj);
     let quoted_custom_clause = quote_meta_clause_from_obj(obj, ATTR_RUST_CUSTOM_CLAUSE, "");
+    let quoted_non_exhaustive_clause = quote_non_exhaustive_clause(obj);
 
     let mut derives = vec!["Clone", "Copy", "Debug", "Hash", "PartialEq", "Eq"];
 
@@ -599,6 +600,7 @@ fn quote_enum(
         #[derive( #(#derives,)* )]
         #quoted_custom_clause
         #[repr(u8)]
+        #quoted_non_exhaustive_clause
         pub enum #name {
             #(#quoted_fields,)*
         }
@@ -708,9 +710,27 @@ fn quote_obj_docs(reporter: &Reporter, objects: &Objects, obj: &Object) -> Token
         reporter.error(&obj.virtpath, &obj.fqname, "Missing documentation for");
     }
 
+    if obj.is_non_exhaustive() {
+        lines.push(String::new());
+        lines.push(
+            "This enum is `#[non_exhaustive]`: new variants may be added in future versions, \
+             so make sure to always include a catch-all arm when matching on it."
+                .to_owned(),
+        );
+    }
+
     quote_doc_lines(&lines)
 }
 
+/// `#[non_exhaustive]` if the object has the [`crate::ATTR_RUST_NON_EXHAUSTIVE`] attribute.
+fn quote_non_exhaustive_clause(obj: &Object) -> TokenStream {
+    if obj.is_non_exhaustive() {
+        quote!(#[non_exhaustive])
+    } else {
+        quote!()
+    }
+}
+
 /// Returns type name as string and whether it was force unwrapped.
 ///
 /// Specifying `unwrap = true` will unwrap the final type before returning it, e.g. `Vec<String>`
@@ -1640,3 +1660,31 @@ fn quote_builder_from_obj(reporter: &Reporter, objects: &Objects, obj: &Object)
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_non_exhaustive_enums() {
+        let (_report, reporter) = crate::report::init();
+        let Some((objects, arrow_registry)) = crate::generate_lang_agnostic_for_tests(&reporter)
+        else {
+            return;
+        };
+
+        let quote_enum = |fqname: &str| {
+            quote_enum(&reporter, &arrow_registry, &objects, &objects[fqname])
+                .to_string()
+                .replace(' ', "")
+        };
+
+        let marked = quote_enum("rerun.testing.datatypes.EnumTest");
+        assert!(marked.contains("#[non_exhaustive]"));
+        assert!(marked.contains("catch-allarm"));
+
+        let unmarked = quote_enum("rerun.testing.datatypes.ValuedEnum");
+        assert!(!unmarked.contains("#[non_exhaustive]"));
+        assert!(!unmarked.contains("catch-allarm"));
+    }
+}
@@ -0,0 +1,407 @@
+//! Generate TypeScript type definitions for datatypes and components.
+//!
+//! We generate a single `.ts` file per module (e.g. `datatypes.ts`, `blueprint/components.ts`),
+//! which only contains type declarations: the actual (de)serialization is left to Arrow.
+//!
+//! Affixes are mapped as follows:
+//! - Transparent objects & fields are replaced by the type of their single inner field.
+//! - Arrow-transparent objects (e.g. components) become type aliases of their single field.
+//! - Nullable fields are optional properties, nullable aliases are unions with `null`.
+//! - Plural fields are arrays.
+
+use std::collections::BTreeSet;
+use std::fmt::Write as _;
+
+use camino::Utf8PathBuf;
+use itertools::Itertools as _;
+
+use crate::{
+    codegen::{autogen_warning, Target},
+    CodeGenerator, Docs, ElementType, GeneratedFiles, Object, ObjectClass, ObjectField, ObjectKind,
+    Objects, Reporter, Type, ATTR_TRANSPARENT,
+};
+
+/// Like [`writeln!`], but without a [`Result`].
+macro_rules! putln {
+    ($o:ident) => ( { writeln!($o).ok(); } );
+    ($o:ident, $($tt:tt)*) => ( { writeln!($o, $($tt)*).unwrap(); } );
+}
+
+pub struct TypeScriptCodeGenerator {
+    output_path: Utf8PathBuf,
+}
+
+impl TypeScriptCodeGenerator {
+    pub fn new(output_path: impl Into<Utf8PathBuf>) -> Self {
+        Self {
+            output_path: output_path.into(),
+        }
+    }
+}
+
+impl CodeGenerator for TypeScriptCodeGenerator {
+    fn generate(
+        &mut self,
+        reporter: &Reporter,
+        objects: &Objects,
+        _arrow_registry: &crate::ArrowRegistry,
+    ) -> GeneratedFiles {
+        re_tracing::profile_function!();
+
+        let mut files_to_write = GeneratedFiles::default();
+
+        let objects_per_module = objects
+            .values()
+            .filter(|obj| matches!(obj.kind, ObjectKind::Datatype | ObjectKind::Component))
+            .into_group_map_by(|obj| module_path(obj));
+
+        for (module, module_objects) in objects_per_module {
+            let contents = module_file(reporter, objects, &module, &module_objects);
+            files_to_write.insert(self.output_path.join(format!("{module}.ts")), contents);
+        }
+
+        files_to_write
+    }
+}
+
+/// The path of the module an object lives in, relative to the output directory and without
+/// extension, e.g. `blueprint/components` or `testing/datatypes`.
+fn module_path(obj: &Object) -> String {
+    if obj.is_testing() {
+        format!("testing/{}", obj.module_name())
+    } else {
+        obj.module_name()
+    }
+}
+
+/// The name under which a module is imported by other modules, e.g. `blueprint_components`.
+fn module_alias(module: &str) -> String {
+    module.replace('/', "_")
+}
+
+/// The path to import `to` from within `from`, e.g. `../datatypes`.
+fn relative_import_path(from: &str, to: &str) -> String {
+    let from_dir = from.split('/').collect_vec();
+    let from_dir = &from_dir[..from_dir.len() - 1];
+    let to = to.split('/').collect_vec();
+
+    let num_common = from_dir
+        .iter()
+        .zip(&to)
+        .take_while(|(from, to)| from == to)
+        .count();
+    let prefix = match from_dir.len() - num_common {
+        0 => "./".to_owned(),
+        n => "../".repeat(n),
+    };
+
+    format!("{prefix}{}", to[num_common..].join("/"))
+}
+
+fn module_file(
+    reporter: &Reporter,
+    objects: &Objects,
+    module: &str,
+    module_objects: &[&Object],
+) -> String {
+    // Other modules referenced by this one, filled in while generating the declarations.
+    let mut imports = BTreeSet::new();
+
+    let declarations = module_objects
+        .iter()
+        .map(|obj| {
+            let mut code = String::new();
+            write_docs(&mut code, reporter, objects, &obj.docs, "");
+
+            match obj.class {
+                ObjectClass::Struct => {
+                    write_struct(&mut code, reporter, objects, module, &mut imports, obj);
+                }
+                ObjectClass::Enum => write_enum(&mut code, reporter, objects, obj),
+                ObjectClass::Union => {
+                    write_union(&mut code, reporter, objects, module, &mut imports, obj);
+                }
+            }
+
+            code
+        })
+        .collect_vec();
+
+    let mut code = String::new();
+    putln!(code, "// {}", autogen_warning!());
+    putln!(code);
+
+    if !imports.is_empty() {
+        for import in &imports {
+            putln!(
+                code,
+                "import type * as {} from {:?};",
+                module_alias(import),
+                relative_import_path(module, import)
+            );
+        }
+        putln!(code);
+    }
+
+    code.push_str(&declarations.join("\n"));
+
+    code
+}
+
+fn write_docs(
+    code: &mut String,
+    reporter: &Reporter,
+    objects: &Objects,
+    docs: &Docs,
+    indent: &str,
+) {
+    let lines = docs.lines_for(reporter, objects, Target::TypeScript);
+    if lines.is_empty() {
+        return;
+    }
+
+    putln!(code, "{indent}/**");
+    for line in lines {
+        if line.is_empty() {
+            putln!(code, "{indent} *");
+        } else {
+            putln!(code, "{indent} * {line}");
+        }
+    }
+    putln!(code, "{indent} */");
+}
+
+fn write_struct(
+    code: &mut String,
+    reporter: &Reporter,
+    objects: &Objects,
+    module: &str,
+    imports: &mut BTreeSet<String>,
+    obj: &Object,
+) {
+    let name = &obj.name;
+
+    if is_alias(obj) {
+        let field = &obj.fields[0];
+        let mut typ = quote_field_type(objects, module, imports, field);
+        if field.is_nullable {
+            typ.push_str(" | null");
+        }
+        putln!(code, "export type {name} = {typ};");
+        return;
+    }
+
+    // NOTE: Fields are already sorted according to their `order` attribute.
+    putln!(code, "export interface {name} {{");
+    for field in &obj.fields {
+        write_docs(code, reporter, objects, &field.docs, "  ");
+        let optional = if field.is_nullable { "?" } else { "" };
+        let typ = quote_field_type(objects, module, imports, field);
+        putln!(code, "  {}{optional}: {typ};", field.name);
+    }
+    putln!(code, "}}");
+}
+
+fn write_enum(code: &mut String, reporter: &Reporter, objects: &Objects, obj: &Object) {
+    putln!(code, "export enum {} {{", obj.name);
+    for field in &obj.fields {
+        write_docs(code, reporter, objects, &field.docs, "  ");
+        let value = field
+            .enum_value
+            .unwrap_or_else(|| panic!("{} is missing an enum value", field.fqname));
+        putln!(code, "  {} = {value},", field.name);
+    }
+    putln!(code, "}}");
+}
+
+/// Unions are generated as tagged unions, discriminated by the `kind` property.
+fn write_union(
+    code: &mut String,
+    reporter: &Reporter,
+    objects: &Objects,
+    module: &str,
+    imports: &mut BTreeSet<String>,
+    obj: &Object,
+) {
+    putln!(code, "export type {} =", obj.name);
+    for (i, field) in obj.fields.iter().enumerate() {
+        write_docs(code, reporter, objects, &field.docs, "  ");
+        let terminator = if i + 1 == obj.fields.len() { ";" } else { "" };
+        if field.typ == Type::Unit {
+            putln!(code, "  | {{ kind: {:?} }}{terminator}", field.name);
+        } else {
+            let typ = quote_field_type(objects, module, imports, field);
+            putln!(
+                code,
+                "  | {{ kind: {:?}; value: {typ} }}{terminator}",
+                field.name
+            );
+        }
+    }
+}
+
+/// Is this object declared as an alias of its single field rather than as an interface?
+fn is_alias(obj: &Object) -> bool {
+    (obj.is_arrow_transparent() || obj.attrs.has(ATTR_TRANSPARENT)) && obj.fields.len() == 1
+}
+
+fn quote_field_type(
+    objects: &Objects,
+    module: &str,
+    imports: &mut BTreeSet<String>,
+    field: &ObjectField,
+) -> String {
+    let typ = resolve_transparency(objects, field);
+    quote_type(objects, module, imports, typ)
+}
+
+/// Transparent fields are replaced by the (possibly itself transparent) single field of their type.
+fn resolve_transparency<'a>(objects: &'a Objects, field: &'a ObjectField) -> &'a Type {
+    if !field.has_attr(ATTR_TRANSPARENT) {
+        return &field.typ;
+    }
+
+    let Type::Object(fqname) = &field.typ else {
+        unimplemented!("{}: only object fields can be transparent", field.fqname);
+    };
+    let obj = &objects[fqname];
+    assert!(
+        obj.fields.len() == 1,
+        "{}: transparent field of type {fqname} which has {} fields, expected exactly one",
+        field.fqname,
+        obj.fields.len()
+    );
+
+    resolve_transparency(objects, &obj.fields[0])
+}
+
+fn quote_type(
+    objects: &Objects,
+    module: &str,
+    imports: &mut BTreeSet<String>,
+    typ: &Type,
+) -> String {
+    match typ {
+        Type::Unit => "null".to_owned(),
+        Type::UInt64 | Type::Int64 => "bigint".to_owned(),
+        Type::UInt8
+        | Type::UInt16
+        | Type::UInt32
+        | Type::Int8
+        | Type::Int16
+        | Type::Int32
+        | Type::Float16
+        | Type::Float32
+        | Type::Float64 => "number".to_owned(),
+        Type::Bool => "boolean".to_owned(),
+        Type::String => "string".to_owned(),
+        Type::Array { elem_type, .. } | Type::Vector { elem_type } => {
+            format!(
+                "{}[]",
+                quote_element_type(objects, module, imports, elem_type)
+            )
+        }
+        Type::Object(fqname) => quote_object_name(objects, module, imports, fqname),
+    }
+}
+
+fn quote_element_type(
+    objects: &Objects,
+    module: &str,
+    imports: &mut BTreeSet<String>,
+    elem_type: &ElementType,
+) -> String {
+    match elem_type {
+        ElementType::Object(fqname) => quote_object_name(objects, module, imports, fqname),
+        _ => quote_type(objects, module, imports, &elem_type.clone().into()),
+    }
+}
+
+fn quote_object_name(
+    objects: &Objects,
+    module: &str,
+    imports: &mut BTreeSet<String>,
+    fqname: &str,
+) -> String {
+    let obj = &objects[fqname];
+    let obj_module = module_path(obj);
+
+    if obj_module == module {
+        obj.name.clone()
+    } else {
+        let alias = module_alias(&obj_module);
+        imports.insert(obj_module);
+        format!("{alias}.{}", obj.name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_relative_import_path() {
+        assert_eq!(
+            relative_import_path("components", "datatypes"),
+            "./datatypes"
+        );
+        assert_eq!(
+            relative_import_path("blueprint/components", "datatypes"),
+            "../datatypes"
+        );
+        assert_eq!(
+            relative_import_path("blueprint/components", "blueprint/datatypes"),
+            "./datatypes"
+        );
+        assert_eq!(
+            relative_import_path("datatypes", "testing/datatypes"),
+            "./testing/datatypes"
+        );
+    }
+
+    /// Runs [`crate::generate_typescript_code`] on the real definitions, and compares the code
+    /// generated for the `AffixFuzzer` test types against the golden files in `testdata/`.
+    ///
+    /// Requires `flatc`, just like the rest of the codegen. Run with `UPDATE_SNAPSHOTS=1` to
+    /// update the golden files.
+    #[test]
+    fn test_affix_fuzzer_golden_files() {
+        let (_report, reporter) = crate::report::init();
+        let Some((objects, arrow_registry)) = crate::generate_lang_agnostic_for_tests(&reporter)
+        else {
+            return;
+        };
+
+        let tempdir = tempfile::tempdir().unwrap();
+        let output_dir = camino::Utf8Path::from_path(tempdir.path()).unwrap();
+        let diffs = crate::generate_typescript_code(
+            &reporter,
+            output_dir,
+            &objects,
+            &arrow_registry,
+            crate::CodegenMode::Write,
+            None,
+        );
+        assert!(diffs.is_empty());
+
+        let testdata_dir = camino::Utf8Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("src/codegen/typescript/testdata");
+        let update = std::env::var("UPDATE_SNAPSHOTS").is_ok();
+
+        for module in ["testing/datatypes", "testing/components"] {
+            let contents =
+                std::fs::read_to_string(output_dir.join(format!("{module}.ts"))).unwrap();
+            let golden_path = testdata_dir.join(format!("{}.ts", module_alias(module)));
+
+            if update {
+                std::fs::write(&golden_path, contents).unwrap();
+            } else {
+                let golden = std::fs::read_to_string(&golden_path).unwrap();
+                assert_eq!(
+                    contents, golden,
+                    "{golden_path} is out of date, run with UPDATE_SNAPSHOTS=1 to update it"
+                );
+            }
+        }
+    }
+}
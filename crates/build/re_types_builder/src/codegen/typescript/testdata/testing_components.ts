@@ -0,0 +1,49 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/typescript/mod.rs
+
+import type * as testing_datatypes from "./datatypes";
+
+export type AffixFuzzer1 = testing_datatypes.AffixFuzzer1;
+
+export type AffixFuzzer10 = string | null;
+
+export type AffixFuzzer11 = number[] | null;
+
+export type AffixFuzzer12 = string[];
+
+export type AffixFuzzer13 = string[] | null;
+
+export type AffixFuzzer14 = testing_datatypes.AffixFuzzer3;
+
+export type AffixFuzzer15 = testing_datatypes.AffixFuzzer3 | null;
+
+export type AffixFuzzer16 = testing_datatypes.AffixFuzzer3[];
+
+export type AffixFuzzer17 = testing_datatypes.AffixFuzzer3[] | null;
+
+export type AffixFuzzer18 = testing_datatypes.AffixFuzzer4[] | null;
+
+export type AffixFuzzer19 = testing_datatypes.AffixFuzzer5;
+
+export type AffixFuzzer2 = testing_datatypes.AffixFuzzer1;
+
+export type AffixFuzzer20 = testing_datatypes.AffixFuzzer20;
+
+export type AffixFuzzer21 = testing_datatypes.AffixFuzzer21;
+
+export type AffixFuzzer22 = testing_datatypes.AffixFuzzer22 | null;
+
+export type AffixFuzzer23 = testing_datatypes.MultiEnum | null;
+
+export type AffixFuzzer3 = testing_datatypes.AffixFuzzer1;
+
+export type AffixFuzzer4 = testing_datatypes.AffixFuzzer1 | null;
+
+export type AffixFuzzer5 = testing_datatypes.AffixFuzzer1 | null;
+
+export type AffixFuzzer6 = testing_datatypes.AffixFuzzer1 | null;
+
+export type AffixFuzzer7 = testing_datatypes.AffixFuzzer1[] | null;
+
+export type AffixFuzzer8 = number | null;
+
+export type AffixFuzzer9 = string;
@@ -0,0 +1,128 @@
+// DO NOT EDIT! This file was auto-generated by crates/build/re_types_builder/src/codegen/typescript/mod.rs
+
+export interface AffixFuzzer1 {
+  single_float_optional?: number;
+  single_string_required: string;
+  single_string_optional?: string;
+  many_floats_optional?: number[];
+  many_strings_required: string[];
+  many_strings_optional?: string[];
+  flattened_scalar: number;
+  almost_flattened_scalar: FlattenedScalar;
+  from_parent?: boolean;
+}
+
+export type AffixFuzzer2 = number | null;
+
+export interface AffixFuzzer20 {
+  p: PrimitiveComponent;
+  s: StringComponent;
+}
+
+export interface AffixFuzzer21 {
+  single_half: number;
+  many_halves: number[];
+}
+
+export interface AffixFuzzer22 {
+  fixed_sized_native: number[];
+}
+
+export type AffixFuzzer3 =
+  | { kind: "degrees"; value: number }
+  | { kind: "craziness"; value: AffixFuzzer1[] }
+  | { kind: "fixed_size_shenanigans"; value: number[] }
+  | { kind: "empty_variant" };
+
+export type AffixFuzzer4 =
+  | { kind: "single_required"; value: AffixFuzzer3 }
+  | { kind: "many_required"; value: AffixFuzzer3[] };
+
+export interface AffixFuzzer5 {
+  single_optional_union?: AffixFuzzer4;
+}
+
+export type ArrayOfFloats = number[];
+
+export type DeeplyFlattenedScalar = number;
+
+/**
+ * A test of the enum type.
+ */
+export enum EnumTest {
+  /**
+   * Great film.
+   */
+  Up = 1,
+  /**
+   * Feeling blue.
+   */
+  Down = 2,
+  /**
+   * Correct.
+   */
+  Right = 3,
+  /**
+   * It's what's remaining.
+   */
+  Left = 4,
+  /**
+   * It's the only way to go.
+   */
+  Forward = 5,
+  /**
+   * Baby's got it.
+   */
+  Back = 6,
+}
+
+export interface FlattenedScalar {
+  value: number;
+}
+
+export interface MultiEnum {
+  /**
+   * The first value.
+   */
+  value1: EnumTest;
+  /**
+   * The second value.
+   */
+  value2?: ValuedEnum;
+}
+
+export type PrimitiveComponent = number;
+
+export type StringComponent = string;
+
+export type SurprisinglyShallowScalar = FlattenedScalar;
+
+/**
+ * A test of an enumate with specified values.
+ */
+export enum ValuedEnum {
+  /**
+   * One.
+   */
+  One = 1,
+  /**
+   * Two.
+   */
+  Two = 2,
+  /**
+   * Three.
+   */
+  Three = 3,
+  /**
+   * The answer to life, the universe, and everything.
+   */
+  TheAnswer = 42,
+}
+
+export type VeryDeeplyFlattenedScalar = number;
+
+export type __AffixFuzzer1Vec = AffixFuzzer1[];
+
+export type __AffixFuzzer3 = AffixFuzzer3;
+
+export type __AffixFuzzer3Vec = AffixFuzzer3[];
@@ -96,6 +96,7 @@ impl Docs {
             Target::Cpp => "cpp",
             Target::Python => "py",
             Target::Rust => "rs",
+            Target::TypeScript => "ts",
             Target::WebDocsMarkdown => "md",
         };
         assert!(
@@ -136,7 +137,7 @@ fn remove_extra_newlines(lines: impl Iterator<Item = String>) -> Vec<String> {
 }
 
 fn is_known_tag(tag: &str) -> bool {
-    matches!(tag, "" | "example" | "cpp" | "py" | "rs" | "md")
+    matches!(tag, "" | "example" | "cpp" | "py" | "rs" | "ts" | "md")
 }
 
 /// Parses `" \tag The comment"` into `("tag", "The comment")`.
@@ -416,6 +417,13 @@ mod doclink_translation {
                     format!("[`{kind_and_type}`][{object_path}]")
                 }
             }
+            Target::TypeScript => {
+                if let Some(field_or_enum_name) = field_or_enum_name {
+                    format!("`{kind}.{type_name}.{field_or_enum_name}`")
+                } else {
+                    format!("`{kind}.{type_name}`")
+                }
+            }
             Target::WebDocsMarkdown => {
                 let kind_and_type = format!("{kind}.{type_name}");
 
@@ -157,11 +157,65 @@ pub mod report;
 /// etc), and finally written to disk by the I/O pass.
 pub type GeneratedFiles = std::collections::BTreeMap<camino::Utf8PathBuf, String>;
 
+/// Applied to the contents of every generated file right before it is written (or checked),
+/// e.g. to prepend a license header.
+///
+/// Gets the path of the file and its contents, and returns the new contents.
+pub type PostProcess = dyn Fn(&Utf8Path, String) -> String + Send + Sync;
+
+/// Whether the codegen writes its output to disk, or only compares it with what's already there.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CodegenMode {
+    /// Write the generated files to disk, and remove orphaned files.
+    Write,
+
+    /// Compare the generated files with the ones on disk, without touching the file system.
+    ///
+    /// Every difference is reported as an error, and returned as a [`CodegenDiff`].
+    CheckOnly,
+}
+
+/// Restricts which objects the codegen regenerates.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum ObjectFilter {
+    /// Regenerate everything, and remove orphaned files.
+    #[default]
+    All,
+
+    /// Only regenerate the files of the objects with these fully-qualified names,
+    /// e.g. `rerun.archetypes.Points3D`.
+    ///
+    /// Files shared between objects (`mod.rs`, `__init__.py`, `.gitattributes`, …) are left
+    /// untouched, and orphaned files aren't removed.
+    Only(BTreeSet<String>),
+}
+
+impl ObjectFilter {
+    pub fn matches(&self, obj: &Object) -> bool {
+        match self {
+            Self::All => true,
+            Self::Only(fqnames) => fqnames.contains(&obj.fqname),
+        }
+    }
+}
+
+/// A generated file that doesn't match what's on disk.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CodegenDiff {
+    pub filepath: camino::Utf8PathBuf,
+
+    /// The contents of the file on disk, or `None` if it doesn't exist.
+    pub current: Option<String>,
+
+    /// The freshly generated contents of the file.
+    pub expected: String,
+}
+
 pub use self::{
     arrow_registry::{ArrowRegistry, LazyDatatype, LazyField},
     codegen::{
-        CodeGenerator, CppCodeGenerator, DocsCodeGenerator, PythonCodeGenerator, RustCodeGenerator,
-        SnippetsRefCodeGenerator,
+        CodeGenerator, CppCodeGenerator, DocsCodeGenerator, JsonSchemaCodeGenerator,
+        PythonCodeGenerator, RustCodeGenerator, SnippetsRefCodeGenerator, TypeScriptCodeGenerator,
     },
     docs::Docs,
     format::{CodeFormatter, CppCodeFormatter, PythonCodeFormatter, RustCodeFormatter},
@@ -198,6 +252,7 @@ pub const ATTR_RUST_CUSTOM_CLAUSE: &str = "attr.rust.custom_clause";
 pub const ATTR_RUST_DERIVE: &str = "attr.rust.derive";
 pub const ATTR_RUST_DERIVE_ONLY: &str = "attr.rust.derive_only";
 pub const ATTR_RUST_NEW_PUB_CRATE: &str = "attr.rust.new_pub_crate";
+pub const ATTR_RUST_NON_EXHAUSTIVE: &str = "attr.rust.non_exhaustive";
 pub const ATTR_RUST_OVERRIDE_CRATE: &str = "attr.rust.override_crate";
 pub const ATTR_RUST_REPR: &str = "attr.rust.repr";
 pub const ATTR_RUST_TUPLE_STRUCT: &str = "attr.rust.tuple_struct";
@@ -208,6 +263,42 @@ pub const ATTR_DOCS_UNRELEASED: &str = "attr.docs.unreleased";
 pub const ATTR_DOCS_CATEGORY: &str = "attr.docs.category";
 pub const ATTR_DOCS_VIEW_TYPES: &str = "attr.docs.view_types";
 
+/// All the attributes the codegen knows about.
+///
+/// Any other `attr.*` attribute is most likely a typo, see [`Objects::report_unknown_attributes`].
+pub const KNOWN_ATTRS: &[&str] = &[
+    ATTR_DEFAULT,
+    ATTR_NULLABLE,
+    ATTR_ORDER,
+    ATTR_TRANSPARENT,
+    ATTR_ARROW_TRANSPARENT,
+    ATTR_ARROW_SPARSE_UNION,
+    ATTR_RERUN_COMPONENT_OPTIONAL,
+    ATTR_RERUN_COMPONENT_RECOMMENDED,
+    ATTR_RERUN_COMPONENT_REQUIRED,
+    ATTR_RERUN_LOG_MISSING_AS_EMPTY,
+    ATTR_RERUN_OVERRIDE_TYPE,
+    ATTR_RERUN_SCOPE,
+    ATTR_RERUN_VIEW_IDENTIFIER,
+    ATTR_RERUN_DEPRECATED,
+    ATTR_RERUN_EXPERIMENTAL,
+    ATTR_PYTHON_ALIASES,
+    ATTR_PYTHON_ARRAY_ALIASES,
+    ATTR_RUST_CUSTOM_CLAUSE,
+    ATTR_RUST_DERIVE,
+    ATTR_RUST_DERIVE_ONLY,
+    ATTR_RUST_NEW_PUB_CRATE,
+    ATTR_RUST_NON_EXHAUSTIVE,
+    ATTR_RUST_OVERRIDE_CRATE,
+    ATTR_RUST_REPR,
+    ATTR_RUST_TUPLE_STRUCT,
+    ATTR_CPP_NO_FIELD_CTORS,
+    ATTR_CPP_RENAME_FIELD,
+    ATTR_DOCS_UNRELEASED,
+    ATTR_DOCS_CATEGORY,
+    ATTR_DOCS_VIEW_TYPES,
+];
+
 // --- Entrypoints ---
 
 use camino::{Utf8Path, Utf8PathBuf};
@@ -226,6 +317,9 @@ pub fn compile_binary_schemas(
     output_dir_path: impl AsRef<Utf8Path>,
     entrypoint_path: impl AsRef<Utf8Path>,
 ) {
+    #[cfg(test)]
+    tests::NUM_FLATC_INVOCATIONS.with(|num| num.set(num.get() + 1));
+
     let include_dir_path = include_dir_path.as_ref().as_str();
     let output_dir_path = output_dir_path.as_ref().as_str();
     let entrypoint_path = entrypoint_path.as_ref().as_str();
@@ -259,32 +353,101 @@ pub fn generate_lang_agnostic(
 ) -> (Objects, ArrowRegistry) {
     re_tracing::profile_function!();
 
-    use xshell::Shell;
-
-    let sh = Shell::new().unwrap();
-    let tmp = sh.create_temp_dir().unwrap();
+    let tmp = tempfile::tempdir().unwrap();
     let tmp_path = Utf8PathBuf::try_from(tmp.path().to_path_buf()).unwrap();
 
+    let include_dir_path = canonicalize_include_dir_path(include_dir_path.as_ref());
     let entrypoint_path = entrypoint_path.as_ref();
-    let entrypoint_filename = entrypoint_path.file_name().unwrap();
-
-    let include_dir_path = include_dir_path.as_ref();
-    let include_dir_path = include_dir_path
-        .canonicalize_utf8()
-        .with_context(|| format!("failed to canonicalize include path: {include_dir_path:?}"))
-        .unwrap();
 
     // generate bfbs definitions
     compile_binary_schemas(&include_dir_path, &tmp_path, entrypoint_path);
 
-    let mut binary_entrypoint_path = Utf8PathBuf::from(entrypoint_filename);
+    semantic_pass(
+        reporter,
+        include_dir_path,
+        &binary_entrypoint_path(&tmp_path, entrypoint_path),
+    )
+}
+
+/// Same as [`generate_lang_agnostic`], but the binary reflection dumps are cached in `cache_dir`.
+///
+/// The cache is keyed off the hash of all the definitions in `include_dir_path`, so `flatc` only
+/// runs when these change.
+/// `cache_dir` is owned by the cache: any stale entry in it gets removed.
+///
+/// Panics on error.
+pub fn generate_lang_agnostic_cached(
+    reporter: &Reporter,
+    include_dir_path: impl AsRef<Utf8Path>,
+    entrypoint_path: impl AsRef<Utf8Path>,
+    cache_dir: impl AsRef<Utf8Path>,
+) -> (Objects, ArrowRegistry) {
+    re_tracing::profile_function!();
+
+    let include_dir_path = canonicalize_include_dir_path(include_dir_path.as_ref());
+    let entrypoint_path = entrypoint_path.as_ref();
+    let cache_dir = cache_dir.as_ref();
+
+    let definitions_hash = compute_dir_hash(&include_dir_path, Some(&["fbs"]));
+    let cached_dir = cache_dir.join(&definitions_hash);
+
+    if cached_dir.exists() {
+        re_log::debug!("Reusing cached binary schemas from {cached_dir}");
+    } else {
+        std::fs::create_dir_all(cache_dir)
+            .with_context(|| format!("failed to create cache dir {cache_dir:?}"))
+            .unwrap();
+
+        // Compile to a temporary directory first, so that an interrupted run can't leave a
+        // half-written cache entry behind.
+        let tmp = tempfile::tempdir_in(cache_dir).unwrap();
+        let tmp_path = Utf8PathBuf::try_from(tmp.path().to_path_buf()).unwrap();
+        compile_binary_schemas(&include_dir_path, &tmp_path, entrypoint_path);
+        std::fs::rename(tmp.into_path(), &cached_dir)
+            .with_context(|| format!("failed to populate cache entry {cached_dir:?}"))
+            .unwrap();
+
+        // Remove the entries for older versions of the definitions:
+        for entry in std::fs::read_dir(cache_dir).unwrap().flatten() {
+            if entry.file_name() != definitions_hash.as_str() {
+                std::fs::remove_dir_all(entry.path()).ok();
+            }
+        }
+    }
+
+    semantic_pass(
+        reporter,
+        include_dir_path,
+        &binary_entrypoint_path(&cached_dir, entrypoint_path),
+    )
+}
+
+fn canonicalize_include_dir_path(include_dir_path: &Utf8Path) -> Utf8PathBuf {
+    include_dir_path
+        .canonicalize_utf8()
+        .with_context(|| format!("failed to canonicalize include path: {include_dir_path:?}"))
+        .unwrap()
+}
+
+/// Where `flatc` writes the binary schema for `entrypoint_path` when compiling to `output_dir_path`.
+fn binary_entrypoint_path(output_dir_path: &Utf8Path, entrypoint_path: &Utf8Path) -> Utf8PathBuf {
+    let mut binary_entrypoint_path = output_dir_path.join(entrypoint_path.file_name().unwrap());
     binary_entrypoint_path.set_extension("bfbs");
+    binary_entrypoint_path
+}
 
+/// Runs the semantic pass on the binary schemas, and computes the Arrow registry.
+fn semantic_pass(
+    reporter: &Reporter,
+    include_dir_path: Utf8PathBuf,
+    binary_entrypoint_path: &Utf8Path,
+) -> (Objects, ArrowRegistry) {
     // semantic pass: high level objects from low-level reflection data
     let mut objects = Objects::from_buf(
         reporter,
         include_dir_path,
-        sh.read_binary_file(tmp_path.join(binary_entrypoint_path))
+        std::fs::read(binary_entrypoint_path)
+            .with_context(|| format!("failed to read {binary_entrypoint_path:?}"))
             .unwrap()
             .as_slice(),
     );
@@ -298,6 +461,39 @@ pub fn generate_lang_agnostic(
     (objects, arrow_registry)
 }
 
+/// Runs [`generate_lang_agnostic`] on the workspace's own definitions, for use in tests.
+///
+/// Returns `None` if `flatc` is not available.
+#[cfg(test)]
+pub(crate) fn generate_lang_agnostic_for_tests(
+    reporter: &Reporter,
+) -> Option<(Objects, ArrowRegistry)> {
+    if !has_flatc() {
+        eprintln!("`flatc` not found, skipping");
+        return None;
+    }
+
+    let definitions_dir = rerun_workspace_path().join("crates/store/re_types/definitions");
+    let entrypoint_path = definitions_dir.join("entry_point.fbs");
+    Some(generate_lang_agnostic(
+        reporter,
+        definitions_dir,
+        entrypoint_path,
+    ))
+}
+
+#[cfg(test)]
+fn has_flatc() -> bool {
+    use xshell::{cmd, Shell};
+
+    let sh = Shell::new().unwrap();
+    cmd!(sh, "flatc --version")
+        .quiet()
+        .ignore_stdout()
+        .run()
+        .is_ok()
+}
+
 /// Generates .gitattributes files that mark up all generated files as generated.
 fn generate_gitattributes_for_generated_files(files_to_write: &mut GeneratedFiles) {
     re_tracing::profile_function!();
@@ -312,18 +508,28 @@ fn generate_gitattributes_for_generated_files(files_to_write: &mut GeneratedFile
         files.push(filepath.clone());
     }
 
+    // NOTE: Both the folders and their files are explicitly sorted, so that the output never
+    // depends on the order in which the files were generated.
     for (dirpath, files) in filepaths_per_folder {
         let gitattributes_path = dirpath.join(FILENAME);
 
-        let generated_files = std::iter::once(FILENAME.to_owned()) // The attributes itself is generated!
-            .chain(files.iter().map(|filepath| {
+        let mut filenames = files
+            .iter()
+            .map(|filepath| {
                 format_path(
                     filepath
                         .strip_prefix(&dirpath)
                         .context("Failed to make {filepath} relative to {dirpath}.")
                         .unwrap(),
                 )
-            }))
+            })
+            .filter(|filename| filename != FILENAME)
+            .collect::<Vec<_>>();
+        filenames.sort();
+        filenames.dedup();
+
+        let generated_files = std::iter::once(FILENAME.to_owned()) // The attributes itself is generated!
+            .chain(filenames)
             .map(|s| format!("{s} linguist-generated=true"))
             .collect::<Vec<_>>();
 
@@ -394,9 +600,16 @@ pub fn compute_re_types_hash(locations: &SourceLocations<'_>) -> String {
 
 /// Generates, formats and optionally writes code.
 ///
-/// If `check` is true, this will run a comparison check instead of writing files to disk.
+/// In [`CodegenMode::CheckOnly`], nothing is written: the differences with the files on disk are
+/// returned instead.
+///
+/// With [`ObjectFilter::Only`], only the files of the matching objects are kept (and thus
+/// formatted and written), and orphaned files are left alone.
+///
+/// `post_process` runs on every file after formatting, see [`PostProcess`].
 ///
 /// Panics on error.
+#[allow(clippy::too_many_arguments)]
 fn generate_code(
     reporter: &Reporter,
     objects: &Objects,
@@ -404,13 +617,21 @@ fn generate_code(
     generator: &mut dyn CodeGenerator,
     formatter: &mut dyn CodeFormatter,
     orphan_paths_opt_out: &BTreeSet<Utf8PathBuf>,
-    check: bool,
-) {
+    filter: &ObjectFilter,
+    mode: CodegenMode,
+    post_process: Option<&PostProcess>,
+) -> Vec<CodegenDiff> {
     use rayon::prelude::*;
 
     // Generate in-memory code files:
+    // NOTE: The generators always see all objects, since objects refer to each other.
     let mut files = generator.generate(reporter, objects, arrow_registry);
 
+    let is_filtered = *filter != ObjectFilter::All;
+    if is_filtered {
+        retain_files_of_objects(&mut files, objects, filter);
+    }
+
     for (filepath, contents) in &files {
         if !contents.contains("DO NOT EDIT") {
             reporter.error_file(
@@ -421,19 +642,38 @@ fn generate_code(
     }
 
     // Generate in-memory gitattribute files:
-    generate_gitattributes_for_generated_files(&mut files);
+    // (only when generating everything, otherwise they would only list the filtered files)
+    if !is_filtered {
+        generate_gitattributes_for_generated_files(&mut files);
+    }
 
     // Format in-memory files:
     formatter.format(reporter, &mut files);
 
-    if check {
-        files.par_iter().for_each(|(filepath, contents)| {
-            let cur_contents = std::fs::read_to_string(filepath).unwrap();
-            if *contents != cur_contents {
-                reporter.error(filepath.as_str(), "", "out of sync");
-            }
-        });
-        return;
+    if let Some(post_process) = post_process {
+        for (filepath, contents) in &mut files {
+            *contents = post_process(filepath, std::mem::take(contents));
+        }
+    }
+
+    if mode == CodegenMode::CheckOnly {
+        // NOTE: Orphaned files are left alone: we don't touch the file system in check mode.
+        let mut diffs: Vec<_> = files
+            .par_iter()
+            .filter_map(|(filepath, contents)| {
+                let current = std::fs::read_to_string(filepath).ok();
+                (current.as_ref() != Some(contents)).then(|| {
+                    reporter.error(filepath.as_str(), "", "out of sync");
+                    CodegenDiff {
+                        filepath: filepath.clone(),
+                        current,
+                        expected: contents.clone(),
+                    }
+                })
+            })
+            .collect();
+        diffs.sort_by(|a, b| a.filepath.cmp(&b.filepath));
+        return diffs;
     }
 
     // Write all files to filesystem:
@@ -446,15 +686,43 @@ fn generate_code(
     }
 
     // Remove orphaned files:
-    for path in orphan_paths_opt_out {
-        files.retain(|filepath, _| filepath.parent() != Some(path));
+    // (only when generating everything, otherwise every other file would look orphaned)
+    if !is_filtered {
+        for path in orphan_paths_opt_out {
+            files.retain(|filepath, _| filepath.parent() != Some(path));
+        }
+        crate::codegen::common::remove_orphaned_files(reporter, &files);
     }
-    crate::codegen::common::remove_orphaned_files(reporter, &files);
+
+    Vec::new()
+}
+
+/// Only keeps the files generated for the objects matching `filter`.
+///
+/// These are the ones named after the object, in a folder named after its kind,
+/// e.g. `components/position2d.rs` or `archetypes/points3d.hpp`.
+fn retain_files_of_objects(files: &mut GeneratedFiles, objects: &Objects, filter: &ObjectFilter) {
+    let kept: BTreeSet<(&str, String)> = objects
+        .values()
+        .filter(|obj| filter.matches(obj))
+        .map(|obj| (obj.kind.plural_snake_case(), obj.snake_case_name()))
+        .collect();
+
+    files.retain(|filepath, _| {
+        let folder_name = filepath.parent().and_then(|parent| parent.file_name());
+        match (folder_name, filepath.file_stem()) {
+            (Some(folder_name), Some(stem)) => kept.contains(&(folder_name, stem.to_owned())),
+            _ => false,
+        }
+    });
 }
 
 /// Generates C++ code.
 ///
-/// If `check` is true, this will run a comparison check instead of writing files to disk.
+/// In [`CodegenMode::CheckOnly`], nothing is written: the differences with the files on disk are
+/// returned instead.
+///
+/// Only the objects matching `filter` are regenerated, see [`ObjectFilter`].
 ///
 /// Panics on error.
 ///
@@ -464,8 +732,10 @@ pub fn generate_cpp_code(
     output_path: impl AsRef<Utf8Path>,
     objects: &Objects,
     arrow_registry: &ArrowRegistry,
-    check: bool,
-) {
+    filter: &ObjectFilter,
+    mode: CodegenMode,
+    post_process: Option<&PostProcess>,
+) -> Vec<CodegenDiff> {
     re_tracing::profile_function!();
 
     let mut generator = CppCodeGenerator::new(output_path.as_ref());
@@ -483,13 +753,52 @@ pub fn generate_cpp_code(
         &mut generator,
         &mut formatter,
         &std::iter::once(orphan_path_opt_out).collect(),
-        check,
-    );
+        filter,
+        mode,
+        post_process,
+    )
+}
+
+/// Generates TypeScript code.
+///
+/// In [`CodegenMode::CheckOnly`], nothing is written: the differences with the files on disk are
+/// returned instead.
+///
+/// Panics on error.
+///
+/// - `output_path`: path to the root of the output.
+pub fn generate_typescript_code(
+    reporter: &Reporter,
+    output_path: impl AsRef<Utf8Path>,
+    objects: &Objects,
+    arrow_registry: &ArrowRegistry,
+    mode: CodegenMode,
+    post_process: Option<&PostProcess>,
+) -> Vec<CodegenDiff> {
+    re_tracing::profile_function!();
+
+    let mut generator = TypeScriptCodeGenerator::new(output_path.as_ref());
+    let mut formatter = NoopCodeFormatter;
+
+    generate_code(
+        reporter,
+        objects,
+        arrow_registry,
+        &mut generator,
+        &mut formatter,
+        &Default::default(),
+        &ObjectFilter::All,
+        mode,
+        post_process,
+    )
 }
 
 /// Generates Rust code.
 ///
-/// If `check` is true, this will run a comparison check instead of writing files to disk.
+/// In [`CodegenMode::CheckOnly`], nothing is written: the differences with the files on disk are
+/// returned instead.
+///
+/// Only the objects matching `filter` are regenerated, see [`ObjectFilter`].
 ///
 /// Panics on error.
 pub fn generate_rust_code(
@@ -497,8 +806,10 @@ pub fn generate_rust_code(
     workspace_path: impl Into<Utf8PathBuf>,
     objects: &Objects,
     arrow_registry: &ArrowRegistry,
-    check: bool,
-) {
+    filter: &ObjectFilter,
+    mode: CodegenMode,
+    post_process: Option<&PostProcess>,
+) -> Vec<CodegenDiff> {
     re_tracing::profile_function!();
 
     let mut generator = RustCodeGenerator::new(workspace_path);
@@ -511,25 +822,33 @@ pub fn generate_rust_code(
         &mut generator,
         &mut formatter,
         &Default::default(),
-        check,
-    );
+        filter,
+        mode,
+        post_process,
+    )
 }
 
 /// Generates Python code.
 ///
-/// If `check` is true, this will run a comparison check instead of writing files to disk.
+/// In [`CodegenMode::CheckOnly`], nothing is written: the differences with the files on disk are
+/// returned instead.
+///
+/// Only the objects matching `filter` are regenerated, see [`ObjectFilter`].
 ///
 /// Panics on error.
 ///
 /// - `output_pkg_path`: path to the root of the output package.
+#[allow(clippy::too_many_arguments)]
 pub fn generate_python_code(
     reporter: &Reporter,
     output_pkg_path: impl AsRef<Utf8Path>,
     testing_output_pkg_path: impl AsRef<Utf8Path>,
     objects: &Objects,
     arrow_registry: &ArrowRegistry,
-    check: bool,
-) {
+    filter: &ObjectFilter,
+    mode: CodegenMode,
+    post_process: Option<&PostProcess>,
+) -> Vec<CodegenDiff> {
     re_tracing::profile_function!();
 
     let mut generator =
@@ -544,8 +863,10 @@ pub fn generate_python_code(
         &mut generator,
         &mut formatter,
         &Default::default(),
-        check,
-    );
+        filter,
+        mode,
+        post_process,
+    )
 }
 
 pub fn generate_docs(
@@ -553,8 +874,9 @@ pub fn generate_docs(
     output_docs_dir: impl AsRef<Utf8Path>,
     objects: &Objects,
     arrow_registry: &ArrowRegistry,
-    check: bool,
-) {
+    mode: CodegenMode,
+    post_process: Option<&PostProcess>,
+) -> Vec<CodegenDiff> {
     re_tracing::profile_function!();
 
     re_log::info!("Generating docs to {}", output_docs_dir.as_ref());
@@ -569,8 +891,45 @@ pub fn generate_docs(
         &mut generator,
         &mut formatter,
         &Default::default(),
-        check,
-    );
+        &ObjectFilter::All,
+        mode,
+        post_process,
+    )
+}
+
+/// Generates a machine-readable JSON description of all types, their Arrow datatypes and
+/// attributes.
+///
+/// In [`CodegenMode::CheckOnly`], nothing is written: the differences with the files on disk are
+/// returned instead.
+///
+/// Panics on error.
+///
+/// - `output_path`: path to the directory the JSON file is written to.
+pub fn generate_json_schema(
+    reporter: &Reporter,
+    output_path: impl AsRef<Utf8Path>,
+    objects: &Objects,
+    arrow_registry: &ArrowRegistry,
+    mode: CodegenMode,
+    post_process: Option<&PostProcess>,
+) -> Vec<CodegenDiff> {
+    re_tracing::profile_function!();
+
+    let mut generator = JsonSchemaCodeGenerator::new(output_path.as_ref());
+    let mut formatter = NoopCodeFormatter;
+
+    generate_code(
+        reporter,
+        objects,
+        arrow_registry,
+        &mut generator,
+        &mut formatter,
+        &Default::default(),
+        &ObjectFilter::All,
+        mode,
+        post_process,
+    )
 }
 
 pub fn generate_snippets_ref(
@@ -578,8 +937,9 @@ pub fn generate_snippets_ref(
     output_snippets_ref_dir: impl AsRef<Utf8Path>,
     objects: &Objects,
     arrow_registry: &ArrowRegistry,
-    check: bool,
-) {
+    mode: CodegenMode,
+    post_process: Option<&PostProcess>,
+) -> Vec<CodegenDiff> {
     re_tracing::profile_function!();
 
     re_log::info!(
@@ -600,14 +960,21 @@ pub fn generate_snippets_ref(
         &mut generator,
         &mut formatter,
         &std::iter::once(orphan_path_opt_out).collect(),
-        check,
-    );
+        &ObjectFilter::All,
+        mode,
+        post_process,
+    )
 }
 
 /// Generate flatbuffers definition files.
 ///
 /// This should run as the first step in the codegen pipeline as it influences all others.
-pub fn generate_fbs(reporter: &Reporter, definition_dir: impl AsRef<Utf8Path>, check: bool) {
+pub fn generate_fbs(
+    reporter: &Reporter,
+    definition_dir: impl AsRef<Utf8Path>,
+    mode: CodegenMode,
+    post_process: Option<&PostProcess>,
+) -> Vec<CodegenDiff> {
     re_tracing::profile_function!();
 
     let mut generator = FbsCodeGenerator::new(definition_dir.as_ref());
@@ -631,8 +998,10 @@ pub fn generate_fbs(reporter: &Reporter, definition_dir: impl AsRef<Utf8Path>, c
         &mut generator,
         &mut formatter,
         &orphan_path_opt_outs,
-        check,
-    );
+        &ObjectFilter::All,
+        mode,
+        post_process,
+    )
 }
 
 pub(crate) fn rerun_workspace_path() -> camino::Utf8PathBuf {
@@ -678,3 +1047,288 @@ pub(crate) fn rerun_workspace_path() -> camino::Utf8PathBuf {
 pub(crate) fn format_path(path: impl AsRef<Utf8Path>) -> String {
     path.as_ref().as_str().replace('\\', "/")
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    thread_local! {
+        /// How many times [`compile_binary_schemas`] ran on the current test thread.
+        pub static NUM_FLATC_INVOCATIONS: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+    }
+
+    /// Generates a single file with fixed contents.
+    struct TestCodeGenerator {
+        output_path: Utf8PathBuf,
+    }
+
+    impl CodeGenerator for TestCodeGenerator {
+        fn generate(
+            &mut self,
+            _reporter: &Reporter,
+            _objects: &Objects,
+            _arrow_registry: &ArrowRegistry,
+        ) -> GeneratedFiles {
+            std::iter::once((
+                self.output_path.join("generated.txt"),
+                format!("// {}\n", crate::codegen::autogen_warning!()),
+            ))
+            .collect()
+        }
+    }
+
+    /// Generates one file per object, plus a file shared by all objects of the same kind.
+    struct PerObjectTestCodeGenerator {
+        output_path: Utf8PathBuf,
+    }
+
+    impl CodeGenerator for PerObjectTestCodeGenerator {
+        fn generate(
+            &mut self,
+            _reporter: &Reporter,
+            objects: &Objects,
+            _arrow_registry: &ArrowRegistry,
+        ) -> GeneratedFiles {
+            let autogen_warning = crate::codegen::autogen_warning!();
+
+            objects
+                .values()
+                .flat_map(|obj| {
+                    let mut folder_path = self.output_path.clone();
+                    if obj.is_testing() {
+                        folder_path.push("testing");
+                    }
+                    folder_path.push(obj.kind.plural_snake_case());
+
+                    [
+                        (
+                            folder_path.join(format!("{}.txt", obj.snake_case_name())),
+                            format!("// {autogen_warning}\n{}\n", obj.fqname),
+                        ),
+                        (
+                            folder_path.join("mod.txt"),
+                            format!("// {autogen_warning}\n"),
+                        ),
+                    ]
+                })
+                .collect()
+        }
+    }
+
+    #[test]
+    fn test_gitattributes_are_deterministic() {
+        let filepaths = [
+            "rerun_py/rerun_sdk/rerun/datatypes/vec3d.py",
+            "rerun_py/rerun_sdk/rerun/components/color.py",
+            "rerun_py/rerun_sdk/rerun/datatypes/angle.py",
+            "rerun_py/rerun_sdk/rerun/datatypes/__init__.py",
+            "rerun_py/rerun_sdk/rerun/components/__init__.py",
+        ];
+
+        let gitattributes = |filepaths: &mut dyn Iterator<Item = &&str>| {
+            let mut files = GeneratedFiles::default();
+            for filepath in filepaths {
+                files.insert(Utf8PathBuf::from(*filepath), String::new());
+            }
+            generate_gitattributes_for_generated_files(&mut files);
+            files
+                .into_iter()
+                .filter(|(filepath, _)| filepath.file_name() == Some(".gitattributes"))
+                .collect::<Vec<_>>()
+        };
+
+        let forward = gitattributes(&mut filepaths.iter());
+        let backward = gitattributes(&mut filepaths.iter().rev());
+        assert_eq!(forward, backward);
+
+        assert_eq!(forward.len(), 2);
+        let (filepath, contents) = &forward[1];
+        assert_eq!(
+            filepath.as_str(),
+            "rerun_py/rerun_sdk/rerun/datatypes/.gitattributes"
+        );
+        assert!(contents.ends_with(
+            "\n\n.gitattributes linguist-generated=true\n\
+             __init__.py linguist-generated=true\n\
+             angle.py linguist-generated=true\n\
+             vec3d.py linguist-generated=true\n"
+        ));
+    }
+
+    #[test]
+    fn test_check_only_mode() {
+        let (_report, reporter) = crate::report::init();
+        let tmp = tempfile::tempdir().unwrap();
+        let output_path = Utf8PathBuf::try_from(tmp.path().to_path_buf()).unwrap();
+
+        let run = |mode| {
+            generate_code(
+                &reporter,
+                &Objects::default(),
+                &ArrowRegistry::default(),
+                &mut TestCodeGenerator {
+                    output_path: output_path.clone(),
+                },
+                &mut NoopCodeFormatter,
+                &Default::default(),
+                &ObjectFilter::All,
+                mode,
+                None,
+            )
+        };
+
+        // Checking must not write anything, so the files stay missing:
+        for _ in 0..2 {
+            let diffs = run(CodegenMode::CheckOnly);
+            assert_eq!(
+                diffs
+                    .iter()
+                    .map(|diff| diff.filepath.clone())
+                    .collect::<Vec<_>>(),
+                vec![
+                    output_path.join(".gitattributes"),
+                    output_path.join("generated.txt")
+                ]
+            );
+            assert!(diffs.iter().all(|diff| diff.current.is_none()));
+        }
+
+        assert!(run(CodegenMode::Write).is_empty());
+
+        for _ in 0..2 {
+            assert!(run(CodegenMode::CheckOnly).is_empty());
+        }
+    }
+
+    #[test]
+    fn test_object_filter() {
+        let (_report, reporter) = crate::report::init();
+        let Some((objects, arrow_registry)) = generate_lang_agnostic_for_tests(&reporter) else {
+            return;
+        };
+
+        let tmp = tempfile::tempdir().unwrap();
+        let output_path = Utf8PathBuf::try_from(tmp.path().to_path_buf()).unwrap();
+
+        let run = |filter: &ObjectFilter| {
+            generate_code(
+                &reporter,
+                &objects,
+                &arrow_registry,
+                &mut PerObjectTestCodeGenerator {
+                    output_path: output_path.clone(),
+                },
+                &mut NoopCodeFormatter,
+                &Default::default(),
+                filter,
+                CodegenMode::Write,
+                None,
+            )
+        };
+
+        run(&ObjectFilter::All);
+
+        let folder_path = output_path.join("testing/datatypes");
+        let filtered_path = folder_path.join("affix_fuzzer1.txt");
+        let untouched_paths = [
+            folder_path.join("affix_fuzzer2.txt"),
+            folder_path.join("mod.txt"),
+            folder_path.join(".gitattributes"),
+            folder_path.join("orphan.txt"),
+        ];
+        for path in std::iter::once(&filtered_path).chain(&untouched_paths) {
+            std::fs::write(path, "edited").unwrap();
+        }
+
+        run(&ObjectFilter::Only(
+            std::iter::once("rerun.testing.datatypes.AffixFuzzer1".to_owned()).collect(),
+        ));
+
+        assert!(std::fs::read_to_string(&filtered_path)
+            .unwrap()
+            .contains("rerun.testing.datatypes.AffixFuzzer1"));
+        for path in &untouched_paths {
+            assert_eq!(std::fs::read_to_string(path).unwrap(), "edited", "{path}");
+        }
+
+        // Regenerating everything fixes up the rest:
+        run(&ObjectFilter::All);
+
+        assert!(!folder_path.join("orphan.txt").exists());
+        assert!(
+            std::fs::read_to_string(folder_path.join("affix_fuzzer2.txt"))
+                .unwrap()
+                .contains("rerun.testing.datatypes.AffixFuzzer2")
+        );
+    }
+
+    #[test]
+    fn test_post_process() {
+        let (_report, reporter) = crate::report::init();
+        let tmp = tempfile::tempdir().unwrap();
+        let output_path = Utf8PathBuf::try_from(tmp.path().to_path_buf()).unwrap();
+
+        let prepend_license: Box<PostProcess> =
+            Box::new(|_filepath, contents| format!("SPDX-License-Identifier: MIT\n{contents}"));
+
+        let run = |mode| {
+            generate_code(
+                &reporter,
+                &Objects::default(),
+                &ArrowRegistry::default(),
+                &mut TestCodeGenerator {
+                    output_path: output_path.clone(),
+                },
+                &mut NoopCodeFormatter,
+                &Default::default(),
+                &ObjectFilter::All,
+                mode,
+                Some(prepend_license.as_ref()),
+            )
+        };
+
+        assert!(run(CodegenMode::Write).is_empty());
+
+        let mut num_files = 0;
+        for entry in std::fs::read_dir(&output_path).unwrap() {
+            let path = entry.unwrap().path();
+            let contents = std::fs::read_to_string(&path).unwrap();
+            assert!(
+                contents.starts_with("SPDX-License-Identifier: MIT\n"),
+                "{path:?}: {contents}"
+            );
+            num_files += 1;
+        }
+        assert_eq!(num_files, 2, "expected generated.txt and .gitattributes");
+
+        // Checking applies the same post-processing, so everything is in sync:
+        assert!(run(CodegenMode::CheckOnly).is_empty());
+    }
+
+    #[test]
+    #[ignore = "requires `flatc` in $PATH"]
+    fn test_generate_lang_agnostic_cached() {
+        let (_report, reporter) = crate::report::init();
+        let cache_dir = tempfile::tempdir().unwrap();
+        let cache_dir = Utf8PathBuf::try_from(cache_dir.path().to_path_buf()).unwrap();
+
+        let definitions_dir = rerun_workspace_path().join("crates/store/re_types/definitions");
+        let entrypoint_path = definitions_dir.join("entry_point.fbs");
+
+        let (objects, _) = generate_lang_agnostic_cached(
+            &reporter,
+            &definitions_dir,
+            &entrypoint_path,
+            &cache_dir,
+        );
+        let (cached_objects, _) = generate_lang_agnostic_cached(
+            &reporter,
+            &definitions_dir,
+            &entrypoint_path,
+            &cache_dir,
+        );
+
+        assert_eq!(NUM_FLATC_INVOCATIONS.with(|num| num.get()), 1);
+        assert!(objects.objects.keys().eq(cached_objects.objects.keys()));
+    }
+}
@@ -216,6 +216,32 @@ impl Objects {
     pub fn objects_of_kind(&self, kind: ObjectKind) -> impl Iterator<Item = &Object> {
         self.objects.values().filter(move |obj| obj.kind == kind)
     }
+
+    /// Reports all `attr.*` attributes that aren't in [`crate::KNOWN_ATTRS`], which are most
+    /// likely typos (e.g. `attr.rust.drive`) that would otherwise be silently ignored.
+    ///
+    /// These are reported as warnings, or as errors if `strict` is set.
+    pub fn report_unknown_attributes(&self, reporter: &Reporter, strict: bool) {
+        let report = |virtpath: &str, fqname: &str, attr: &str| {
+            let text = format!("Unknown attribute {attr:?}");
+            if strict {
+                reporter.error(virtpath, fqname, text);
+            } else {
+                reporter.warn(virtpath, fqname, text);
+            }
+        };
+
+        for obj in self.objects.values() {
+            for attr in obj.attrs.unknown_attrs() {
+                report(&obj.virtpath, &obj.fqname, attr);
+            }
+            for field in &obj.fields {
+                for attr in field.attrs.unknown_attrs() {
+                    report(&field.virtpath, &field.fqname, attr);
+                }
+            }
+        }
+    }
 }
 
 /// Returns a resolved object using its fully-qualified name.
@@ -459,6 +485,12 @@ impl Object {
             );
         }
 
+        assert!(
+            !attrs.has(crate::ATTR_RUST_NON_EXHAUSTIVE),
+            "{fqname}: {:?} is only supported on enums",
+            crate::ATTR_RUST_NON_EXHAUSTIVE
+        );
+
         Self {
             virtpath,
             filepath,
@@ -548,6 +580,12 @@ impl Object {
             fields.remove(0);
         }
 
+        assert!(
+            is_enum || !attrs.has(crate::ATTR_RUST_NON_EXHAUSTIVE),
+            "{fqname}: {:?} is only supported on enums",
+            crate::ATTR_RUST_NON_EXHAUSTIVE
+        );
+
         Self {
             virtpath,
             filepath,
@@ -666,6 +704,11 @@ impl Object {
         self.is_attr_set(crate::ATTR_RERUN_EXPERIMENTAL)
     }
 
+    /// Should new variants of this enum not be considered a breaking change?
+    pub fn is_non_exhaustive(&self) -> bool {
+        self.is_attr_set(crate::ATTR_RUST_NON_EXHAUSTIVE)
+    }
+
     pub fn doc_category(&self) -> Option<String> {
         self.try_get_attr::<String>(crate::ATTR_DOCS_CATEGORY)
     }
@@ -1359,6 +1402,14 @@ impl Attributes {
 }
 
 impl Attributes {
+    /// All `attr.*` attributes that aren't in [`crate::KNOWN_ATTRS`].
+    fn unknown_attrs(&self) -> impl Iterator<Item = &str> {
+        self.0
+            .keys()
+            .map(String::as_str)
+            .filter(|attr| attr.starts_with("attr.") && !crate::KNOWN_ATTRS.contains(attr))
+    }
+
     pub fn get<T>(&self, owner_fqname: impl AsRef<str>, name: impl AsRef<str>) -> T
     where
         T: std::str::FromStr,
@@ -1418,6 +1469,13 @@ impl Attributes {
     pub fn has(&self, name: impl AsRef<str>) -> bool {
         self.0.contains_key(name.as_ref())
     }
+
+    /// All attributes and their values, sorted by name.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, Option<&str>)> {
+        self.0
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.as_deref()))
+    }
 }
 
 fn filepath_from_declaration_file(
@@ -1445,3 +1503,59 @@ fn filepath_from_declaration_file(
         .canonicalize_utf8()
         .unwrap_or_else(|_| panic!("Failed to canonicalize declaration path {declaration_file:?}"))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_objects(attrs: &[&str]) -> Objects {
+        let attrs = Attributes(
+            attrs
+                .iter()
+                .map(|&attr| (attr.to_owned(), Some("Default".to_owned())))
+                .collect(),
+        );
+
+        Objects {
+            objects: std::iter::once((
+                "rerun.testing.datatypes.Fixture".to_owned(),
+                Object {
+                    virtpath: "fixture.fbs".to_owned(),
+                    filepath: "fixture.fbs".into(),
+                    fqname: "rerun.testing.datatypes.Fixture".to_owned(),
+                    pkg_name: "rerun.testing.datatypes".to_owned(),
+                    name: "Fixture".to_owned(),
+                    docs: Docs::default(),
+                    kind: ObjectKind::Datatype,
+                    attrs,
+                    fields: Vec::new(),
+                    class: ObjectClass::Struct,
+                    datatype: None,
+                },
+            ))
+            .collect(),
+        }
+    }
+
+    #[test]
+    fn test_report_unknown_attributes() {
+        let objects = test_objects(&[crate::ATTR_RUST_DERIVE, "attr.rust.drive"]);
+
+        let (report, reporter) = crate::report::init();
+        objects.report_unknown_attributes(&reporter, false);
+        let warnings = report.take_warnings();
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("\"attr.rust.drive\""));
+        assert!(report.take_errors().is_empty());
+
+        let (report, reporter) = crate::report::init();
+        objects.report_unknown_attributes(&reporter, true);
+        assert!(report.take_warnings().is_empty());
+        assert_eq!(report.take_errors().len(), 1);
+
+        let (report, reporter) = crate::report::init();
+        test_objects(&[crate::ATTR_RUST_DERIVE]).report_unknown_attributes(&reporter, true);
+        assert!(report.take_warnings().is_empty());
+        assert!(report.take_errors().is_empty());
+    }
+}
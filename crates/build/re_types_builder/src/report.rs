@@ -102,6 +102,16 @@ impl Report {
         }
     }
 
+    #[cfg(test)]
+    pub(crate) fn take_warnings(&self) -> Vec<String> {
+        self.warnings.try_iter().collect()
+    }
+
+    #[cfg(test)]
+    pub(crate) fn take_errors(&self) -> Vec<String> {
+        self.errors.try_iter().collect()
+    }
+
     /// This outputs all errors and warnings to stderr and panics if there were any errors.
     pub fn finalize(&self) {
         use colored::Colorize;
@@ -20,7 +20,7 @@ impl std::fmt::Debug for LatestAtQuery {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.write_fmt(format_args!(
             "<latest-at {} on {:?}>",
-            self.timeline.typ().format_utc(self.at),
+            self.timeline.format_time_utc(self.at),
             self.timeline.name(),
         ))
     }
@@ -77,8 +77,8 @@ impl std::fmt::Debug for RangeQuery {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.write_fmt(format_args!(
             "<ranging {}..={} on {:?} ([{}]keep_timelines [{}]keep_components [{}]extended_bounds)>",
-            self.timeline.typ().format_utc(self.range.min()),
-            self.timeline.typ().format_utc(self.range.max()),
+            self.timeline.format_time_utc(self.range.min()),
+            self.timeline.format_time_utc(self.range.max()),
             self.timeline.name(),
             if self.options.keep_extra_timelines {
                 "✓"
@@ -1,4 +1,4 @@
-use re_chunk::{ChunkId, Timeline};
+use re_chunk::{ChunkId, EntityPath, Timeline};
 use re_log_types::ResolvedTimeRange;
 
 use crate::{ChunkStore, ChunkStoreEvent};
@@ -111,4 +111,80 @@ impl ChunkStore {
 
         events
     }
+
+    /// Drop all the data of the given `entity_path` on the given timeline.
+    ///
+    /// Chunks that only have data on that timeline are dropped altogether.
+    /// Chunks that also have data on other timelines lose their time column for that timeline,
+    /// but keep their events on all other timelines.
+    ///
+    /// Static chunks are unaffected.
+    ///
+    /// This is _not_ recursive. The store is unaware of the entity hierarchy.
+    pub fn drop_entity_path_on_timeline(
+        &mut self,
+        entity_path: &EntityPath,
+        timeline: &Timeline,
+    ) -> Vec<ChunkStoreEvent> {
+        re_tracing::profile_function!(entity_path.to_string());
+
+        let chunk_ids_to_drop: Vec<ChunkId> = self
+            .temporal_chunk_ids_per_entity
+            .get(entity_path)
+            .and_then(|temporal_chunk_ids_per_timeline| {
+                temporal_chunk_ids_per_timeline.get(timeline)
+            })
+            .map(|temporal_chunk_ids_per_time| {
+                temporal_chunk_ids_per_time
+                    .per_start_time
+                    .values()
+                    .flatten()
+                    .copied()
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        // Chunks with data on other timelines are re-inserted without this timeline.
+        let new_chunks: Vec<_> = chunk_ids_to_drop
+            .iter()
+            .filter_map(|chunk_id| self.chunks_per_chunk_id.get(chunk_id))
+            .filter(|chunk| chunk.num_timelines() > 1)
+            .map(|chunk| {
+                let timelines_to_keep = chunk
+                    .timelines()
+                    .keys()
+                    .filter(|chunk_timeline| *chunk_timeline != timeline)
+                    .copied()
+                    .collect();
+                chunk
+                    .timelines_sliced(&timelines_to_keep)
+                    .with_id(ChunkId::new())
+            })
+            .collect();
+
+        let generation = self.generation();
+        let mut events: Vec<ChunkStoreEvent> = vec![];
+
+        for chunk_id in chunk_ids_to_drop {
+            for diff in self.remove_chunk(chunk_id) {
+                events.push(ChunkStoreEvent {
+                    store_id: self.id.clone(),
+                    store_generation: generation.clone(),
+                    event_id: self
+                        .event_id
+                        .fetch_add(1, std::sync::atomic::Ordering::Relaxed),
+                    diff,
+                });
+            }
+        }
+
+        Self::on_events(&events);
+
+        for chunk in new_chunks {
+            #[allow(clippy::unwrap_used)] // The chunk came from the store, so it should be fine
+            events.append(&mut self.insert_chunk(&chunk.into()).unwrap());
+        }
+
+        events
+    }
 }
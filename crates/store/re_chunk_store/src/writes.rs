@@ -61,7 +61,7 @@ impl ChunkStore {
         // * An SDK logs a blueprint (tagged), which is then updated by the viewer (which uses untagged log calls).
         // * Somebody loads an old .rbl from somewhere and starts logging new blueprint data to it.
         // * Etc.
-        if self.id.kind == re_log_types::StoreKind::Blueprint {
+        if self.id.is_blueprint() {
             let patched = chunk.patched_for_blueprint_021_compat();
             let patched = patched.clone_as_untagged();
             chunk = Arc::new(patched);
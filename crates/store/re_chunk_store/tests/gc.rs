@@ -555,3 +555,122 @@ fn manual_drop_entity_path() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn manual_drop_entity_path_on_timeline() -> anyhow::Result<()> {
+    re_log::setup_logging();
+
+    let mut store = ChunkStore::new(
+        re_log_types::StoreId::random(re_log_types::StoreKind::Recording),
+        Default::default(),
+    );
+
+    let entity_path = EntityPath::from("entity");
+    let frame_nr = Timeline::new_sequence("frame_nr");
+
+    let chunk_frame_nr = Arc::new(
+        Chunk::builder(entity_path.clone())
+            .with_component_batches(
+                RowId::new(),
+                [build_frame_nr(10)],
+                [&MyIndex::from_iter(0..3) as _],
+            )
+            .build()?,
+    );
+
+    let row_id_log_time = RowId::new();
+    let chunk_log_time = Arc::new(
+        Chunk::builder(entity_path.clone())
+            .with_component_batches(
+                row_id_log_time,
+                [build_log_time(Time::now())],
+                [&MyIndex::from_iter(0..3) as _],
+            )
+            .build()?,
+    );
+
+    let row_id_static = RowId::new();
+    let chunk_static = Arc::new(
+        Chunk::builder(entity_path.clone())
+            .with_component_batches(
+                row_id_static,
+                TimePoint::default(),
+                [&MyIndex::from_iter(0..3) as _],
+            )
+            .build()?,
+    );
+
+    store.insert_chunk(&chunk_frame_nr)?;
+    store.insert_chunk(&chunk_log_time)?;
+    store.insert_chunk(&chunk_static)?;
+
+    let events = store.drop_entity_path_on_timeline(&entity_path, &frame_nr);
+    assert_eq!(1, events.len());
+    assert_eq!(ChunkStoreDiffKind::Deletion, events[0].kind);
+    similar_asserts::assert_eq!(chunk_frame_nr, events[0].chunk);
+
+    assert!(store.chunk(&chunk_frame_nr.id()).is_none());
+    assert!(store.chunk(&chunk_log_time.id()).is_some());
+    assert!(store.chunk(&chunk_static.id()).is_some());
+
+    let events = store.drop_entity_path_on_timeline(&entity_path, &frame_nr);
+    assert!(events.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn manual_drop_entity_path_on_timeline_keeps_other_timelines() -> anyhow::Result<()> {
+    re_log::setup_logging();
+
+    let mut store = ChunkStore::new(
+        re_log_types::StoreId::random(re_log_types::StoreKind::Recording),
+        Default::default(),
+    );
+
+    let entity_path = EntityPath::from("entity");
+    let frame_nr = Timeline::new_sequence("frame_nr");
+
+    let row_id = RowId::new();
+    let chunk = Arc::new(
+        Chunk::builder(entity_path.clone())
+            .with_component_batches(
+                row_id,
+                [build_frame_nr(10), build_log_time(Time::now())],
+                [&MyIndex::from_iter(0..3) as _],
+            )
+            .build()?,
+    );
+
+    store.insert_chunk(&chunk)?;
+
+    let events = store.drop_entity_path_on_timeline(&entity_path, &frame_nr);
+    assert_eq!(2, events.len());
+    assert_eq!(ChunkStoreDiffKind::Deletion, events[0].kind);
+    similar_asserts::assert_eq!(chunk, events[0].chunk);
+    assert_eq!(ChunkStoreDiffKind::Addition, events[1].kind);
+
+    assert!(store.chunk(&chunk.id()).is_none());
+
+    let remaining = query_latest_array(
+        &store,
+        &entity_path,
+        MyIndex::name(),
+        &LatestAtQuery::new(Timeline::log_time(), TimeInt::MAX),
+    )
+    .map(|(_data_time, row_id, _array)| row_id);
+    assert_eq!(Some(row_id), remaining);
+
+    let dropped = query_latest_array(
+        &store,
+        &entity_path,
+        MyIndex::name(),
+        &LatestAtQuery::new(frame_nr, TimeInt::MAX),
+    );
+    assert!(dropped.is_none());
+
+    let events = store.drop_entity_path_on_timeline(&entity_path, &frame_nr);
+    assert!(events.is_empty());
+
+    Ok(())
+}
@@ -4,7 +4,7 @@ use itertools::Itertools;
 
 use re_dataframe::{
     ChunkStoreConfig, EntityPathFilter, QueryEngine, QueryExpression, ResolvedTimeRange,
-    SparseFillStrategy, StoreKind, TimeInt, Timeline,
+    SparseFillStrategy, TimeInt, Timeline,
 };
 use re_log_encoding::VersionPolicy;
 
@@ -48,7 +48,7 @@ fn main() -> anyhow::Result<()> {
     )?;
 
     for (store_id, engine) in &engines {
-        if store_id.kind != StoreKind::Recording {
+        if !store_id.is_recording() {
             continue;
         }
 
@@ -3,7 +3,10 @@ use std::sync::Arc;
 use nohash_hasher::IntMap;
 use parking_lot::Mutex;
 
-use re_chunk::{Chunk, ChunkResult, RowId, TimeInt};
+use re_chunk::{
+    external::arrow2::array::BooleanArray as Arrow2BooleanArray, Chunk, ChunkId, ChunkResult,
+    RowId, TimeInt,
+};
 use re_chunk_store::{
     ChunkStore, ChunkStoreChunkStats, ChunkStoreConfig, ChunkStoreDiffKind, ChunkStoreEvent,
     ChunkStoreHandle, ChunkStoreSubscriber, GarbageCollectionOptions, GarbageCollectionTarget,
@@ -525,6 +528,46 @@ impl EntityDb {
         }
     }
 
+    /// Drops all the temporal data on the given timeline for a given [`EntityPath`] and all its children.
+    ///
+    /// Data that was also logged on other timelines is kept on those.
+    ///
+    /// Static data is unaffected.
+    pub fn drop_entity_path_recursive_on_timeline(
+        &mut self,
+        entity_path: &EntityPath,
+        timeline: &Timeline,
+    ) {
+        re_tracing::profile_function!();
+
+        let mut to_drop = vec![entity_path.clone()];
+
+        if let Some(tree) = self.tree().subtree(entity_path) {
+            tree.visit_children_recursively(|path| {
+                to_drop.push(path.clone());
+            });
+        }
+
+        let mut engine = self.storage_engine.write();
+
+        let store_events = to_drop
+            .iter()
+            .flat_map(|entity_path| {
+                engine
+                    .store()
+                    .drop_entity_path_on_timeline(entity_path, timeline)
+            })
+            .collect::<Vec<_>>();
+
+        Self::on_store_deletions(
+            &mut self.times_per_timeline,
+            &mut self.time_histogram_per_timeline,
+            &mut self.tree,
+            engine,
+            &store_events,
+        );
+    }
+
     // NOTE: Parameters deconstructed instead of taking `self`, because borrowck cannot understand
     // partial borrows on methods.
     fn on_store_deletions(
@@ -634,6 +677,61 @@ impl EntityDb {
     pub fn clone_with_new_id(&self, new_id: StoreId) -> Result<Self, Error> {
         re_tracing::profile_function!();
 
+        let mut new_db = self.empty_clone_with_new_id(new_id);
+
+        let engine = self.storage_engine.read();
+        for chunk in engine.store().iter_chunks() {
+            new_db.add_chunk(&Arc::clone(chunk))?;
+        }
+
+        Ok(new_db)
+    }
+
+    /// Make a clone of this [`EntityDb`] with a new [`StoreId`], keeping only the data
+    /// that falls within `time_range` on `timeline`.
+    ///
+    /// Static data is always kept.
+    /// Temporal data that isn't logged on `timeline` at all is dropped.
+    pub fn clone_with_new_id_and_time_range(
+        &self,
+        new_id: StoreId,
+        timeline: &Timeline,
+        time_range: ResolvedTimeRange,
+    ) -> Result<Self, Error> {
+        re_tracing::profile_function!();
+
+        let mut new_db = self.empty_clone_with_new_id(new_id);
+
+        let engine = self.storage_engine.read();
+        for chunk in engine.store().iter_chunks() {
+            if chunk.is_static() {
+                new_db.add_chunk(&Arc::clone(chunk))?;
+                continue;
+            }
+
+            let Some(time_column) = chunk.timelines().get(timeline) else {
+                continue;
+            };
+
+            if time_range.contains_range(time_column.time_range()) {
+                new_db.add_chunk(&Arc::clone(chunk))?;
+            } else if time_range.intersects(time_column.time_range()) {
+                let mask: Vec<bool> = time_column
+                    .times()
+                    .map(|time| time_range.contains(time))
+                    .collect();
+                let filter = Arrow2BooleanArray::from_slice(mask);
+                if let Some(filtered) = chunk.filtered(&filter) {
+                    new_db.add_chunk(&Arc::new(filtered.with_id(ChunkId::new())))?;
+                }
+            }
+        }
+
+        Ok(new_db)
+    }
+
+    /// An empty [`EntityDb`] with a new [`StoreId`], but otherwise the same store info as this one.
+    fn empty_clone_with_new_id(&self, new_id: StoreId) -> Self {
         let mut new_db = Self::new(new_id.clone());
 
         new_db.last_modified_at = self.last_modified_at;
@@ -658,12 +756,7 @@ impl EntityDb {
             });
         }
 
-        let engine = self.storage_engine.read();
-        for chunk in engine.store().iter_chunks() {
-            new_db.add_chunk(&Arc::clone(chunk))?;
-        }
-
-        Ok(new_db)
+        new_db
     }
 }
 
@@ -736,6 +829,23 @@ impl EntityDb {
             .is_some()
     }
 
+    /// Returns true if an entity or any of its children have any static data.
+    pub fn subtree_has_static_data(
+        &self,
+        engine: &StorageEngineReadGuard<'_>,
+        entity_path: &EntityPath,
+    ) -> bool {
+        re_tracing::profile_function!();
+
+        let Some(subtree) = self.tree.subtree(entity_path) else {
+            return false;
+        };
+
+        subtree
+            .find_first_child_recursive(|path| engine.store().entity_has_static_data(path))
+            .is_some()
+    }
+
     /// Returns true if an entity or any of its children have any temporal data on the given timeline.
     ///
     /// This ignores static data.
@@ -68,6 +68,14 @@ pub(crate) fn encode_arrow(
     let data = match compression {
         crate::Compression::Off => uncompressed,
         crate::Compression::LZ4 => lz4_flex::block::compress(&uncompressed),
+        crate::Compression::Zstd { level } => {
+            let mut compressed = Vec::new();
+            let compressed_len =
+                crate::zstd_compression::compress(&uncompressed, &mut compressed, level)
+                    .map_err(crate::encoder::EncodeError::Zstd)?;
+            compressed.truncate(compressed_len);
+            compressed
+        }
     };
 
     Ok(Payload {
@@ -90,6 +98,12 @@ pub(crate) fn decode_arrow(
             lz4_flex::block::decompress_into(data, &mut uncompressed)?;
             uncompressed.as_slice()
         }
+        crate::Compression::Zstd { .. } => {
+            uncompressed.resize(uncompressed_size, 0);
+            crate::zstd_compression::decompress(data, &mut uncompressed)
+                .map_err(crate::decoder::DecodeError::Zstd)?;
+            uncompressed.as_slice()
+        }
     };
 
     Ok(read_arrow_from_bytes(&mut &data[..])?)
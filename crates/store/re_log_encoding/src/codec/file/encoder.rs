@@ -2,7 +2,8 @@ use super::{MessageHeader, MessageKind};
 use crate::codec::arrow::encode_arrow;
 use crate::encoder::EncodeError;
 use crate::Compression;
-use re_log_types::LogMsg;
+use re_log_types::{LogMsg, StoreId};
+use re_protos::log_msg::v0 as proto;
 
 pub(crate) fn encode(
     buf: &mut Vec<u8>,
@@ -10,9 +11,7 @@ pub(crate) fn encode(
     compression: Compression,
 ) -> Result<(), EncodeError> {
     use re_protos::external::prost::Message;
-    use re_protos::log_msg::v0::{
-        self as proto, ArrowMsg, BlueprintActivationCommand, Encoding, SetStoreInfo,
-    };
+    use re_protos::log_msg::v0::{BlueprintActivationCommand, SetStoreInfo};
 
     match message {
         LogMsg::SetStoreInfo(set_store_info) => {
@@ -25,17 +24,7 @@ pub(crate) fn encode(
             set_store_info.encode(buf)?;
         }
         LogMsg::ArrowMsg(store_id, arrow_msg) => {
-            let payload = encode_arrow(&arrow_msg.schema, &arrow_msg.chunk, compression)?;
-            let arrow_msg = ArrowMsg {
-                store_id: Some(store_id.clone().into()),
-                compression: match compression {
-                    Compression::Off => proto::Compression::None as i32,
-                    Compression::LZ4 => proto::Compression::Lz4 as i32,
-                },
-                uncompressed_size: payload.uncompressed_size as i32,
-                encoding: Encoding::ArrowIpc as i32,
-                payload: payload.data,
-            };
+            let arrow_msg = arrow_msg_to_proto(store_id, arrow_msg, compression)?;
             let header = MessageHeader {
                 kind: MessageKind::ArrowMsg,
                 len: arrow_msg.encoded_len() as u64,
@@ -57,3 +46,42 @@ pub(crate) fn encode(
 
     Ok(())
 }
+
+/// The number of bytes [`encode`] would write for this message, header included.
+///
+/// Only the Arrow payload of a [`LogMsg::ArrowMsg`] actually gets encoded (and compressed),
+/// since there is no telling its size otherwise.
+pub(crate) fn encoded_len(message: &LogMsg, compression: Compression) -> Result<u64, EncodeError> {
+    use re_protos::external::prost::Message;
+    use re_protos::log_msg::v0::{BlueprintActivationCommand, SetStoreInfo};
+
+    let header_len = std::mem::size_of::<MessageHeader>();
+    let body_len = match message {
+        LogMsg::SetStoreInfo(set_store_info) => {
+            SetStoreInfo::from(set_store_info.clone()).encoded_len()
+        }
+        LogMsg::ArrowMsg(store_id, arrow_msg) => {
+            arrow_msg_to_proto(store_id, arrow_msg, compression)?.encoded_len()
+        }
+        LogMsg::BlueprintActivationCommand(blueprint_activation_command) => {
+            BlueprintActivationCommand::from(blueprint_activation_command.clone()).encoded_len()
+        }
+    };
+
+    Ok((header_len + body_len) as u64)
+}
+
+fn arrow_msg_to_proto(
+    store_id: &StoreId,
+    arrow_msg: &re_log_types::ArrowMsg,
+    compression: Compression,
+) -> Result<proto::ArrowMsg, EncodeError> {
+    let payload = encode_arrow(&arrow_msg.schema, &arrow_msg.chunk, compression)?;
+    Ok(proto::ArrowMsg {
+        store_id: Some(store_id.clone().into()),
+        compression: proto::Compression::from(compression) as i32,
+        uncompressed_size: payload.uncompressed_size as i32,
+        encoding: proto::Encoding::ArrowIpc as i32,
+        payload: payload.data,
+    })
+}
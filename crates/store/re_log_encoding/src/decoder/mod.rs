@@ -76,6 +76,9 @@ pub enum DecodeError {
     #[error("lz4 error: {0}")]
     Lz4(#[from] lz4_flex::block::DecompressError),
 
+    #[error("zstd error: {0}")]
+    Zstd(std::io::Error),
+
     #[error("Protobuf error: {0}")]
     Protobuf(#[from] re_protos::external::prost::DecodeError),
 
@@ -359,6 +362,30 @@ impl<R: std::io::Read> Iterator for Decoder<R> {
 
                                 self.size_bytes += compressed_len as u64;
                             }
+
+                            Compression::Zstd { .. } => {
+                                self.compressed
+                                    .resize(self.compressed.len().max(compressed_len), 0);
+
+                                {
+                                    re_tracing::profile_scope!("read compressed");
+                                    if let Err(err) =
+                                        self.read.read_exact(&mut self.compressed[..compressed_len])
+                                    {
+                                        return Some(Err(DecodeError::Read(err)));
+                                    }
+                                }
+
+                                re_tracing::profile_scope!("zstd");
+                                if let Err(err) = crate::zstd_compression::decompress(
+                                    &self.compressed[..compressed_len],
+                                    &mut self.uncompressed[..uncompressed_len],
+                                ) {
+                                    return Some(Err(DecodeError::Zstd(err)));
+                                }
+
+                                self.size_bytes += compressed_len as u64;
+                            }
                         }
 
                         let data = &self.uncompressed[..uncompressed_len];
@@ -491,6 +518,8 @@ mod tests {
                 compression: Compression::LZ4,
                 serializer: Serializer::Protobuf,
             },
+            EncodingOptions::MSGPACK_ZSTD,
+            EncodingOptions::PROTOBUF_ZSTD,
         ];
 
         for options in options {
@@ -509,6 +538,60 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_encode_to_bytes() {
+        let messages = fake_log_messages();
+
+        for options in [
+            EncodingOptions::MSGPACK_COMPRESSED,
+            EncodingOptions::PROTOBUF_COMPRESSED,
+        ] {
+            let bytes = crate::encoder::encode_as_bytes(
+                CrateVersion::LOCAL,
+                options,
+                messages.iter().cloned().map(Ok),
+            )
+            .unwrap();
+
+            let mut decoded_messages = Decoder::new(VersionPolicy::Error, bytes.as_slice())
+                .unwrap()
+                .collect::<Result<Vec<LogMsg>, DecodeError>>()
+                .unwrap();
+            clear_arrow_extension_metadata(&mut decoded_messages);
+            assert_eq!(messages, decoded_messages);
+
+            // Same thing, just without the end-of-stream marker:
+            let unfinished_bytes =
+                crate::encoder::encode_to_bytes(CrateVersion::LOCAL, options, &messages).unwrap();
+            assert!(unfinished_bytes.len() < bytes.len());
+            assert!(bytes.starts_with(&unfinished_bytes));
+        }
+    }
+
+    #[test]
+    fn test_estimated_wire_size() {
+        let messages = fake_log_messages();
+
+        for options in [
+            EncodingOptions {
+                compression: Compression::Off,
+                serializer: Serializer::Protobuf,
+            },
+            EncodingOptions::PROTOBUF_COMPRESSED,
+            EncodingOptions::PROTOBUF_ZSTD,
+        ] {
+            let mut encoder =
+                crate::encoder::Encoder::new(CrateVersion::LOCAL, options, vec![]).unwrap();
+
+            for message in &messages {
+                let estimated_size =
+                    crate::encoder::estimated_wire_size(message, options.compression).unwrap();
+                let encoded_size = encoder.append(message).unwrap();
+                assert_eq!(estimated_size, encoded_size, "{options:?}");
+            }
+        }
+    }
+
     #[test]
     fn test_concatenated_streams() {
         let options = [
@@ -528,6 +611,8 @@ mod tests {
                 compression: Compression::LZ4,
                 serializer: Serializer::Protobuf,
             },
+            EncodingOptions::MSGPACK_ZSTD,
+            EncodingOptions::PROTOBUF_ZSTD,
         ];
 
         for options in options {
@@ -570,4 +655,63 @@ mod tests {
             assert_eq!([messages.clone(), messages].concat(), decoded_messages);
         }
     }
+
+    #[cfg(feature = "zstd")]
+    #[test]
+    fn test_zstd_compresses_better_than_lz4() {
+        let store_id = StoreId::random(StoreKind::Recording);
+        let messages = (0..10)
+            .map(|frame| {
+                let positions = (0..1_000).map(|i| [(i % 100) as f32, (i / 100) as f32, 0.0]);
+                let chunk = re_chunk::Chunk::builder("points".into())
+                    .with_archetype(
+                        RowId::new(),
+                        re_log_types::TimePoint::default().with(
+                            re_log_types::Timeline::new_sequence("frame"),
+                            re_log_types::TimeInt::new_temporal(frame),
+                        ),
+                        &re_types::archetypes::Points3D::new(positions),
+                    )
+                    .build()
+                    .unwrap();
+                LogMsg::ArrowMsg(store_id.clone(), chunk.to_arrow_msg().unwrap())
+            })
+            .collect::<Vec<_>>();
+
+        for serializer in [Serializer::MsgPack, Serializer::Protobuf] {
+            let encode = |compression| {
+                let mut file = vec![];
+                let options = EncodingOptions {
+                    compression,
+                    serializer,
+                };
+                crate::encoder::encode_ref(
+                    CrateVersion::LOCAL,
+                    options,
+                    messages.iter().map(Ok),
+                    &mut file,
+                )
+                .unwrap();
+                file
+            };
+
+            let lz4 = encode(Compression::LZ4);
+            let zstd = encode(Compression::Zstd { level: 19 });
+            assert!(
+                zstd.len() < lz4.len(),
+                "{serializer:?}: zstd ({} bytes) should be smaller than lz4 ({} bytes)",
+                zstd.len(),
+                lz4.len()
+            );
+
+            for file in [lz4, zstd] {
+                let mut decoded_messages = Decoder::new(VersionPolicy::Error, file.as_slice())
+                    .unwrap()
+                    .collect::<Result<Vec<LogMsg>, DecodeError>>()
+                    .unwrap();
+                clear_arrow_extension_metadata(&mut decoded_messages);
+                assert_eq!(messages, decoded_messages);
+            }
+        }
+    }
 }
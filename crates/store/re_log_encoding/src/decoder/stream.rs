@@ -130,6 +130,15 @@ impl StreamDecoder {
                                         .map_err(DecodeError::Lz4)?;
                                     &self.uncompressed
                                 }
+                                Compression::Zstd { .. } => {
+                                    self.uncompressed.resize(uncompressed_len as usize, 0);
+                                    crate::zstd_compression::decompress(
+                                        bytes,
+                                        &mut self.uncompressed,
+                                    )
+                                    .map_err(DecodeError::Zstd)?;
+                                    &self.uncompressed
+                                }
                             };
 
                             // read the message from the uncompressed bytes
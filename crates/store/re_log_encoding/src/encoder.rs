@@ -21,6 +21,9 @@ pub enum EncodeError {
     #[error("lz4 error: {0}")]
     Lz4(#[from] lz4_flex::block::CompressError),
 
+    #[error("zstd error: {0}")]
+    Zstd(std::io::Error),
+
     #[error("MsgPack error: {0}")]
     MsgPack(#[from] rmp_serde::encode::Error),
 
@@ -42,6 +45,10 @@ pub enum EncodeError {
 
 // ----------------------------------------------------------------------------
 
+/// Encode the given messages into an in-memory `.rrd` stream.
+///
+/// No end-of-stream marker is written, so more messages can be appended later on.
+/// Use [`encode_as_bytes`] for a complete stream.
 pub fn encode_to_bytes<'a>(
     version: CrateVersion,
     options: EncodingOptions,
@@ -57,6 +64,17 @@ pub fn encode_to_bytes<'a>(
     Ok(bytes)
 }
 
+/// The size in bytes of `message` once encoded as protobuf with the given compression,
+/// i.e. what [`Encoder::append`] returns when using [`Serializer::Protobuf`].
+///
+/// Useful to batch messages up to some size limit before sending them.
+/// Store infos and blueprint commands are measured without being serialized, but the Arrow
+/// payload of a [`LogMsg::ArrowMsg`] has to be encoded (and compressed) to know its size.
+pub fn estimated_wire_size(message: &LogMsg, compression: Compression) -> Result<u64, EncodeError> {
+    re_tracing::profile_function!();
+    encoder::encoded_len(message, compression)
+}
+
 // ----------------------------------------------------------------------------
 
 /// An [`Encoder`] that properly closes the stream on drop.
@@ -198,6 +216,24 @@ impl<W: std::io::Write> Encoder<W> {
                             .map(|_| compressed_len as _)
                             .map_err(EncodeError::Write)
                     }
+
+                    Compression::Zstd { level } => {
+                        let compressed_len = crate::zstd_compression::compress(
+                            &self.uncompressed,
+                            &mut self.compressed,
+                            level,
+                        )
+                        .map_err(EncodeError::Zstd)?;
+                        MessageHeader::Data {
+                            uncompressed_len: self.uncompressed.len() as u32,
+                            compressed_len: compressed_len as u32,
+                        }
+                        .encode(&mut self.write)?;
+                        self.write
+                            .write_all(&self.compressed[..compressed_len])
+                            .map(|_| compressed_len as _)
+                            .map_err(EncodeError::Write)
+                    }
                 }
             }
         }
@@ -265,6 +301,8 @@ pub fn encode_ref<'a>(
     Ok(size_bytes)
 }
 
+/// Encode the given messages into a complete in-memory `.rrd` stream, including the
+/// end-of-stream marker.
 pub fn encode_as_bytes(
     version: CrateVersion,
     options: EncodingOptions,
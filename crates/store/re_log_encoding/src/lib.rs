@@ -10,6 +10,9 @@ pub mod codec;
 
 mod protobuf_conversions;
 
+#[cfg(any(feature = "encoder", feature = "decoder"))]
+mod zstd_compression;
+
 #[cfg(feature = "encoder")]
 #[cfg(not(target_arch = "wasm32"))]
 mod file_sink;
@@ -49,12 +52,51 @@ const OLD_RRD_HEADERS: &[[u8; 4]] = &[*b"RRF0", *b"RRF1"];
 
 /// Compression format used.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
-#[repr(u8)]
 pub enum Compression {
-    Off = 0,
+    Off,
 
     /// Very fast compression and decompression, but not very good compression ratio.
-    LZ4 = 1,
+    LZ4,
+
+    /// Slower than [`Self::LZ4`], but with a much better compression ratio.
+    ///
+    /// Useful for archival `.rrd` files, where size matters more than speed.
+    ///
+    /// Encoding or decoding it requires the `zstd` feature, which isn't available on the web.
+    Zstd {
+        /// The zstd compression level, from 1 (fastest) to 22 (smallest), or 0 for zstd's default.
+        ///
+        /// This is only used when encoding: it isn't stored in the stream, so decoded options
+        /// always report [`Self::ZSTD_DEFAULT_LEVEL`].
+        level: i32,
+    },
+}
+
+impl Compression {
+    /// Lets zstd pick its default compression level.
+    pub const ZSTD_DEFAULT_LEVEL: i32 = 0;
+
+    /// The byte identifying this compression format in the stream header.
+    #[inline]
+    pub fn to_u8(self) -> u8 {
+        match self {
+            Self::Off => 0,
+            Self::LZ4 => 1,
+            Self::Zstd { .. } => 2,
+        }
+    }
+
+    #[inline]
+    pub fn from_u8(compression: u8) -> Result<Self, OptionsError> {
+        match compression {
+            0 => Ok(Self::Off),
+            1 => Ok(Self::LZ4),
+            2 => Ok(Self::Zstd {
+                level: Self::ZSTD_DEFAULT_LEVEL,
+            }),
+            _ => Err(OptionsError::UnknownCompression(compression)),
+        }
+    }
 }
 
 /// How we serialize the data
@@ -84,15 +126,23 @@ impl EncodingOptions {
         compression: Compression::LZ4,
         serializer: Serializer::Protobuf,
     };
+    pub const MSGPACK_ZSTD: Self = Self {
+        compression: Compression::Zstd {
+            level: Compression::ZSTD_DEFAULT_LEVEL,
+        },
+        serializer: Serializer::MsgPack,
+    };
+    pub const PROTOBUF_ZSTD: Self = Self {
+        compression: Compression::Zstd {
+            level: Compression::ZSTD_DEFAULT_LEVEL,
+        },
+        serializer: Serializer::Protobuf,
+    };
 
     pub fn from_bytes(bytes: [u8; 4]) -> Result<Self, OptionsError> {
         match bytes {
             [compression, serializer, 0, 0] => {
-                let compression = match compression {
-                    0 => Compression::Off,
-                    1 => Compression::LZ4,
-                    _ => return Err(OptionsError::UnknownCompression(compression)),
-                };
+                let compression = Compression::from_u8(compression)?;
                 let serializer = match serializer {
                     1 => Serializer::MsgPack,
                     2 => Serializer::Protobuf,
@@ -109,7 +159,7 @@ impl EncodingOptions {
 
     pub fn to_bytes(self) -> [u8; 4] {
         [
-            self.compression as u8,
+            self.compression.to_u8(),
             self.serializer as u8,
             0, // reserved
             0, // reserved
@@ -3,6 +3,9 @@ impl From<re_protos::log_msg::v0::Compression> for crate::Compression {
         match value {
             re_protos::log_msg::v0::Compression::None => Self::Off,
             re_protos::log_msg::v0::Compression::Lz4 => Self::LZ4,
+            re_protos::log_msg::v0::Compression::Zstd => Self::Zstd {
+                level: Self::ZSTD_DEFAULT_LEVEL,
+            },
         }
     }
 }
@@ -12,6 +15,7 @@ impl From<crate::Compression> for re_protos::log_msg::v0::Compression {
         match value {
             crate::Compression::Off => Self::None,
             crate::Compression::LZ4 => Self::Lz4,
+            crate::Compression::Zstd { .. } => Self::Zstd,
         }
     }
 }
@@ -0,0 +1,51 @@
+//! Zstd compression, for [`crate::Compression::Zstd`].
+//!
+//! This is behind the `zstd` feature, since the `zstd` crate wraps the C library, which doesn't
+//! build for the web viewer. Without it, encoding or decoding zstd-compressed data is an error.
+
+/// Compresses `uncompressed` into `compressed`, resizing it as needed.
+///
+/// Returns the number of compressed bytes written to the start of `compressed`.
+#[cfg(feature = "encoder")]
+pub(crate) fn compress(
+    uncompressed: &[u8],
+    compressed: &mut Vec<u8>,
+    level: i32,
+) -> std::io::Result<usize> {
+    #[cfg(feature = "zstd")]
+    {
+        compressed.resize(zstd::zstd_safe::compress_bound(uncompressed.len()), 0);
+        zstd::bulk::compress_to_buffer(uncompressed, compressed.as_mut_slice(), level)
+    }
+
+    #[cfg(not(feature = "zstd"))]
+    {
+        _ = (uncompressed, compressed, level);
+        Err(not_enabled())
+    }
+}
+
+/// Decompresses `compressed` into `uncompressed`, which must be large enough to hold the result.
+///
+/// Returns the number of decompressed bytes.
+#[cfg(feature = "decoder")]
+pub(crate) fn decompress(compressed: &[u8], uncompressed: &mut [u8]) -> std::io::Result<usize> {
+    #[cfg(feature = "zstd")]
+    {
+        zstd::bulk::decompress_to_buffer(compressed, uncompressed)
+    }
+
+    #[cfg(not(feature = "zstd"))]
+    {
+        _ = (compressed, uncompressed);
+        Err(not_enabled())
+    }
+}
+
+#[cfg(not(feature = "zstd"))]
+fn not_enabled() -> std::io::Error {
+    std::io::Error::new(
+        std::io::ErrorKind::Unsupported,
+        "re_log_encoding was built without the `zstd` feature",
+    )
+}
@@ -44,8 +44,12 @@ pub use self::path::*;
 pub use self::resolved_time_range::{ResolvedTimeRange, ResolvedTimeRangeF};
 pub use self::time::{Duration, Time, TimeZone};
 pub use self::time_point::{
-    NonMinI64, TimeInt, TimePoint, TimeType, Timeline, TimelineName, TryFromIntError,
+    sort_timelines_canonical, NonMinI64, ParseTimeTypeError, TimeInt, TimePoint, TimeType,
+    Timeline, TimelineName, TryFromIntError,
 };
+
+#[cfg(feature = "serde")]
+pub use self::time_point::TimePointJsonError;
 pub use self::time_real::TimeReal;
 pub use self::vec_deque_ext::{VecDequeInsertionExt, VecDequeRemovalExt, VecDequeSortingExt};
 
@@ -99,6 +103,25 @@ impl std::fmt::Display for StoreKind {
     }
 }
 
+impl std::str::FromStr for StoreKind {
+    type Err = ParseStoreKindError;
+
+    /// Parses `recording` or `blueprint`, case-insensitively.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.eq_ignore_ascii_case("recording") {
+            Ok(Self::Recording)
+        } else if s.eq_ignore_ascii_case("blueprint") {
+            Ok(Self::Blueprint)
+        } else {
+            Err(ParseStoreKindError(s.to_owned()))
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("unknown store kind {0:?}, expected \"recording\" or \"blueprint\"")]
+pub struct ParseStoreKindError(pub String);
+
 /// A unique id per store.
 ///
 /// The kind of store is part of the id, and can be either a
@@ -145,11 +168,82 @@ impl StoreId {
         self.id.as_str()
     }
 
+    #[inline]
+    pub fn is_recording(&self) -> bool {
+        self.kind == StoreKind::Recording
+    }
+
+    #[inline]
+    pub fn is_blueprint(&self) -> bool {
+        self.kind == StoreKind::Blueprint
+    }
+
     pub fn is_empty_recording(&self) -> bool {
-        self.kind == StoreKind::Recording && self.id.as_str() == "<EMPTY>"
+        self.is_recording() && self.id.as_str() == "<EMPTY>"
+    }
+
+    /// A compact version of the id for showing in UI and logs, like a git short hash.
+    ///
+    /// For UUIDs this is their first 8 hex digits.
+    /// Other ids longer than that are truncated with an ellipsis.
+    ///
+    /// This is _not_ unique. Use [`Self::as_str`] or the [`std::fmt::Display`] implementation
+    /// whenever the full id is needed.
+    pub fn short_id(&self) -> String {
+        const LEN: usize = 8;
+
+        if let Ok(uuid) = uuid::Uuid::parse_str(&self.id) {
+            let mut hex = uuid.simple().to_string();
+            hex.truncate(LEN);
+            hex
+        } else if self.id.chars().count() <= LEN {
+            self.id.to_string()
+        } else {
+            let mut short: String = self.id.chars().take(LEN).collect();
+            short.push('…');
+            short
+        }
+    }
+
+    /// Formats the id together with its [`StoreKind`], e.g. `recording:<uuid>`.
+    ///
+    /// Unlike the [`std::fmt::Display`] implementation, this can be losslessly parsed back using
+    /// [`Self::parse_qualified`].
+    pub fn to_qualified_string(&self) -> String {
+        format!("{}:{}", self.kind.to_string().to_lowercase(), self.id)
+    }
+
+    /// Parses a string produced by [`Self::to_qualified_string`], e.g. `blueprint:<uuid>`.
+    ///
+    /// The kind is parsed case-insensitively, like [`StoreKind`]'s [`std::str::FromStr`] implementation.
+    pub fn parse_qualified(s: &str) -> Result<Self, StoreIdParseError> {
+        let (kind, id) = s
+            .split_once(':')
+            .ok_or_else(|| StoreIdParseError::MissingKind(s.to_owned()))?;
+
+        let kind: StoreKind = kind.parse()?;
+
+        if id.is_empty() {
+            return Err(StoreIdParseError::EmptyId);
+        }
+
+        Ok(Self::from_string(kind, id.to_owned()))
     }
 }
 
+/// Error returned by [`StoreId::parse_qualified`].
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+pub enum StoreIdParseError {
+    #[error("expected a store id of the form `<kind>:<id>`, got {0:?}")]
+    MissingKind(String),
+
+    #[error(transparent)]
+    UnknownKind(#[from] ParseStoreKindError),
+
+    #[error("store id is empty")]
+    EmptyId,
+}
+
 impl std::fmt::Display for StoreId {
     #[inline]
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -189,11 +283,42 @@ impl ApplicationId {
         Self("unknown_app_id".to_owned())
     }
 
+    /// Creates an [`ApplicationId`], validating the given string.
+    ///
+    /// Surrounding whitespace is trimmed.
+    /// Empty ids and ids containing control characters (e.g. newlines) are rejected.
+    ///
+    /// Use this for application ids coming from untrusted sources.
+    /// The infallible `From` implementations don't do any validation.
+    pub fn new_validated(s: &str) -> Result<Self, ApplicationIdError> {
+        let s = s.trim();
+
+        if s.is_empty() {
+            return Err(ApplicationIdError::Empty);
+        }
+
+        if let Some(c) = s.chars().find(|c| c.is_control()) {
+            return Err(ApplicationIdError::ControlCharacter(c));
+        }
+
+        Ok(Self(s.to_owned()))
+    }
+
     pub fn as_str(&self) -> &str {
         self.0.as_str()
     }
 }
 
+/// Error returned by [`ApplicationId::new_validated`].
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+pub enum ApplicationIdError {
+    #[error("application id is empty")]
+    Empty,
+
+    #[error("application id contains the control character {0:?}")]
+    ControlCharacter(char),
+}
+
 impl std::fmt::Display for ApplicationId {
     #[inline]
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -288,6 +413,11 @@ impl LogMsg {
         }
     }
 
+    /// Overwrites the [`StoreId`] embedded in this message.
+    ///
+    /// This does not check whether the new id's [`StoreKind`] makes sense for this message,
+    /// e.g. it will happily tag a [`Self::BlueprintActivationCommand`] with a recording id.
+    /// Prefer [`Self::try_retag_store_id`] unless you know what you're doing.
     pub fn set_store_id(&mut self, new_store_id: StoreId) {
         match self {
             Self::SetStoreInfo(store_info) => {
@@ -301,6 +431,43 @@ impl LogMsg {
             }
         }
     }
+
+    /// Overwrites the [`StoreId`] embedded in this message, checking that its [`StoreKind`] is
+    /// compatible with the message.
+    ///
+    /// [`Self::SetStoreInfo`] and [`Self::BlueprintActivationCommand`] must keep the kind of
+    /// store they refer to, since the kind determines how the store is interpreted.
+    /// On error, the message is left untouched.
+    pub fn try_retag_store_id(&mut self, new_store_id: StoreId) -> Result<(), RetagError> {
+        let expected = match self {
+            Self::SetStoreInfo(_) | Self::BlueprintActivationCommand(_) => {
+                Some(self.store_id().kind)
+            }
+            Self::ArrowMsg(..) => None,
+        };
+
+        if let Some(expected) = expected {
+            if new_store_id.kind != expected {
+                return Err(RetagError::KindMismatch {
+                    expected,
+                    actual: new_store_id.kind,
+                });
+            }
+        }
+
+        self.set_store_id(new_store_id);
+        Ok(())
+    }
+}
+
+/// Error returned by [`LogMsg::try_retag_store_id`].
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+pub enum RetagError {
+    #[error("can't retag a message about a {expected} store with a {actual} store id")]
+    KindMismatch {
+        expected: StoreKind,
+        actual: StoreKind,
+    },
 }
 
 impl_into_enum!(SetStoreInfo, LogMsg, SetStoreInfo);
@@ -366,6 +533,18 @@ pub struct StoreInfo {
 }
 
 impl StoreInfo {
+    /// Same as [`StoreId::is_recording`].
+    #[inline]
+    pub fn is_recording(&self) -> bool {
+        self.store_id.is_recording()
+    }
+
+    /// Same as [`StoreId::is_blueprint`].
+    #[inline]
+    pub fn is_blueprint(&self) -> bool {
+        self.store_id.is_blueprint()
+    }
+
     /// Whether this `StoreInfo` is the default used when a user is not explicitly
     /// creating their own blueprint.
     pub fn is_app_default_blueprint(&self) -> bool {
@@ -593,6 +772,12 @@ pub enum StoreSource {
 
     /// Perhaps from some manual data ingestion?
     Other(String),
+
+    /// Forwarded to us by a message proxy server.
+    MessageProxy {
+        /// The url the proxy is reachable at, e.g. `rerun+http://127.0.0.1:9876/proxy`.
+        url: String,
+    },
 }
 
 impl std::fmt::Display for StoreSource {
@@ -611,6 +796,7 @@ impl std::fmt::Display for StoreSource {
             },
             Self::Viewer => write!(f, "Viewer-generated"),
             Self::Other(string) => format!("{string:?}").fmt(f), // put it in quotes
+            Self::MessageProxy { url } => write!(f, "via message proxy ({url})"),
         }
     }
 }
@@ -687,4 +873,215 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn test_store_id_short_id() {
+        let uuid = uuid::Uuid::parse_str("1e7a6b4c-9d0f-4e2a-8b3c-5d6e7f809a1b").unwrap();
+        let store_id = StoreId::from_uuid(StoreKind::Recording, uuid);
+        assert_eq!(store_id.short_id(), "1e7a6b4c");
+        assert_eq!(
+            store_id.to_string(),
+            "1e7a6b4c-9d0f-4e2a-8b3c-5d6e7f809a1b",
+            "Display stays full"
+        );
+
+        let store_id = StoreId::from_string(StoreKind::Blueprint, "my_blueprint_id".to_owned());
+        assert_eq!(store_id.short_id(), "my_bluep…");
+        assert_eq!(store_id.as_str(), "my_blueprint_id");
+
+        let store_id = StoreId::from_string(StoreKind::Recording, "short".to_owned());
+        assert_eq!(store_id.short_id(), "short");
+    }
+
+    #[test]
+    fn test_store_id_kind() {
+        let recording = StoreId::random(StoreKind::Recording);
+        assert!(recording.is_recording());
+        assert!(!recording.is_blueprint());
+
+        let blueprint = StoreId::random(StoreKind::Blueprint);
+        assert!(blueprint.is_blueprint());
+        assert!(!blueprint.is_recording());
+
+        assert!(StoreId::empty_recording().is_recording());
+        assert!(StoreId::empty_recording().is_empty_recording());
+        assert!(
+            !StoreId::from_string(StoreKind::Blueprint, "<EMPTY>".to_owned()).is_empty_recording()
+        );
+
+        for store_id in [recording, blueprint] {
+            let store_info = StoreInfo {
+                application_id: ApplicationId::from("test"),
+                store_id: store_id.clone(),
+                cloned_from: None,
+                is_official_example: false,
+                started: Time::now(),
+                store_source: StoreSource::Unknown,
+                store_version: None,
+            };
+            assert_eq!(store_info.is_recording(), store_id.is_recording());
+            assert_eq!(store_info.is_blueprint(), store_id.is_blueprint());
+        }
+    }
+
+    #[test]
+    fn test_store_kind_from_str() {
+        for kind in [StoreKind::Recording, StoreKind::Blueprint] {
+            assert_eq!(kind.to_string().parse::<StoreKind>(), Ok(kind));
+        }
+
+        assert_eq!("recording".parse(), Ok(StoreKind::Recording));
+        assert_eq!("BLUEPRINT".parse(), Ok(StoreKind::Blueprint));
+
+        for garbage in ["", "record", "blueprints", " recording"] {
+            assert_eq!(
+                garbage.parse::<StoreKind>(),
+                Err(ParseStoreKindError(garbage.to_owned()))
+            );
+        }
+    }
+
+    fn set_store_info_msg(store_id: StoreId) -> LogMsg {
+        LogMsg::SetStoreInfo(SetStoreInfo {
+            row_id: re_tuid::Tuid::new(),
+            info: StoreInfo {
+                application_id: ApplicationId::from("test"),
+                store_id,
+                cloned_from: None,
+                is_official_example: false,
+                started: Time::now(),
+                store_source: StoreSource::Unknown,
+                store_version: None,
+            },
+        })
+    }
+
+    #[test]
+    fn try_retag_store_id() {
+        let recording_id = StoreId::random(StoreKind::Recording);
+        let other_recording_id = StoreId::random(StoreKind::Recording);
+        let blueprint_id = StoreId::random(StoreKind::Blueprint);
+        let other_blueprint_id = StoreId::random(StoreKind::Blueprint);
+
+        // Happy path:
+        let mut msg = set_store_info_msg(recording_id.clone());
+        assert_eq!(msg.try_retag_store_id(other_recording_id.clone()), Ok(()));
+        assert_eq!(msg.store_id(), &other_recording_id);
+
+        let mut msg = LogMsg::BlueprintActivationCommand(BlueprintActivationCommand::make_active(
+            blueprint_id.clone(),
+        ));
+        assert_eq!(msg.try_retag_store_id(other_blueprint_id.clone()), Ok(()));
+        assert_eq!(msg.store_id(), &other_blueprint_id);
+
+        // Mismatches are rejected and leave the message untouched:
+        let mut msg = set_store_info_msg(blueprint_id.clone());
+        assert_eq!(
+            msg.try_retag_store_id(recording_id.clone()),
+            Err(RetagError::KindMismatch {
+                expected: StoreKind::Blueprint,
+                actual: StoreKind::Recording,
+            })
+        );
+        assert_eq!(msg.store_id(), &blueprint_id);
+
+        let mut msg = LogMsg::BlueprintActivationCommand(BlueprintActivationCommand::make_active(
+            blueprint_id.clone(),
+        ));
+        assert_eq!(
+            msg.try_retag_store_id(recording_id),
+            Err(RetagError::KindMismatch {
+                expected: StoreKind::Blueprint,
+                actual: StoreKind::Recording,
+            })
+        );
+        assert_eq!(msg.store_id(), &blueprint_id);
+    }
+
+    #[test]
+    fn application_id_validation() {
+        assert_eq!(
+            ApplicationId::new_validated("rerun_example_app"),
+            Ok(ApplicationId::from("rerun_example_app"))
+        );
+        assert_eq!(
+            ApplicationId::new_validated("my app/with-slashes"),
+            Ok(ApplicationId::from("my app/with-slashes"))
+        );
+        assert_eq!(
+            ApplicationId::new_validated("  \tmy_app \n"),
+            Ok(ApplicationId::from("my_app"))
+        );
+
+        assert_eq!(
+            ApplicationId::new_validated("my\napp"),
+            Err(ApplicationIdError::ControlCharacter('\n'))
+        );
+        assert_eq!(
+            ApplicationId::new_validated("my\u{7}app"),
+            Err(ApplicationIdError::ControlCharacter('\u{7}'))
+        );
+        assert_eq!(
+            ApplicationId::new_validated(""),
+            Err(ApplicationIdError::Empty)
+        );
+        assert_eq!(
+            ApplicationId::new_validated(" \n "),
+            Err(ApplicationIdError::Empty)
+        );
+    }
+
+    #[test]
+    fn store_id_qualified_roundtrip() {
+        for kind in [StoreKind::Recording, StoreKind::Blueprint] {
+            let store_id = StoreId::random(kind);
+            let qualified = store_id.to_qualified_string();
+            assert_eq!(StoreId::parse_qualified(&qualified), Ok(store_id));
+        }
+
+        let store_id = StoreId::from_string(StoreKind::Blueprint, "my:app".to_owned());
+        assert_eq!(store_id.to_qualified_string(), "blueprint:my:app");
+        assert_eq!(
+            StoreId::parse_qualified("blueprint:my:app"),
+            Ok(store_id.clone())
+        );
+
+        // The plain display doesn't include the kind.
+        assert_eq!(store_id.to_string(), "my:app");
+    }
+
+    #[test]
+    fn store_id_parse_qualified_errors() {
+        assert_eq!(
+            StoreId::parse_qualified("foo:bar"),
+            Err(StoreIdParseError::UnknownKind(ParseStoreKindError(
+                "foo".to_owned()
+            )))
+        );
+        assert_eq!(
+            StoreId::parse_qualified("Recording:bar"),
+            Ok(StoreId::from_string(StoreKind::Recording, "bar".to_owned()))
+        );
+        assert_eq!(
+            StoreId::parse_qualified("no-kind"),
+            Err(StoreIdParseError::MissingKind("no-kind".to_owned()))
+        );
+        assert_eq!(
+            StoreId::parse_qualified("recording:"),
+            Err(StoreIdParseError::EmptyId)
+        );
+    }
+
+    #[test]
+    fn store_source_display() {
+        assert_eq!(
+            StoreSource::MessageProxy {
+                url: "rerun+http://127.0.0.1:9876/proxy".to_owned()
+            }
+            .to_string(),
+            "via message proxy (rerun+http://127.0.0.1:9876/proxy)"
+        );
+        assert_eq!(StoreSource::Other("foo".to_owned()).to_string(), "\"foo\"");
+        assert_eq!(StoreSource::CSdk.to_string(), "C SDK");
+    }
 }
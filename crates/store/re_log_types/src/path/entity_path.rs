@@ -181,6 +181,14 @@ impl EntityPath {
         prefix.len() <= self.len() && self.iter().zip(prefix.iter()).all(|(a, b)| a == b)
     }
 
+    /// The remaining parts of this path after `prefix`, if it [starts with](Self::starts_with) it.
+    ///
+    /// Stripping the path itself returns the root path.
+    pub fn strip_prefix(&self, prefix: &Self) -> Option<Self> {
+        self.starts_with(prefix)
+            .then(|| Self::new(self.parts[prefix.len()..].to_vec()))
+    }
+
     /// Is this a strict descendant of the given path.
     #[inline]
     pub fn is_descendant_of(&self, other: &Self) -> bool {
@@ -257,7 +265,10 @@ impl EntityPath {
     }
 
     /// Returns the first common ancestor of a list of entity paths.
-    pub fn common_ancestor_of<'a>(mut entities: impl Iterator<Item = &'a Self>) -> Self {
+    ///
+    /// This is the root if the list is empty, or if the paths have nothing in common.
+    pub fn common_ancestor_of<'a>(entities: impl IntoIterator<Item = &'a Self>) -> Self {
+        let mut entities = entities.into_iter();
         let first = entities.next().cloned().unwrap_or(Self::root());
         entities.fold(first, |acc, e| acc.common_ancestor(e))
     }
@@ -586,6 +597,42 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_starts_with() {
+        let path = EntityPath::from("foo/bar");
+
+        assert!(path.starts_with(&EntityPath::root()));
+        assert!(path.starts_with(&EntityPath::from("foo")));
+        assert!(path.starts_with(&EntityPath::from("foo/bar")));
+
+        // Respects part boundaries:
+        assert!(!path.starts_with(&EntityPath::from("foo/ba")));
+        assert!(!path.starts_with(&EntityPath::from("fo")));
+
+        assert!(!path.starts_with(&EntityPath::from("foo/bar/baz")));
+        assert!(!path.starts_with(&EntityPath::from("bar")));
+        assert!(EntityPath::root().starts_with(&EntityPath::root()));
+    }
+
+    #[test]
+    fn test_strip_prefix() {
+        let path = EntityPath::from("foo/bar/baz");
+
+        assert_eq!(path.strip_prefix(&EntityPath::root()), Some(path.clone()));
+        assert_eq!(
+            path.strip_prefix(&EntityPath::from("foo")),
+            Some(EntityPath::from("bar/baz"))
+        );
+        assert_eq!(path.strip_prefix(&path), Some(EntityPath::root()));
+
+        assert_eq!(path.strip_prefix(&EntityPath::from("foo/ba")), None);
+        assert_eq!(
+            path.strip_prefix(&EntityPath::from("foo/bar/baz/qux")),
+            None
+        );
+        assert_eq!(path.strip_prefix(&EntityPath::from("bar")), None);
+    }
+
     #[test]
     fn test_common_ancestor() {
         assert_eq!(
@@ -610,6 +657,51 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_common_ancestor_of() {
+        let common_ancestor_of = |paths: &[&str]| {
+            let paths = paths
+                .iter()
+                .map(|path| EntityPath::from(*path))
+                .collect_vec();
+            EntityPath::common_ancestor_of(&paths)
+        };
+
+        // Empty:
+        assert_eq!(common_ancestor_of(&[]), EntityPath::root());
+
+        // Identical:
+        assert_eq!(
+            common_ancestor_of(&["foo/bar"]),
+            EntityPath::from("foo/bar")
+        );
+        assert_eq!(
+            common_ancestor_of(&["foo/bar", "foo/bar", "foo/bar"]),
+            EntityPath::from("foo/bar")
+        );
+
+        // Nested:
+        assert_eq!(
+            common_ancestor_of(&["foo/bar/baz", "foo/bar", "foo/bar/qux/quux"]),
+            EntityPath::from("foo/bar")
+        );
+        assert_eq!(
+            common_ancestor_of(&["world/robot/arm", "world/robot/leg", "world/camera"]),
+            EntityPath::from("world")
+        );
+
+        // Disjoint:
+        assert_eq!(
+            common_ancestor_of(&["mario/bowser", "luigi/bowser"]),
+            EntityPath::root()
+        );
+        assert_eq!(
+            common_ancestor_of(&["foo/bar", "foo/baz", "qux"]),
+            EntityPath::root()
+        );
+        assert_eq!(common_ancestor_of(&["/", "foo/bar"]), EntityPath::root());
+    }
+
     #[test]
     fn test_short_names_with_disambiguation() {
         fn run_test(entities: &[(&str, &str)]) {
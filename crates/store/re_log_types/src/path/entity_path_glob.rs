@@ -0,0 +1,224 @@
+use crate::{EntityPath, EntityPathPart, PathParseError};
+
+use super::parse_path::tokenize_entity_path;
+
+/// A glob pattern that matches [`EntityPath`]s, e.g. `world/**/points`.
+///
+/// Each part of the pattern is either:
+/// * `*`, which matches exactly one part of the path,
+/// * `**`, which matches any number of parts, including none,
+/// * or a literal [`EntityPathPart`], which is escaped just like in any other entity path.
+///
+/// This means that a part actually named `*` must be written as `\*`.
+/// Wildcards only ever match whole parts: `foo*` is not a valid pattern.
+///
+/// ```
+/// # use re_log_types::{EntityPath, EntityPathGlob};
+/// let glob = EntityPathGlob::parse_strict("world/**/points").unwrap();
+/// assert!(glob.is_match(&EntityPath::parse_strict("world/points").unwrap()));
+/// assert!(glob.is_match(&EntityPath::parse_strict("world/robot/arm/points").unwrap()));
+/// assert!(!glob.is_match(&EntityPath::parse_strict("world/points/42").unwrap()));
+/// ```
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct EntityPathGlob {
+    segments: Vec<GlobSegment>,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+enum GlobSegment {
+    /// Matches this exact part.
+    Part(EntityPathPart),
+
+    /// `*`: matches any single part.
+    AnyPart,
+
+    /// `**`: matches zero or more parts.
+    AnyParts,
+}
+
+impl EntityPathGlob {
+    /// Parse a glob pattern, with the same strict escaping rules as [`EntityPath::parse_strict`].
+    ///
+    /// The leading slash is optional, and `/` on its own only matches the root.
+    pub fn parse_strict(pattern: &str) -> Result<Self, PathParseError> {
+        let tokens = tokenize_entity_path(pattern);
+
+        let tokens = match tokens.as_slice() {
+            [] => return Err(PathParseError::EmptyString),
+            ["/"] => return Ok(Self { segments: vec![] }), // special-case root entity
+            ["/", rest @ ..] => rest,
+            tokens => tokens,
+        };
+        if tokens.last() == Some(&"/") {
+            return Err(PathParseError::TrailingSlash);
+        }
+
+        let segments = tokens
+            .split(|token| *token == "/")
+            .map(|part| match part {
+                ["*"] => Ok(GlobSegment::AnyPart),
+                ["**"] => Ok(GlobSegment::AnyParts),
+                [part] => EntityPathPart::parse_strict(part).map(GlobSegment::Part),
+                _ => Err(PathParseError::DoubleSlash),
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { segments })
+    }
+
+    /// Does the given path match this pattern?
+    pub fn is_match(&self, path: &EntityPath) -> bool {
+        segments_match(&self.segments, path.as_slice())
+    }
+}
+
+fn segments_match(segments: &[GlobSegment], parts: &[EntityPathPart]) -> bool {
+    match segments.split_first() {
+        None => parts.is_empty(),
+
+        Some((GlobSegment::AnyParts, segments)) => {
+            (0..=parts.len()).any(|num_skipped| segments_match(segments, &parts[num_skipped..]))
+        }
+
+        Some((segment, segments)) => parts.split_first().is_some_and(|(part, parts)| {
+            let part_matches = match segment {
+                GlobSegment::Part(expected) => part == expected,
+                GlobSegment::AnyPart | GlobSegment::AnyParts => true,
+            };
+            part_matches && segments_match(segments, parts)
+        }),
+    }
+}
+
+impl std::str::FromStr for EntityPathGlob {
+    type Err = PathParseError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_strict(s)
+    }
+}
+
+impl std::fmt::Display for EntityPathGlob {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use std::fmt::Write as _;
+
+        if self.segments.is_empty() {
+            return f.write_char('/');
+        }
+
+        // We always lead with a slash, just like `EntityPath`.
+        for segment in &self.segments {
+            f.write_char('/')?;
+            match segment {
+                GlobSegment::Part(part) => part.escaped_string().fmt(f)?,
+                GlobSegment::AnyPart => f.write_char('*')?,
+                GlobSegment::AnyParts => f.write_str("**")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{EntityPath, EntityPathGlob, PathParseError};
+
+    #[test]
+    fn test_is_match() {
+        // (pattern, path, expected)
+        let cases = [
+            // Exact matches:
+            ("/", "/", true),
+            ("/", "world", false),
+            ("world", "world", true),
+            ("/world", "world", true),
+            ("world/points", "world/points", true),
+            ("world/points", "world", false),
+            ("world", "world/points", false),
+            ("world/points", "world/lines", false),
+            // Single-level wildcard:
+            ("*", "world", true),
+            ("*", "/", false),
+            ("*", "world/points", false),
+            ("world/*", "world/points", true),
+            ("world/*", "world", false),
+            ("world/*", "world/points/42", false),
+            ("*/points", "world/points", true),
+            ("*/points", "points", false),
+            ("world/*/points", "world/robot/points", true),
+            ("world/*/points", "world/points", false),
+            ("*/*", "world/points", true),
+            // Recursive wildcard:
+            ("**", "/", true),
+            ("/**", "world/points/42", true),
+            ("world/**", "world", true),
+            ("world/**", "world/points/42", true),
+            ("world/**", "other/points", false),
+            ("**/points", "points", true),
+            ("**/points", "world/robot/points", true),
+            ("**/points", "world/points/42", false),
+            ("world/**/points", "world/points", true),
+            ("world/**/points", "world/robot/arm/points", true),
+            ("world/**/points", "world/robot/lines", false),
+            ("world/**/points", "other/robot/points", false),
+            ("**/robot/**", "world/robot/arm/points", true),
+            ("**/robot/**", "world/arm/points", false),
+            ("**/*", "/", false),
+            ("**/*", "world", true),
+            ("**/**", "world/points", true),
+            // Escaping:
+            (r"world/\*", r"world/\*", true),
+            (r"world/\*", "world/points", false),
+            (r"world/\*\*", r"world/\*\*", true),
+            (r"world/\*\*", "world/robot/points", false),
+            (r"world/my\ points", r"world/my\ points", true),
+            (r"*/my\ points", r"world/my\ points", true),
+        ];
+
+        for (pattern, path, expected) in cases {
+            let glob = EntityPathGlob::parse_strict(pattern).unwrap();
+            let path = EntityPath::parse_strict(path).unwrap();
+            assert_eq!(
+                glob.is_match(&path),
+                expected,
+                "{pattern:?} matching {path:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let cases = [
+            ("", PathParseError::EmptyString),
+            ("world//points", PathParseError::DoubleSlash),
+            ("//world", PathParseError::DoubleSlash),
+            ("world/", PathParseError::TrailingSlash),
+            ("world/**/", PathParseError::TrailingSlash),
+            ("world/points*", PathParseError::MissingEscape('*')),
+            ("world/***", PathParseError::MissingEscape('*')),
+        ];
+
+        for (pattern, expected) in cases {
+            assert_eq!(
+                EntityPathGlob::parse_strict(pattern),
+                Err(expected),
+                "{pattern:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_display_roundtrip() {
+        for pattern in [
+            "/",
+            "/world/**/points",
+            "/*/points",
+            r"/world/\*/my\ points",
+        ] {
+            let glob = EntityPathGlob::parse_strict(pattern).unwrap();
+            assert_eq!(glob.to_string(), pattern);
+            assert_eq!(glob.to_string().parse::<EntityPathGlob>(), Ok(glob));
+        }
+    }
+}
@@ -146,6 +146,17 @@ impl EntityPathPart {
         Ok(Self::from(output))
     }
 
+    /// Unescape a string produced by [`Self::escaped_string`], e.g. when reading it back from a URI.
+    ///
+    /// `EntityPathPart::parse_escaped(&part.escaped_string()) == part` holds for any part.
+    ///
+    /// This is forgiving of syntax errors just like [`Self::parse_forgiving`].
+    /// Use [`Self::parse_strict`] to reject input that isn't properly escaped.
+    #[inline]
+    pub fn parse_escaped(escaped: &str) -> Self {
+        Self::parse_forgiving(escaped)
+    }
+
     /// The style of string to use in a UI
     #[inline]
     pub fn ui_string(&self) -> String {
@@ -198,14 +209,14 @@ impl EntityPathPart {
     }
 }
 
-/// Parses e.g. `{262E}`.
+/// Parses e.g. `{262E}` or `{1F600}`.
 ///
 /// Returns the consumed input characters on fail.
 fn parse_unicode_escape(input: &mut impl Iterator<Item = char>) -> Result<char, String> {
     let mut all_chars = String::new();
     for c in input {
         all_chars.push(c);
-        if c == '}' || all_chars.len() == 6 {
+        if c == '}' || all_chars.len() == 8 {
             break;
         }
     }
@@ -219,7 +230,8 @@ fn parse_unicode_escape(input: &mut impl Iterator<Item = char>) -> Result<char,
         return Err(all_chars);
     };
 
-    if chars.len() != 4 {
+    // `escaped_string` always writes at least 4 digits, and no code point needs more than 6.
+    if !(4..=6).contains(&chars.len()) {
         return Err(all_chars);
     }
 
@@ -321,3 +333,36 @@ fn test_parse_entity_path_part() {
         );
     }
 }
+
+#[test]
+fn test_parse_escaped_roundtrip() {
+    for unescaped in [
+        "",
+        "points",
+        "hello world",
+        "a/b",
+        "/",
+        r"C:\Users\image.jpg",
+        "*",
+        "**",
+        "\"quoted\"",
+        "Hallå",
+        "☮",
+        "😀",
+        "new\nline\r\t",
+        "\u{0001}",
+        r"\u{262E}",
+        "日本語",
+    ] {
+        let part = EntityPathPart::new(unescaped);
+        let escaped = part.escaped_string();
+        assert_eq!(EntityPathPart::parse_escaped(&escaped), part, "{escaped:?}");
+        assert_eq!(
+            EntityPathPart::parse_strict(&escaped),
+            Ok(part),
+            "{escaped:?}"
+        );
+    }
+
+    assert_eq!(EntityPathPart::from("😀").escaped_string(), r"\u{1F600}");
+}
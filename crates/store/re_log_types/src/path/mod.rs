@@ -7,6 +7,7 @@ mod component_path;
 mod data_path;
 mod entity_path;
 mod entity_path_filter;
+mod entity_path_glob;
 mod entity_path_part;
 mod natural_ordering;
 mod parse_path;
@@ -15,6 +16,7 @@ pub use component_path::ComponentPath;
 pub use data_path::DataPath;
 pub use entity_path::{EntityPath, EntityPathHash};
 pub use entity_path_filter::{EntityPathFilter, EntityPathRule, EntityPathSubs, RuleEffect};
+pub use entity_path_glob::EntityPathGlob;
 pub use entity_path_part::EntityPathPart;
 pub use parse_path::PathParseError;
 
@@ -51,6 +51,9 @@ pub enum PathParseError {
 
     #[error("Expected e.g. '\\u{{262E}}', found: '\\u{0}'")]
     InvalidUnicodeEscape(String),
+
+    #[error("Entity path is deeper than the maximum of {max_depth} parts")]
+    TooDeep { max_depth: usize },
 }
 
 type Result<T, E = PathParseError> = std::result::Result<T, E>;
@@ -67,6 +70,12 @@ impl std::str::FromStr for DataPath {
     ///
     /// (the leadign slash is optional)
     fn from_str(path: &str) -> Result<Self, Self::Err> {
+        Self::parse_with_max_depth(path, EntityPath::DEFAULT_MAX_DEPTH)
+    }
+}
+
+impl DataPath {
+    fn parse_with_max_depth(path: &str, max_depth: usize) -> Result<Self> {
         if path.is_empty() {
             return Err(PathParseError::EmptyString);
         }
@@ -113,7 +122,7 @@ impl std::str::FromStr for DataPath {
 
         // The remaining tokens should all be separated with `/`:
 
-        let parts = entity_path_parts_from_tokens_strict(&tokens)?;
+        let parts = entity_path_parts_from_tokens_strict(&tokens, max_depth)?;
 
         let entity_path = EntityPath::from(parts);
 
@@ -136,17 +145,31 @@ impl std::str::FromStr for DataPath {
 /// This allows user to do things like `log(f"foo/{filename}", my_mesh)` without
 /// Rerun throwing a fit.
 impl EntityPath {
+    /// The maximum number of parts accepted by [`Self::parse_strict`].
+    ///
+    /// Anything deeper is almost certainly a bug in whatever generated the path.
+    pub const DEFAULT_MAX_DEPTH: usize = 64;
+
     /// Parse an entity path from a string, with strict checks for correctness.
     ///
-    /// Parses anything that `ent_path.to_string()` outputs.
+    /// Parses anything that `ent_path.to_string()` outputs,
+    /// as long as it is at most [`Self::DEFAULT_MAX_DEPTH`] parts deep.
     ///
     /// For a forgiving parse that accepts anything, use [`Self::parse_forgiving`].
     pub fn parse_strict(input: &str) -> Result<Self, PathParseError> {
+        Self::parse_strict_with_max_depth(input, Self::DEFAULT_MAX_DEPTH)
+    }
+
+    /// Like [`Self::parse_strict`], but with a custom maximum number of parts.
+    pub fn parse_strict_with_max_depth(
+        input: &str,
+        max_depth: usize,
+    ) -> Result<Self, PathParseError> {
         let DataPath {
             entity_path,
             instance,
             component_name,
-        } = DataPath::from_str(input)?;
+        } = DataPath::parse_with_max_depth(input, max_depth)?;
 
         if let Some(instance) = instance {
             return Err(PathParseError::UnexpectedInstance(instance));
@@ -211,7 +234,10 @@ impl FromStr for ComponentPath {
     }
 }
 
-fn entity_path_parts_from_tokens_strict(mut tokens: &[&str]) -> Result<Vec<EntityPathPart>> {
+fn entity_path_parts_from_tokens_strict(
+    mut tokens: &[&str],
+    max_depth: usize,
+) -> Result<Vec<EntityPathPart>> {
     if tokens.is_empty() {
         return Err(PathParseError::MissingPath);
     }
@@ -233,6 +259,8 @@ fn entity_path_parts_from_tokens_strict(mut tokens: &[&str]) -> Result<Vec<Entit
 
         if token == "/" {
             return Err(PathParseError::DoubleSlash);
+        } else if parts.len() == max_depth {
+            return Err(PathParseError::TooDeep { max_depth });
         } else {
             parts.push(EntityPathPart::parse_strict(token)?);
         }
@@ -263,7 +291,7 @@ fn join(tokens: &[&str]) -> String {
 }
 
 /// `"/foo/bar"` -> `["/", "foo", "/", "bar"]`
-fn tokenize_entity_path(path: &str) -> Vec<&str> {
+pub(super) fn tokenize_entity_path(path: &str) -> Vec<&str> {
     tokenize_by(path, b"/")
 }
 
@@ -368,6 +396,32 @@ fn test_parse_entity_path_strict() {
     assert_eq!(parse("hallådär"), Ok(entity_path_vec!("hallådär")));
 }
 
+#[test]
+fn test_parse_entity_path_max_depth() {
+    let path_of_depth = |depth: usize| vec!["a"; depth].join("/");
+    let max_depth = EntityPath::DEFAULT_MAX_DEPTH;
+
+    assert_eq!(
+        EntityPath::parse_strict(&path_of_depth(max_depth)).map(|path| path.len()),
+        Ok(max_depth)
+    );
+    assert_eq!(
+        EntityPath::parse_strict(&path_of_depth(max_depth + 1)),
+        Err(PathParseError::TooDeep { max_depth })
+    );
+    assert_eq!(
+        EntityPath::parse_strict_with_max_depth("a/b/c", 2),
+        Err(PathParseError::TooDeep { max_depth: 2 })
+    );
+    assert!(DataPath::from_str(&format!("{}:Color", path_of_depth(1000))).is_err());
+
+    // The forgiving parser has no limit:
+    assert_eq!(
+        EntityPath::parse_forgiving(&path_of_depth(1000)).len(),
+        1000
+    );
+}
+
 #[test]
 fn test_parse_component_path() {
     assert_eq!(
@@ -240,6 +240,10 @@ impl From<crate::StoreSource> for re_protos::log_msg::v0::StoreSource {
                 re_protos::log_msg::v0::StoreSourceKind::Other as i32,
                 description.into_bytes(),
             ),
+            crate::StoreSource::MessageProxy { url } => (
+                re_protos::log_msg::v0::StoreSourceKind::MessageProxy as i32,
+                url.into_bytes(),
+            ),
         };
 
         Self {
@@ -301,6 +305,15 @@ impl TryFrom<re_protos::log_msg::v0::StoreSource> for crate::StoreSource {
                 })?;
                 Ok(Self::Other(description))
             }
+            StoreSourceKind::MessageProxy => {
+                let url = value
+                    .extra
+                    .ok_or(missing_field!(re_protos::log_msg::v0::StoreSource, "extra"))?;
+                let url = String::from_utf8(url.payload).map_err(|err| {
+                    invalid_field!(re_protos::log_msg::v0::StoreSource, "extra", err)
+                })?;
+                Ok(Self::MessageProxy { url })
+            }
         }
     }
 }
@@ -619,6 +632,16 @@ mod tests {
         assert_eq!(store_source, store_source2);
     }
 
+    #[test]
+    fn store_source_message_proxy_conversion() {
+        let store_source = crate::StoreSource::MessageProxy {
+            url: "rerun+http://127.0.0.1:9876/proxy".to_owned(),
+        };
+        let proto_store_source: re_protos::log_msg::v0::StoreSource = store_source.clone().into();
+        let store_source2: crate::StoreSource = proto_store_source.try_into().unwrap();
+        assert_eq!(store_source, store_source2);
+    }
+
     #[test]
     fn file_source_conversion() {
         let file_source = crate::FileSource::Uri;
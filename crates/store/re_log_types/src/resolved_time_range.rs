@@ -100,6 +100,17 @@ impl ResolvedTimeRange {
         self.min <= other.max && self.max >= other.min
     }
 
+    /// The range covered by both, if they [intersect](Self::intersects).
+    ///
+    /// Ranges that only touch intersect in a single point.
+    #[inline]
+    pub fn intersection(&self, other: Self) -> Option<Self> {
+        self.intersects(other).then(|| Self {
+            min: self.min.max(other.min),
+            max: self.max.min(other.max),
+        })
+    }
+
     #[inline]
     pub fn union(&self, other: Self) -> Self {
         Self {
@@ -209,3 +220,68 @@ impl From<ResolvedTimeRange> for ResolvedTimeRangeF {
         Self::new(range.min, range.max)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_intersection() {
+        let range = ResolvedTimeRange::new(10, 20);
+
+        // Overlapping:
+        assert_eq!(
+            range.intersection(ResolvedTimeRange::new(15, 30)),
+            Some(ResolvedTimeRange::new(15, 20))
+        );
+        assert_eq!(
+            range.intersection(ResolvedTimeRange::new(0, 15)),
+            Some(ResolvedTimeRange::new(10, 15))
+        );
+        assert_eq!(
+            range.intersection(ResolvedTimeRange::new(12, 18)),
+            Some(ResolvedTimeRange::new(12, 18))
+        );
+        assert_eq!(range.intersection(range), Some(range));
+
+        // Touching:
+        assert_eq!(
+            range.intersection(ResolvedTimeRange::new(20, 30)),
+            Some(ResolvedTimeRange::point(20))
+        );
+        assert_eq!(
+            range.intersection(ResolvedTimeRange::new(0, 10)),
+            Some(ResolvedTimeRange::point(10))
+        );
+
+        // Disjoint:
+        assert_eq!(range.intersection(ResolvedTimeRange::new(21, 30)), None);
+        assert_eq!(range.intersection(ResolvedTimeRange::new(0, 9)), None);
+        assert_eq!(range.intersection(ResolvedTimeRange::EMPTY), None);
+
+        // Single point:
+        let point = ResolvedTimeRange::point(15);
+        assert_eq!(range.intersection(point), Some(point));
+        assert_eq!(point.intersection(point), Some(point));
+        assert_eq!(point.intersection(ResolvedTimeRange::point(16)), None);
+
+        assert_eq!(
+            range.intersection(ResolvedTimeRange::EVERYTHING),
+            Some(range)
+        );
+    }
+
+    #[test]
+    fn test_contains_and_length() {
+        let range = ResolvedTimeRange::new(10, 20);
+        assert!(range.contains(TimeInt::new_temporal(10)));
+        assert!(range.contains(TimeInt::new_temporal(20)));
+        assert!(!range.contains(TimeInt::new_temporal(21)));
+        assert_eq!(range.abs_length(), 10);
+
+        let point = ResolvedTimeRange::point(15);
+        assert!(point.contains(TimeInt::new_temporal(15)));
+        assert!(!point.contains(TimeInt::new_temporal(16)));
+        assert_eq!(point.abs_length(), 0);
+    }
+}
@@ -5,14 +5,14 @@ mod time_int;
 mod timeline;
 
 use crate::{
-    time::{Time, TimeZone},
+    time::{Duration, Time, TimeZone},
     ResolvedTimeRange,
 };
 
 // Re-exports
 pub use non_min_i64::{NonMinI64, TryFromIntError};
 pub use time_int::TimeInt;
-pub use timeline::{Timeline, TimelineName};
+pub use timeline::{sort_timelines_canonical, Timeline, TimelineName};
 
 /// A point in time on any number of [`Timeline`]s.
 ///
@@ -96,6 +96,88 @@ impl TimePoint {
         }
         self
     }
+
+    /// Computes the union of two `TimePoint`s.
+    ///
+    /// In case of conflicts, the time value from `other` wins (last-wins).
+    /// See [`Self::union_max`] for keeping the maximum instead.
+    #[inline]
+    pub fn union(&self, other: &Self) -> Self {
+        let mut timepoint = self.clone();
+        timepoint
+            .0
+            .extend(other.iter().map(|(&timeline, &time)| (timeline, time)));
+        timepoint
+    }
+
+    /// Computes the intersection of two `TimePoint`s, i.e. only the timelines present in both.
+    ///
+    /// In case of conflicts, the time value from `other` wins (last-wins), just like [`Self::union`].
+    #[inline]
+    pub fn intersection(&self, other: &Self) -> Self {
+        Self(
+            other
+                .iter()
+                .filter(|(timeline, _)| self.0.contains_key(timeline))
+                .map(|(&timeline, &time)| (timeline, time))
+                .collect(),
+        )
+    }
+}
+
+/// Error returned by [`TimePoint::from_debug_json`].
+#[cfg(feature = "serde")]
+#[derive(thiserror::Error, Debug)]
+pub enum TimePointJsonError {
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+
+    #[error("Unknown timeline {0:?}")]
+    UnknownTimeline(String),
+
+    #[error("Invalid time {time} on timeline {timeline:?}")]
+    InvalidTime { timeline: String, time: i64 },
+}
+
+#[cfg(feature = "serde")]
+impl TimePoint {
+    /// A stable textual form of this time point, for debugging and diffing log streams.
+    ///
+    /// Timelines are keyed by name, in alphabetical order, with their raw [`TimeInt`] values,
+    /// e.g. `{"frame_nr":10,"log_time":1234567890}`.
+    /// The types of the timelines aren't included: see [`Self::from_debug_json`].
+    pub fn to_debug_json(&self) -> String {
+        let times: BTreeMap<&str, i64> = self
+            .iter()
+            .map(|(timeline, time)| (timeline.name().as_str(), time.as_i64()))
+            .collect();
+
+        // Can't fail: all keys are strings.
+        serde_json::to_string(&times).unwrap_or_default()
+    }
+
+    /// Parses the output of [`Self::to_debug_json`].
+    ///
+    /// Since the JSON only contains timeline names, the matching timelines must be provided.
+    pub fn from_debug_json(json: &str, timelines: &[Timeline]) -> Result<Self, TimePointJsonError> {
+        let times: BTreeMap<String, i64> = serde_json::from_str(json)?;
+
+        times
+            .into_iter()
+            .map(|(name, time)| {
+                let Some(timeline) = timelines.iter().find(|t| t.name().as_str() == name) else {
+                    return Err(TimePointJsonError::UnknownTimeline(name));
+                };
+                let time =
+                    TimeInt::try_from(time).map_err(|_err| TimePointJsonError::InvalidTime {
+                        timeline: name,
+                        time,
+                    })?;
+                Ok((*timeline, time))
+            })
+            .collect::<Result<BTreeMap<_, _>, _>>()
+            .map(Self)
+    }
 }
 
 impl re_byte_size::SizeBytes for TimePoint {
@@ -182,8 +264,79 @@ impl TimeType {
     pub fn format_range_utc(&self, time_range: ResolvedTimeRange) -> String {
         self.format_range(time_range, TimeZone::Utc)
     }
+
+    /// Formats `time_range` along with its length, e.g. `#10 – #42 (32 ticks)` or
+    /// `12:00:01 – 12:00:05 (4s)`.
+    ///
+    /// Single-point ranges are formatted as a single time, and empty ranges as `<empty>`.
+    /// The length is left out for unbounded ranges.
+    pub fn format_range_with_length(
+        &self,
+        time_range: ResolvedTimeRange,
+        time_zone_for_timestamps: TimeZone,
+    ) -> String {
+        let (min, max) = (time_range.min(), time_range.max());
+        if max < min {
+            return "<empty>".to_owned();
+        }
+        if min == max {
+            return self.format(min, time_zone_for_timestamps);
+        }
+
+        let range = format!(
+            "{} – {}",
+            self.format(min, time_zone_for_timestamps),
+            self.format(max, time_zone_for_timestamps)
+        );
+
+        let is_bounded = [min, max]
+            .iter()
+            .all(|time| !matches!(*time, TimeInt::STATIC | TimeInt::MIN | TimeInt::MAX));
+        if !is_bounded {
+            return range;
+        }
+
+        let length = match self {
+            Self::Time => {
+                let nanos = i64::try_from(time_range.abs_length()).unwrap_or(i64::MAX);
+                Duration::from_nanos(nanos).to_string()
+            }
+            Self::Sequence => re_format::format_count(time_range.abs_length(), "tick"),
+        };
+
+        format!("{range} ({length})")
+    }
+}
+
+impl std::fmt::Display for TimeType {
+    #[inline]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Time => "Time".fmt(f),
+            Self::Sequence => "Sequence".fmt(f),
+        }
+    }
+}
+
+impl std::str::FromStr for TimeType {
+    type Err = ParseTimeTypeError;
+
+    /// Parses `time` (or its alias `timestamp`) and `sequence`, case-insensitively.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.eq_ignore_ascii_case("time") || s.eq_ignore_ascii_case("timestamp") {
+            Ok(Self::Time)
+        } else if s.eq_ignore_ascii_case("sequence") {
+            Ok(Self::Sequence)
+        } else {
+            Err(ParseTimeTypeError(s.to_owned()))
+        }
+    }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("unknown time type {0:?}, expected \"time\" or \"sequence\"")]
+pub struct ParseTimeTypeError(pub String);
+
 // ----------------------------------------------------------------------------
 
 impl IntoIterator for TimePoint {
@@ -239,9 +392,36 @@ impl<T: TryInto<TimeInt>, const N: usize> From<[(Timeline, T); N]> for TimePoint
 
 // ----------------------------------------------------------------------------
 
+/// Build a [`TimePoint`] from any number of `timeline => time` pairs.
+///
+/// The time can be anything that converts into a [`TimeInt`].
+/// If the same timeline is specified more than once, the last time wins.
+/// No pairs at all result in a static [`TimePoint`].
+///
+/// ```
+/// # use re_log_types::*;
+/// let timepoint = time_point![
+///     Timeline::new_sequence("frame") => 10,
+///     Timeline::new_temporal("sim_time") => TimeInt::new_temporal(1_000_000),
+/// ];
+/// assert_eq!(timepoint.timelines().len(), 2);
+/// assert!(time_point![].is_static());
+/// ```
+#[macro_export]
+macro_rules! time_point {
+    ($($timeline: expr => $time: expr),* $(,)?) => {
+        $crate::TimePoint::default()$(.with($timeline, $time))*
+    };
+}
+
+// ----------------------------------------------------------------------------
+
 #[cfg(test)]
 mod tests {
-    use super::{TimeInt, TimeType};
+    use super::{
+        sort_timelines_canonical, ParseTimeTypeError, TimeInt, TimePoint, TimeType, Timeline,
+    };
+    use crate::{time::TimeZone, ResolvedTimeRange, TryFromIntError};
 
     #[test]
     fn test_format_parse() {
@@ -258,4 +438,327 @@ mod tests {
             assert_eq!(TimeType::parse_sequence(s), Some(int));
         }
     }
+
+    #[test]
+    fn test_time_type_from_str() {
+        for typ in [TimeType::Time, TimeType::Sequence] {
+            assert_eq!(typ.to_string().parse::<TimeType>(), Ok(typ));
+        }
+
+        assert_eq!("timestamp".parse(), Ok(TimeType::Time));
+        assert_eq!("SEQUENCE".parse(), Ok(TimeType::Sequence));
+
+        for garbage in ["", "seq", "times", "frame"] {
+            assert_eq!(
+                garbage.parse::<TimeType>(),
+                Err(ParseTimeTypeError(garbage.to_owned()))
+            );
+        }
+    }
+
+    #[test]
+    fn test_timeline_format_time() {
+        let frame_nr = Timeline::new_sequence("frame_nr");
+        assert_eq!(frame_nr.format_time_utc(TimeInt::new_temporal(42)), "#42");
+        assert_eq!(
+            frame_nr.format_time_utc(TimeInt::new_temporal(-12_345)),
+            "#−12 345"
+        );
+
+        let log_time = Timeline::new_temporal("log_time");
+        assert_eq!(
+            log_time.format_time_utc(TimeInt::new_temporal(1_646_087_742_000_000_000)),
+            "2022-02-28 22:35:42Z"
+        );
+        assert_eq!(
+            log_time.format_time(TimeInt::new_temporal(42_000_000_000), TimeZone::Local),
+            "+42s",
+            "small values are relative times"
+        );
+
+        for timeline in [frame_nr, log_time] {
+            assert_eq!(timeline.format_time_utc(TimeInt::STATIC), "<static>");
+            assert_eq!(timeline.format_time_utc(TimeInt::MIN), "−∞");
+            assert_eq!(timeline.format_time_utc(TimeInt::MAX), "+∞");
+        }
+    }
+
+    #[test]
+    fn test_format_range_with_length() {
+        let sequence = |min: i64, max: i64| {
+            TimeType::Sequence
+                .format_range_with_length(ResolvedTimeRange::new(min, max), TimeZone::Utc)
+        };
+        assert_eq!(sequence(10, 42), "#10 – #42 (32 ticks)");
+        assert_eq!(sequence(1, 2), "#1 – #2 (1 tick)");
+        assert_eq!(sequence(-5, 1_000), "#−5 – #1 000 (1 005 ticks)");
+        assert_eq!(sequence(7, 7), "#7");
+        assert_eq!(sequence(42, 10), "<empty>");
+        assert_eq!(
+            TimeType::Sequence
+                .format_range_with_length(ResolvedTimeRange::EVERYTHING, TimeZone::Utc),
+            "−∞ – +∞"
+        );
+
+        let time = |min: i64, max: i64| {
+            TimeType::Time.format_range_with_length(ResolvedTimeRange::new(min, max), TimeZone::Utc)
+        };
+        assert_eq!(
+            time(1_672_574_401_000_000_000, 1_672_574_405_000_000_000),
+            "2023-01-01 12:00:01Z – 2023-01-01 12:00:05Z (4s)"
+        );
+        assert_eq!(
+            time(1_000_000_000, 61_500_000_000),
+            "+1s – +61.500s (1m 0.500s)",
+            "small values are relative times"
+        );
+        assert_eq!(time(1_000_000_000, 1_000_000_000), "+1s");
+    }
+
+    #[test]
+    fn test_time_int_saturating_arithmetic() {
+        let t = TimeInt::new_temporal;
+
+        assert_eq!(t(40).saturating_add(2), t(42));
+        assert_eq!(t(40).saturating_sub(-2), t(42));
+        assert_eq!(t(44).saturating_sub(2), t(42));
+        assert_eq!(t(44).saturating_add(-2), t(42));
+
+        // Saturates at the temporal bounds, and never turns into static:
+        assert_eq!(TimeInt::MAX.saturating_add(1), TimeInt::MAX);
+        assert_eq!(t(i64::MAX - 1).saturating_add(i64::MAX), TimeInt::MAX);
+        assert_eq!(TimeInt::MIN.saturating_sub(1), TimeInt::MIN);
+        assert_eq!(TimeInt::MIN.saturating_add(-1), TimeInt::MIN);
+        assert_eq!(t(0).saturating_add(i64::MIN), TimeInt::MIN);
+        assert_eq!(t(0).saturating_sub(i64::MIN), TimeInt::MAX);
+        assert_eq!(t(-1).saturating_sub(i64::MAX), TimeInt::MIN);
+
+        // Static stays static:
+        assert_eq!(TimeInt::STATIC.saturating_add(1), TimeInt::STATIC);
+        assert_eq!(TimeInt::STATIC.saturating_sub(1), TimeInt::STATIC);
+        assert_eq!(TimeInt::STATIC.saturating_add(i64::MAX), TimeInt::STATIC);
+    }
+
+    #[test]
+    fn test_time_int_try_new_temporal() {
+        assert_eq!(TimeInt::try_new_temporal(42), Ok(TimeInt::new_temporal(42)));
+        assert_eq!(TimeInt::try_new_temporal(i64::MIN + 1), Ok(TimeInt::MIN));
+        assert_eq!(TimeInt::try_new_temporal(i64::MIN), Err(TryFromIntError));
+
+        // Unlike `new_temporal`, which silently clamps:
+        assert_eq!(TimeInt::new_temporal(i64::MIN), TimeInt::MIN);
+
+        assert_eq!(
+            TimeInt::try_from_milliseconds(42),
+            Ok(TimeInt::new_temporal(42_000_000))
+        );
+        assert_eq!(
+            TimeInt::try_from_milliseconds(-42),
+            Ok(TimeInt::new_temporal(-42_000_000))
+        );
+        assert_eq!(
+            TimeInt::try_from_milliseconds(i64::MIN),
+            Err(TryFromIntError)
+        );
+        assert_eq!(
+            TimeInt::try_from_milliseconds(i64::MAX),
+            Err(TryFromIntError)
+        );
+    }
+
+    #[test]
+    fn test_time_int_clamp_to() {
+        let t = TimeInt::new_temporal;
+        let range = ResolvedTimeRange::new(10_i64, 20_i64);
+
+        assert_eq!(t(15).clamp_to(range), t(15));
+        assert_eq!(t(10).clamp_to(range), t(10));
+        assert_eq!(t(20).clamp_to(range), t(20));
+        assert_eq!(t(5).clamp_to(range), t(10));
+        assert_eq!(t(25).clamp_to(range), t(20));
+        assert_eq!(TimeInt::MIN.clamp_to(range), t(10));
+        assert_eq!(TimeInt::MAX.clamp_to(range), t(20));
+
+        assert_eq!(TimeInt::STATIC.clamp_to(range), TimeInt::STATIC);
+        assert_eq!(t(42).clamp_to(ResolvedTimeRange::EMPTY), t(42));
+        assert_eq!(t(42).clamp_to(ResolvedTimeRange::EVERYTHING), t(42));
+        assert_eq!(
+            TimeInt::MIN.clamp_to(ResolvedTimeRange::EVERYTHING),
+            TimeInt::MIN
+        );
+    }
+
+    #[test]
+    fn test_union_intersection() {
+        let frame = Timeline::new_sequence("frame");
+        let sim_time = Timeline::new_temporal("sim_time");
+        let log_tick = Timeline::new_sequence("log_tick");
+
+        let lhs = time_point![frame => 1, sim_time => 100];
+        let overlapping = time_point![frame => 2, log_tick => 7];
+        let disjoint = time_point![log_tick => 7];
+
+        // Overlapping: the right-hand side wins conflicts.
+        assert_eq!(
+            lhs.union(&overlapping),
+            time_point![frame => 2, sim_time => 100, log_tick => 7]
+        );
+        assert_eq!(
+            overlapping.union(&lhs),
+            time_point![frame => 1, sim_time => 100, log_tick => 7]
+        );
+        assert_eq!(lhs.intersection(&overlapping), time_point![frame => 2]);
+        assert_eq!(overlapping.intersection(&lhs), time_point![frame => 1]);
+
+        // … unlike `union_max`, which keeps the maximum:
+        assert_eq!(
+            overlapping.clone().union_max(&lhs),
+            time_point![frame => 2, sim_time => 100, log_tick => 7]
+        );
+
+        // Disjoint:
+        assert_eq!(
+            lhs.union(&disjoint),
+            time_point![frame => 1, sim_time => 100, log_tick => 7]
+        );
+        assert_eq!(lhs.union(&disjoint), disjoint.union(&lhs));
+        assert!(lhs.intersection(&disjoint).is_static());
+
+        // Static:
+        assert_eq!(lhs.union(&TimePoint::default()), lhs);
+        assert!(lhs.intersection(&TimePoint::default()).is_static());
+    }
+
+    #[test]
+    fn test_time_point_macro_last_wins() {
+        let frame = Timeline::new_sequence("frame");
+        let sim_time = Timeline::new_temporal("sim_time");
+
+        let timepoint = time_point![
+            frame => 1,
+            sim_time => 100,
+            frame => 2,
+        ];
+
+        assert_eq!(timepoint.timelines().len(), 2);
+        assert_eq!(timepoint.get(&frame), Some(&TimeInt::new_temporal(2)));
+        assert_eq!(timepoint.get(&sim_time), Some(&TimeInt::new_temporal(100)));
+    }
+
+    #[test]
+    fn test_time_point_macro_ordering() {
+        let a = Timeline::new_sequence("a");
+        let b = Timeline::new_sequence("b");
+        let c = Timeline::new_temporal("c");
+
+        let forwards = time_point![a => 1, b => 2, c => 3];
+        let backwards = time_point![c => 3, b => 2, a => 1];
+
+        // The order in which timelines are specified doesn't matter.
+        assert_eq!(forwards, backwards);
+        assert_eq!(
+            forwards.iter().collect::<Vec<_>>(),
+            backwards.iter().collect::<Vec<_>>()
+        );
+        assert_eq!(forwards, TimePoint::from([(a, 1), (b, 2), (c, 3)]));
+    }
+
+    #[test]
+    fn test_sort_timelines_canonical() {
+        let mut timelines = vec![
+            Timeline::new_sequence("step"),
+            Timeline::new_temporal("sensor_time"),
+            Timeline::log_tick(),
+            Timeline::new_sequence("frame_nr"),
+            Timeline::log_time(),
+            Timeline::new_sequence("Zebra"),
+            Timeline::new_sequence("a_sequence"),
+        ];
+        sort_timelines_canonical(&mut timelines);
+
+        assert_eq!(
+            timelines
+                .iter()
+                .map(|t| t.name().as_str())
+                .collect::<Vec<_>>(),
+            [
+                "log_time",
+                "log_tick",
+                "Zebra",
+                "a_sequence",
+                "frame_nr",
+                "sensor_time",
+                "step"
+            ]
+        );
+
+        // The order doesn't depend on the order of discovery:
+        let mut reversed = timelines.iter().rev().copied().collect::<Vec<_>>();
+        sort_timelines_canonical(&mut reversed);
+        assert_eq!(reversed, timelines);
+
+        // Only the actual built-ins are special, not just anything with the same name:
+        let mut timelines = vec![
+            Timeline::new_sequence("log_time"),
+            Timeline::new_sequence("a_sequence"),
+            Timeline::log_time(),
+        ];
+        sort_timelines_canonical(&mut timelines);
+        assert_eq!(
+            timelines,
+            [
+                Timeline::log_time(),
+                Timeline::new_sequence("a_sequence"),
+                Timeline::new_sequence("log_time"),
+            ]
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_time_point_debug_json() {
+        use super::TimePointJsonError;
+
+        let frame_nr = Timeline::new_sequence("frame_nr");
+        let log_time = Timeline::log_time();
+        let sim_time = Timeline::new_temporal("sim_time");
+        let timelines = [frame_nr, log_time, sim_time];
+
+        let timepoint = TimePoint::default()
+            .with(log_time, 1_234_567_890_i64)
+            .with(frame_nr, 10_i64)
+            .with(sim_time, -5_i64);
+
+        let json = timepoint.to_debug_json();
+        assert_eq!(
+            json,
+            r#"{"frame_nr":10,"log_time":1234567890,"sim_time":-5}"#
+        );
+        assert_eq!(
+            TimePoint::from_debug_json(&json, &timelines).unwrap(),
+            timepoint
+        );
+
+        // Static:
+        assert_eq!(TimePoint::default().to_debug_json(), "{}");
+        assert_eq!(
+            TimePoint::from_debug_json("{}", &timelines).unwrap(),
+            TimePoint::default()
+        );
+
+        assert!(matches!(
+            TimePoint::from_debug_json(&json, &[frame_nr, log_time]),
+            Err(TimePointJsonError::UnknownTimeline(name)) if name == "sim_time"
+        ));
+        assert!(matches!(
+            TimePoint::from_debug_json(r#"{"frame_nr":"ten"}"#, &timelines),
+            Err(TimePointJsonError::Json(_))
+        ));
+    }
+
+    #[test]
+    fn test_time_point_macro_empty() {
+        assert!(time_point![].is_static());
+        assert_eq!(time_point![], TimePoint::default());
+    }
 }
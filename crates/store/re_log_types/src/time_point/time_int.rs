@@ -1,4 +1,4 @@
-use crate::{time::Time, Duration, NonMinI64, TryFromIntError};
+use crate::{time::Time, Duration, NonMinI64, ResolvedTimeRange, TryFromIntError};
 
 /// A 64-bit number describing either nanoseconds, sequence numbers or fully static data.
 ///
@@ -67,6 +67,7 @@ impl TimeInt {
     /// Creates a new temporal [`TimeInt`].
     ///
     /// If `time` is `i64::MIN`, this will return [`TimeInt::MIN`].
+    /// Use [`Self::try_new_temporal`] to reject it instead.
     ///
     /// This can't return [`TimeInt::STATIC`], ever.
     #[inline]
@@ -74,6 +75,16 @@ impl TimeInt {
         NonMinI64::new(time).map_or(Self::MIN, |t| Self(Some(t)))
     }
 
+    /// Creates a new temporal [`TimeInt`], failing if `time` is `i64::MIN`.
+    ///
+    /// `i64::MIN` is reserved for [`TimeInt::STATIC`], so it can't be a temporal value.
+    #[inline]
+    pub fn try_new_temporal(time: i64) -> Result<Self, TryFromIntError> {
+        NonMinI64::new(time)
+            .map(|t| Self(Some(t)))
+            .ok_or(TryFromIntError)
+    }
+
     /// For time timelines.
     #[inline]
     pub fn from_nanos(nanos: NonMinI64) -> Self {
@@ -81,12 +92,29 @@ impl TimeInt {
     }
 
     /// For time timelines.
+    ///
+    /// Saturates at [`TimeInt::MIN`] and [`TimeInt::MAX`] if the result doesn't fit in nanoseconds.
     #[inline]
     pub fn from_milliseconds(millis: NonMinI64) -> Self {
         Self::new_temporal(millis.get().saturating_mul(1_000_000))
     }
 
     /// For time timelines.
+    ///
+    /// Fails if `millis` is `i64::MIN`, or if the result doesn't fit in nanoseconds.
+    #[inline]
+    pub fn try_from_milliseconds(millis: i64) -> Result<Self, TryFromIntError> {
+        let millis = NonMinI64::new(millis).ok_or(TryFromIntError)?;
+        millis
+            .get()
+            .checked_mul(1_000_000)
+            .ok_or(TryFromIntError)
+            .and_then(Self::try_new_temporal)
+    }
+
+    /// For time timelines.
+    ///
+    /// Saturates at [`TimeInt::MIN`] and [`TimeInt::MAX`] if the result doesn't fit in nanoseconds.
     #[inline]
     pub fn from_seconds(seconds: NonMinI64) -> Self {
         Self::new_temporal(seconds.get().saturating_mul(1_000_000_000))
@@ -135,6 +163,46 @@ impl TimeInt {
             None => self,
         }
     }
+
+    /// Adds `delta` to a temporal value, saturating at [`Self::MIN`] and [`Self::MAX`].
+    ///
+    /// The result is never [`Self::STATIC`], except for [`Self::STATIC`] itself which is
+    /// returned as is.
+    #[inline]
+    #[must_use]
+    pub fn saturating_add(self, delta: i64) -> Self {
+        match self.0 {
+            Some(t) => Self::new_temporal(t.get().saturating_add(delta)),
+            None => self,
+        }
+    }
+
+    /// Subtracts `delta` from a temporal value, saturating at [`Self::MIN`] and [`Self::MAX`].
+    ///
+    /// The result is never [`Self::STATIC`], except for [`Self::STATIC`] itself which is
+    /// returned as is.
+    #[inline]
+    #[must_use]
+    pub fn saturating_sub(self, delta: i64) -> Self {
+        match self.0 {
+            Some(t) => Self::new_temporal(t.get().saturating_sub(delta)),
+            None => self,
+        }
+    }
+
+    /// Clamps a temporal value to lie within `range` (inclusive).
+    ///
+    /// [`Self::STATIC`] is returned as is, since static data is not part of any timeline,
+    /// and so is every value if `range` is empty.
+    #[inline]
+    #[must_use]
+    pub fn clamp_to(self, range: ResolvedTimeRange) -> Self {
+        if self.is_static() || range.max() < range.min() {
+            self
+        } else {
+            self.max(range.min()).min(range.max())
+        }
+    }
 }
 
 impl TryFrom<i64> for TimeInt {
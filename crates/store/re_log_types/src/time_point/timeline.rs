@@ -1,6 +1,6 @@
 use arrow2::datatypes::{DataType, TimeUnit};
 
-use crate::{time::TimeZone, ResolvedTimeRange, TimeType};
+use crate::{time::TimeZone, ResolvedTimeRange, TimeInt, TimeType};
 
 re_string_interner::declare_new_type!(
     /// The name of a timeline. Often something like `"log_time"` or `"frame_nr"`.
@@ -93,6 +93,25 @@ impl Timeline {
         Self::new("log_tick", TimeType::Sequence)
     }
 
+    /// Returns a human-readable string of `time` on this `Timeline`.
+    ///
+    /// Sequences are shown as e.g. `#42`, and times as either timestamps or relative times,
+    /// depending on their value.
+    #[inline]
+    pub fn format_time(
+        &self,
+        time: impl Into<TimeInt>,
+        time_zone_for_timestamps: TimeZone,
+    ) -> String {
+        self.typ.format(time, time_zone_for_timestamps)
+    }
+
+    /// Returns a human-readable string of `time` on this `Timeline`, using UTC for timestamps.
+    #[inline]
+    pub fn format_time_utc(&self, time: impl Into<TimeInt>) -> String {
+        self.format_time(time, TimeZone::Utc)
+    }
+
     /// Returns a formatted string of `time_range` on this `Timeline`.
     #[inline]
     pub fn format_time_range(
@@ -102,8 +121,8 @@ impl Timeline {
     ) -> String {
         format!(
             "{}..={}",
-            self.typ.format(time_range.min(), time_zone_for_timestamps),
-            self.typ.format(time_range.max(), time_zone_for_timestamps),
+            self.format_time(time_range.min(), time_zone_for_timestamps),
+            self.format_time(time_range.max(), time_zone_for_timestamps),
         )
     }
 
@@ -123,6 +142,28 @@ impl Timeline {
     }
 }
 
+/// Sorts timelines in the order they should be listed in the UI.
+///
+/// The built-in [`Timeline::log_time`] and [`Timeline::log_tick`] come first, followed by all
+/// other timelines sorted alphabetically by name.
+/// Unlike the order of discovery, this doesn't change as more timelines get logged.
+pub fn sort_timelines_canonical(timelines: &mut [Timeline]) {
+    let builtins = [Timeline::log_time(), Timeline::log_tick()];
+    let builtin_index = |timeline: &Timeline| {
+        builtins
+            .iter()
+            .position(|builtin| builtin == timeline)
+            .unwrap_or(builtins.len())
+    };
+
+    timelines.sort_by(|a, b| {
+        builtin_index(a)
+            .cmp(&builtin_index(b))
+            .then_with(|| a.name().as_str().cmp(b.name().as_str()))
+            .then_with(|| a.typ().cmp(&b.typ()))
+    });
+}
+
 impl nohash_hasher::IsEnabled for Timeline {}
 
 impl re_byte_size::SizeBytes for Timeline {
@@ -228,6 +228,8 @@ pub enum Compression {
     None = 0,
     /// LZ4 block compression.
     Lz4 = 1,
+    /// Zstandard compression.
+    Zstd = 2,
 }
 impl Compression {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -238,6 +240,7 @@ impl Compression {
         match self {
             Self::None => "NONE",
             Self::Lz4 => "LZ4",
+            Self::Zstd => "ZSTD",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -245,6 +248,7 @@ impl Compression {
         match value {
             "NONE" => Some(Self::None),
             "LZ4" => Some(Self::Lz4),
+            "ZSTD" => Some(Self::Zstd),
             _ => None,
         }
     }
@@ -310,6 +314,10 @@ pub enum StoreSourceKind {
     ///
     /// `extra` is a string.
     Other = 6,
+    /// The recording was forwarded by a message proxy server.
+    ///
+    /// `extra` is the url of the proxy, as a string.
+    MessageProxy = 7,
 }
 impl StoreSourceKind {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -325,6 +333,7 @@ impl StoreSourceKind {
             Self::File => "FILE",
             Self::Viewer => "VIEWER",
             Self::Other => "OTHER",
+            Self::MessageProxy => "MESSAGE_PROXY",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -337,6 +346,7 @@ impl StoreSourceKind {
             "FILE" => Some(Self::File),
             "VIEWER" => Some(Self::Viewer),
             "OTHER" => Some(Self::Other),
+            "MESSAGE_PROXY" => Some(Self::MessageProxy),
             _ => None,
         }
     }
@@ -602,7 +602,7 @@ impl std::fmt::Debug for LatestAtCache {
         for (query_time, unit) in per_query_time {
             strings.push(format!(
                 "query_time={} ({})",
-                cache_key.timeline.typ().format_utc(*query_time),
+                cache_key.timeline.format_time_utc(*query_time),
                 re_format::format_bytes(unit.total_size_bytes() as _)
             ));
         }
@@ -1,7 +1,9 @@
-use std::{fmt, net::SocketAddr, thread::JoinHandle};
+use std::{fmt, net::SocketAddr, sync::Arc, thread::JoinHandle};
 
 use crossbeam::channel::{select, Receiver, Sender};
+use parking_lot::{Condvar, Mutex};
 
+use re_byte_size::SizeBytes as _;
 use re_log_types::LogMsg;
 
 #[derive(Debug, PartialEq, Eq)]
@@ -25,21 +27,141 @@ enum InterruptMsg {
 }
 
 enum MsgMsg {
-    LogMsg(LogMsg),
+    /// A message together with the number of bytes it reserved from the [`MemoryBudget`].
+    LogMsg(LogMsg, u64),
     Flush,
 }
 
 enum PacketMsg {
-    Packet(Vec<u8>),
+    /// An encoded message together with the number of bytes it reserved from the [`MemoryBudget`].
+    Packet(Vec<u8>, u64),
     Flush,
 }
 
+/// Options for the [`Client`].
+#[derive(Clone, Debug)]
+pub struct ClientOptions {
+    /// The minimum time the [`Client`] will wait during a flush before potentially dropping data.
+    ///
+    /// Note: Passing `None` here can cause a call to `flush` to block indefinitely
+    /// if a connection cannot be established.
+    pub flush_timeout: Option<std::time::Duration>,
+
+    /// Upper limit on how many bytes of not-yet-sent messages the [`Client`] holds on to.
+    ///
+    /// `None` means unlimited.
+    pub max_memory_bytes: Option<u64>,
+
+    /// What to do when [`Self::max_memory_bytes`] is reached.
+    ///
+    /// If `true`, [`Client::send`] waits until enough messages have been sent. If no message gets
+    /// sent within [`Self::flush_timeout`], the new message is dropped after all.
+    /// Otherwise, new messages are dropped right away.
+    ///
+    /// Note that only the memory of the [`Client`] itself is bounded. When used as the sink of a
+    /// `RecordingStream`, [`Client::send`] is called from its background thread: the logging calls
+    /// never block, and data piles up in the (unbounded) queues of the recording stream while the
+    /// [`Client`] is waiting.
+    pub blocking_when_full: bool,
+}
+
+impl Default for ClientOptions {
+    fn default() -> Self {
+        Self {
+            flush_timeout: crate::default_flush_timeout(),
+            max_memory_bytes: None,
+            blocking_when_full: false,
+        }
+    }
+}
+
+/// Keeps track of how many bytes are queued up in the pipes of a [`Client`].
+struct MemoryBudget {
+    max_bytes: Option<u64>,
+    blocking_when_full: bool,
+
+    /// How long to wait for queued messages to be sent when full, see [`ClientOptions::flush_timeout`].
+    max_wait: Option<std::time::Duration>,
+
+    used_bytes: Mutex<u64>,
+    freed: Condvar,
+}
+
+impl MemoryBudget {
+    fn new(options: &ClientOptions) -> Self {
+        Self {
+            max_bytes: options.max_memory_bytes,
+            blocking_when_full: options.blocking_when_full,
+            max_wait: options.flush_timeout,
+            used_bytes: Mutex::new(0),
+            freed: Condvar::new(),
+        }
+    }
+
+    /// Reserves `num_bytes` from the budget.
+    ///
+    /// Returns `false` if the message should be dropped instead.
+    fn reserve(&self, num_bytes: u64) -> bool {
+        let Some(max_bytes) = self.max_bytes else {
+            return true;
+        };
+
+        let mut used_bytes = self.used_bytes.lock();
+        loop {
+            // Always let a message through if nothing is queued, even if it exceeds the budget on its own.
+            if *used_bytes == 0 || *used_bytes + num_bytes <= max_bytes {
+                *used_bytes += num_bytes;
+                return true;
+            }
+
+            if !self.blocking_when_full {
+                return false;
+            }
+
+            if let Some(max_wait) = self.max_wait {
+                // Don't wait forever for a connection that never comes.
+                if self.freed.wait_for(&mut used_bytes, max_wait).timed_out() {
+                    return false;
+                }
+            } else {
+                self.freed.wait(&mut used_bytes);
+            }
+        }
+    }
+
+    fn release(&self, num_bytes: u64) {
+        if self.max_bytes.is_none() {
+            return;
+        }
+
+        let mut used_bytes = self.used_bytes.lock();
+        *used_bytes = used_bytes.saturating_sub(num_bytes);
+        self.freed.notify_all();
+    }
+}
+
+/// Estimates how much memory a message takes up while it is queued for sending.
+fn queued_size_bytes(log_msg: &LogMsg) -> u64 {
+    match log_msg {
+        LogMsg::ArrowMsg(_, arrow_msg) => arrow_msg
+            .chunk
+            .arrays()
+            .iter()
+            .map(|array| array.total_size_bytes())
+            .sum(),
+        LogMsg::SetStoreInfo(_) | LogMsg::BlueprintActivationCommand(_) => {
+            std::mem::size_of_val(log_msg) as u64
+        }
+    }
+}
+
 /// Send [`LogMsg`]es to a server over TCP.
 ///
 /// The messages are encoded and sent on separate threads
 /// so that calling [`Client::send`] is non-blocking.
 pub struct Client {
     msg_tx: Sender<MsgMsg>,
+    memory_budget: Arc<MemoryBudget>,
     flushed_rx: Receiver<FlushedMsg>,
     encode_quit_tx: Sender<QuitMsg>,
     send_quit_tx: Sender<InterruptMsg>,
@@ -58,10 +180,22 @@ impl Client {
     /// cause a call to `flush` to block indefinitely if a connection cannot be
     /// established.
     pub fn new(addr: SocketAddr, flush_timeout: Option<std::time::Duration>) -> Self {
+        Self::new_with_options(
+            addr,
+            ClientOptions {
+                flush_timeout,
+                ..Default::default()
+            },
+        )
+    }
+
+    /// Connect via TCP to this log server, using the given [`ClientOptions`].
+    pub fn new_with_options(addr: SocketAddr, options: ClientOptions) -> Self {
         re_log::debug!("Connecting to remote {addr}…");
 
-        // TODO(emilk): keep track of how much memory is in each pipe
-        // and apply back-pressure to not use too much RAM.
+        let ClientOptions { flush_timeout, .. } = options;
+        let memory_budget = Arc::new(MemoryBudget::new(&options));
+
         let (msg_tx, msg_rx) = crossbeam::channel::unbounded();
         let (packet_tx, packet_rx) = crossbeam::channel::unbounded();
         let (flushed_tx, flushed_rx) = crossbeam::channel::unbounded();
@@ -75,20 +209,40 @@ impl Client {
 
         let encode_join = std::thread::Builder::new()
             .name("msg_encoder".into())
-            .spawn(move || {
-                msg_encode(encoding_options, &msg_rx, &encode_quit_rx, &packet_tx);
+            .spawn({
+                let memory_budget = memory_budget.clone();
+                move || {
+                    msg_encode(
+                        encoding_options,
+                        &memory_budget,
+                        &msg_rx,
+                        &encode_quit_rx,
+                        &packet_tx,
+                    );
+                }
             })
             .expect("Failed to spawn thread");
 
         let send_join = std::thread::Builder::new()
             .name("tcp_sender".into())
-            .spawn(move || {
-                tcp_sender(addr, flush_timeout, &packet_rx, &send_quit_rx, &flushed_tx);
+            .spawn({
+                let memory_budget = memory_budget.clone();
+                move || {
+                    tcp_sender(
+                        addr,
+                        flush_timeout,
+                        &memory_budget,
+                        &packet_rx,
+                        &send_quit_rx,
+                        &flushed_tx,
+                    );
+                }
             })
             .expect("Failed to spawn thread");
 
         Self {
             msg_tx,
+            memory_budget,
             flushed_rx,
             encode_quit_tx,
             send_quit_tx,
@@ -98,8 +252,19 @@ impl Client {
         }
     }
 
+    /// Queues a message for sending.
+    ///
+    /// If the configured [`ClientOptions::max_memory_bytes`] is exceeded, this either waits or
+    /// drops the message, depending on [`ClientOptions::blocking_when_full`].
     pub fn send(&self, log_msg: LogMsg) {
-        self.send_msg_msg(MsgMsg::LogMsg(log_msg));
+        let num_bytes = queued_size_bytes(&log_msg);
+        if !self.memory_budget.reserve(num_bytes) {
+            re_log::warn_once!(
+                "Dropping messages because the TCP client has reached its memory limit."
+            );
+            return;
+        }
+        self.send_msg_msg(MsgMsg::LogMsg(log_msg, num_bytes));
     }
 
     /// Stall until all messages so far has been sent.
@@ -163,6 +328,7 @@ impl fmt::Debug for Client {
 
 fn msg_encode(
     encoding_options: re_log_encoding::EncodingOptions,
+    memory_budget: &MemoryBudget,
     msg_rx: &Receiver<MsgMsg>,
     quit_rx: &Receiver<QuitMsg>,
     packet_tx: &Sender<PacketMsg>,
@@ -176,17 +342,18 @@ fn msg_encode(
                 };
 
                 let packet_msg = match &msg_msg {
-                    MsgMsg::LogMsg(log_msg) => {
+                    MsgMsg::LogMsg(log_msg, num_bytes) => {
                         match re_log_encoding::encoder::encode_to_bytes(
                             re_build_info::CrateVersion::LOCAL,
                             encoding_options, std::iter::once(log_msg),
                         ) {
                             Ok(packet) => {
                                 re_log::trace!("Encoded message of size {}", packet.len());
-                                Some(PacketMsg::Packet(packet))
+                                Some(PacketMsg::Packet(packet, *num_bytes))
                             }
                             Err(err) => {
                                 re_log::error_once!("Failed to encode log message: {err}");
+                                memory_budget.release(*num_bytes);
                                 None
                             }
                         }
@@ -212,6 +379,7 @@ fn msg_encode(
 fn tcp_sender(
     addr: SocketAddr,
     flush_timeout: Option<std::time::Duration>,
+    memory_budget: &MemoryBudget,
     packet_rx: &Receiver<PacketMsg>,
     quit_rx: &Receiver<InterruptMsg>,
     flushed_tx: &Sender<FlushedMsg>,
@@ -226,8 +394,10 @@ fn tcp_sender(
             recv(packet_rx) -> packet_msg => {
                 if let Ok(packet_msg) = packet_msg {
                     match packet_msg {
-                        PacketMsg::Packet(packet) => {
-                            match send_until_success(&mut tcp_client, drop_if_disconnected, &packet, quit_rx) {
+                        PacketMsg::Packet(packet, num_bytes) => {
+                            let interrupt = send_until_success(&mut tcp_client, drop_if_disconnected, &packet, quit_rx);
+                            memory_budget.release(num_bytes);
+                            match interrupt {
                                 Some(InterruptMsg::Quit) => {return;}
                                 Some(InterruptMsg::DropIfDisconnected) => {
                                     drop_if_disconnected = true;
@@ -325,3 +495,56 @@ fn send_until_success(
         None
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{ClientOptions, MemoryBudget};
+
+    fn budget(blocking_when_full: bool) -> MemoryBudget {
+        MemoryBudget::new(&ClientOptions {
+            flush_timeout: Some(Duration::from_millis(10)),
+            max_memory_bytes: Some(100),
+            blocking_when_full,
+        })
+    }
+
+    #[test]
+    fn test_memory_budget_drops_when_full() {
+        let budget = budget(false);
+
+        // A single message may exceed the budget on its own:
+        assert!(budget.reserve(150));
+        assert!(!budget.reserve(10));
+
+        budget.release(150);
+        assert!(budget.reserve(60));
+        assert!(budget.reserve(40));
+        assert!(!budget.reserve(1));
+    }
+
+    #[test]
+    fn test_memory_budget_waits_when_full() {
+        let budget = budget(true);
+        assert!(budget.reserve(100));
+
+        // Nothing gets sent, so it gives up after the flush timeout:
+        assert!(!budget.reserve(10));
+
+        // Once something gets sent, the waiting message gets through:
+        let budget = std::sync::Arc::new(MemoryBudget {
+            max_wait: None,
+            ..budget
+        });
+        let sender = std::thread::spawn({
+            let budget = budget.clone();
+            move || {
+                std::thread::sleep(Duration::from_millis(10));
+                budget.release(100);
+            }
+        });
+        assert!(budget.reserve(10));
+        sender.join().unwrap();
+    }
+}
@@ -11,13 +11,16 @@ pub(crate) mod tcp_client;
 mod buffered_client;
 
 #[cfg(feature = "client")]
-pub use {buffered_client::Client, tcp_client::ClientError};
+pub use {
+    buffered_client::{Client, ClientOptions},
+    tcp_client::ClientError,
+};
 
 #[cfg(feature = "server")]
 mod server;
 
 #[cfg(feature = "server")]
-pub use server::{serve, ServerError, ServerOptions};
+pub use server::{serve, serve_with_addr, ServerError, ServerOptions};
 
 /// Server connection error.
 ///
@@ -1,6 +1,6 @@
 use std::{
     io::{ErrorKind, Read as _},
-    net::{TcpListener, TcpStream},
+    net::{Ipv4Addr, Ipv6Addr, SocketAddr, TcpListener, TcpStream},
     time::Instant,
 };
 
@@ -59,18 +59,47 @@ pub fn serve(
     port: u16,
     options: ServerOptions,
 ) -> Result<Receiver<LogMsg>, ServerError> {
-    let (tx, rx) = re_smart_channel::smart_channel(
-        // NOTE: We don't know until we start actually accepting clients!
-        re_smart_channel::SmartMessageSource::Unknown,
-        re_smart_channel::SmartChannelSource::TcpServer { port },
-    );
+    serve_with_addr(bind_ip, port, options).map(|(rx, _connect_addr)| rx)
+}
 
+/// Like [`serve`], but also returns the address that SDKs should connect to.
+///
+/// This is the address the server actually bound to, so it has the real port even when binding to
+/// port 0 to let the OS pick a free one. An unspecified IP (`0.0.0.0`) is replaced with localhost.
+///
+/// ``` no_run
+/// # use re_sdk_comms::{serve_with_addr, ServerOptions};
+/// let (log_msg_rx, connect_addr) = serve_with_addr("0.0.0.0", 0, ServerOptions::default()).unwrap();
+/// println!("Connect your SDK to {connect_addr}");
+/// ```
+pub fn serve_with_addr(
+    bind_ip: &str,
+    port: u16,
+    options: ServerOptions,
+) -> Result<(Receiver<LogMsg>, SocketAddr), ServerError> {
     let bind_addr = format!("{bind_ip}:{port}");
     let listener = TcpListener::bind(&bind_addr).map_err(|err| ServerError::TcpBindError {
         bind_addr: bind_addr.clone(),
         err,
     })?;
 
+    let mut connect_addr = listener.local_addr()?;
+    if connect_addr.ip().is_unspecified() {
+        connect_addr.set_ip(if connect_addr.is_ipv4() {
+            Ipv4Addr::LOCALHOST.into()
+        } else {
+            Ipv6Addr::LOCALHOST.into()
+        });
+    }
+
+    let (tx, rx) = re_smart_channel::smart_channel(
+        // NOTE: We don't know until we start actually accepting clients!
+        re_smart_channel::SmartMessageSource::Unknown,
+        re_smart_channel::SmartChannelSource::TcpServer {
+            port: connect_addr.port(),
+        },
+    );
+
     std::thread::Builder::new()
         .name("rerun_sdk_comms: listener".to_owned())
         .spawn(move || {
@@ -79,15 +108,15 @@ pub fn serve(
 
     if options.quiet {
         re_log::debug!(
-            "Hosting a SDK server over TCP at {bind_addr}. Connect with the Rerun logging SDK."
+            "Hosting a SDK server over TCP at {connect_addr}. Connect with the Rerun logging SDK."
         );
     } else {
         re_log::info!(
-            "Hosting a SDK server over TCP at {bind_addr}. Connect with the Rerun logging SDK."
+            "Hosting a SDK server over TCP at {connect_addr}. Connect with the Rerun logging SDK."
         );
     }
 
-    Ok(rx)
+    Ok((rx, connect_addr))
 }
 
 fn listen_for_new_clients(listener: &TcpListener, options: ServerOptions, tx: &Sender<LogMsg>) {
@@ -410,3 +439,21 @@ impl Throttling {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serve_with_addr_resolves_port() {
+        let options = ServerOptions {
+            quiet: true,
+            ..Default::default()
+        };
+        let (_rx, connect_addr) = serve_with_addr("0.0.0.0", 0, options).unwrap();
+
+        assert_ne!(connect_addr.port(), 0);
+        assert!(connect_addr.ip().is_loopback());
+        TcpStream::connect(connect_addr).unwrap();
+    }
+}
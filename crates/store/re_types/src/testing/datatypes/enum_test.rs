@@ -20,8 +20,11 @@ use ::re_types_core::{ComponentDescriptor, ComponentName};
 use ::re_types_core::{DeserializationError, DeserializationResult};
 
 /// **Datatype**: A test of the enum type.
+///
+/// This enum is `#[non_exhaustive]`: new variants may be added in future versions, so make sure to always include a catch-all arm when matching on it.
 #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, Default)]
 #[repr(u8)]
+#[non_exhaustive]
 pub enum EnumTest {
     /// Great film.
     Up = 1,
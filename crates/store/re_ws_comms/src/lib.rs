@@ -17,7 +17,7 @@ pub use client::viewer_to_server;
 #[cfg(feature = "server")]
 mod server;
 #[cfg(feature = "server")]
-pub use server::RerunServer;
+pub use server::{DroppedMessages, RerunServer};
 
 use re_log_types::LogMsg;
 
@@ -7,30 +7,57 @@
 //! for specific data based on e.g. time.
 
 use std::{
-    collections::VecDeque,
+    collections::{BTreeMap, VecDeque},
     net::{TcpListener, TcpStream},
     sync::{
         atomic::{AtomicBool, AtomicU64, Ordering},
         Arc,
     },
+    time::{Duration, Instant},
 };
 
 use parking_lot::Mutex;
 use polling::{Event, Poller};
 use tungstenite::WebSocket;
 
-use re_log_types::LogMsg;
+use re_log_types::{LogMsg, StoreId};
 use re_memory::MemoryLimit;
 use re_smart_channel::ReceiveSet;
 
 use crate::{server_url, RerunServerError, RerunServerPort};
 
+/// How often we log a summary of the messages dropped because of the server memory limit.
+const DROPPED_MESSAGES_SUMMARY_INTERVAL: Duration = Duration::from_secs(60);
+
+/// How much data the server dropped from a store because of its memory limit.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DroppedMessages {
+    pub num_messages: u64,
+    pub num_bytes: u64,
+}
+
+impl DroppedMessages {
+    fn add(&mut self, num_bytes: u64) {
+        self.num_messages += 1;
+        self.num_bytes += num_bytes;
+    }
+}
+
 struct MessageQueue {
     server_memory_limit: MemoryLimit,
-    messages: VecDeque<Vec<u8>>,
+    messages: VecDeque<(StoreId, Vec<u8>)>,
 
     /// Never garbage collected.
     messages_static: VecDeque<Vec<u8>>,
+
+    /// Everything dropped so far, never reset.
+    ///
+    /// Shared with the [`RerunServer`], so it can be queried while the server is running.
+    dropped_per_store: Arc<Mutex<BTreeMap<StoreId, DroppedMessages>>>,
+
+    /// What was dropped since the last summary was logged.
+    dropped_since_summary: BTreeMap<StoreId, DroppedMessages>,
+    last_summary_time: Instant,
 }
 
 impl MessageQueue {
@@ -39,12 +66,15 @@ impl MessageQueue {
             server_memory_limit,
             messages: Default::default(),
             messages_static: Default::default(),
+            dropped_per_store: Default::default(),
+            dropped_since_summary: Default::default(),
+            last_summary_time: Instant::now(),
         }
     }
 
-    pub fn push(&mut self, msg: Vec<u8>) {
+    pub fn push(&mut self, store_id: StoreId, msg: Vec<u8>) {
         self.gc_if_using_too_much_ram();
-        self.messages.push_back(msg);
+        self.messages.push_back((store_id, msg));
     }
 
     /// Messages pushed using this method will stay around indefinitely.
@@ -61,7 +91,11 @@ impl MessageQueue {
 
         if let Some(max_bytes) = self.server_memory_limit.max_bytes {
             let max_bytes = max_bytes as u64;
-            let bytes_used = self.messages.iter().map(|m| m.len() as u64).sum::<u64>();
+            let bytes_used = self
+                .messages
+                .iter()
+                .map(|(_, msg)| msg.len() as u64)
+                .sum::<u64>();
 
             if max_bytes < bytes_used {
                 re_tracing::profile_scope!("Drop messages");
@@ -75,12 +109,22 @@ impl MessageQueue {
                 let mut bytes_dropped = 0;
                 let mut messages_dropped = 0;
 
-                while bytes_dropped < bytes_to_free {
-                    if let Some(msg) = self.messages.pop_front() {
-                        bytes_dropped += msg.len() as u64;
-                        messages_dropped += 1;
-                    } else {
-                        break;
+                {
+                    let mut dropped_per_store = self.dropped_per_store.lock();
+                    while bytes_dropped < bytes_to_free {
+                        if let Some((store_id, msg)) = self.messages.pop_front() {
+                            let msg_len = msg.len() as u64;
+                            bytes_dropped += msg_len;
+                            messages_dropped += 1;
+
+                            self.dropped_since_summary
+                                .entry(store_id.clone())
+                                .or_default()
+                                .add(msg_len);
+                            dropped_per_store.entry(store_id).or_default().add(msg_len);
+                        } else {
+                            break;
+                        }
                     }
                 }
 
@@ -88,9 +132,31 @@ impl MessageQueue {
                     "Dropped {} bytes in {messages_dropped} message(s)",
                     re_format::format_bytes(bytes_dropped as _)
                 );
+
+                self.log_dropped_messages_summary_if_due();
             }
         }
     }
+
+    /// Logs which stores lost how much data, at most once per [`DROPPED_MESSAGES_SUMMARY_INTERVAL`].
+    ///
+    /// This is logged at debug level, i.e. opt-in.
+    fn log_dropped_messages_summary_if_due(&mut self) {
+        let elapsed = self.last_summary_time.elapsed();
+        if elapsed < DROPPED_MESSAGES_SUMMARY_INTERVAL {
+            return;
+        }
+
+        for (store_id, dropped) in std::mem::take(&mut self.dropped_since_summary) {
+            re_log::debug!(
+                "Memory limit: dropped {} in {} message(s) from {store_id:?} over the last {:.0}s",
+                re_format::format_bytes(dropped.num_bytes as _),
+                re_format::format_uint(dropped.num_messages),
+                elapsed.as_secs_f32()
+            );
+        }
+        self.last_summary_time = Instant::now();
+    }
 }
 
 /// Websocket host for relaying [`LogMsg`]s to a web viewer.
@@ -105,6 +171,9 @@ pub struct RerunServer {
 
     /// Total count; never decreasing.
     num_accepted_clients: Arc<AtomicU64>,
+
+    /// See [`Self::dropped_messages_per_store`].
+    dropped_per_store: Arc<Mutex<BTreeMap<StoreId, DroppedMessages>>>,
 }
 
 impl RerunServer {
@@ -146,13 +215,21 @@ impl RerunServer {
         let shutdown_flag_copy = shutdown_flag.clone();
         let num_clients_copy = num_accepted_clients.clone();
 
+        let message_broadcaster = ReceiveSetBroadcaster::new(rerun_rx, server_memory_limit);
+        let dropped_per_store = message_broadcaster
+            .inner
+            .lock()
+            .history
+            .dropped_per_store
+            .clone();
+
         let listener_join_handle = std::thread::Builder::new()
             .name("rerun_ws_server: listener".to_owned())
             .spawn(move || {
                 Self::listen_thread_func(
                     &poller,
                     &listener_socket,
-                    &ReceiveSetBroadcaster::new(rerun_rx, server_memory_limit),
+                    &message_broadcaster,
                     &shutdown_flag,
                     &num_accepted_clients,
                 );
@@ -164,6 +241,7 @@ impl RerunServer {
             listener_join_handle: Some(listener_join_handle),
             shutdown_flag: shutdown_flag_copy,
             num_accepted_clients: num_clients_copy,
+            dropped_per_store,
         };
 
         re_log::info!(
@@ -184,6 +262,13 @@ impl RerunServer {
         self.num_accepted_clients.load(Ordering::Relaxed)
     }
 
+    /// How much data was dropped so far from each store because of the server memory limit.
+    ///
+    /// Clients connecting now won't see any of it.
+    pub fn dropped_messages_per_store(&self) -> BTreeMap<StoreId, DroppedMessages> {
+        self.dropped_per_store.lock().clone()
+    }
+
     /// Blocks execution as long as the server is running.
     ///
     /// There's no way of shutting the server down from the outside right now.
@@ -380,7 +465,7 @@ impl ReceiveSetBroadcaster {
 
                     let msg_is_data = matches!(data, LogMsg::ArrowMsg(_, _));
                     if msg_is_data {
-                        inner.history.push(msg);
+                        inner.history.push(data.store_id().clone(), msg);
                     } else {
                         // Keep non-data commands around for clients late to the party.
                         inner.history.push_static(msg);
@@ -421,7 +506,7 @@ impl ReceiveSetBroadcaster {
             }
         }
 
-        for msg in &inner.history.messages {
+        for (_, msg) in &inner.history.messages {
             if let Err(err) = client.send(tungstenite::Message::Binary(msg.clone())) {
                 re_log::warn!("Error sending message to web socket client: {err}");
                 return;
@@ -442,3 +527,53 @@ impl Drop for ReceiveSetBroadcaster {
         self.inner.lock().clients.clear();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use re_log_types::{StoreId, StoreKind};
+    use re_memory::MemoryLimit;
+
+    use super::{DroppedMessages, MessageQueue};
+
+    #[test]
+    fn test_dropped_messages_per_store() {
+        let store_a = StoreId::random(StoreKind::Recording);
+        let store_b = StoreId::random(StoreKind::Recording);
+
+        let mut queue = MessageQueue::new(MemoryLimit::from_bytes(250));
+
+        // Static messages are never dropped, and don't count towards the limit.
+        queue.push_static(vec![0; 1_000]);
+
+        // Fill up exactly to the limit: nothing dropped yet.
+        queue.push(store_a.clone(), vec![0; 100]);
+        queue.push(store_b.clone(), vec![0; 50]);
+        queue.push(store_a.clone(), vec![0; 100]);
+        assert!(queue.dropped_per_store.lock().is_empty());
+
+        // Once over the limit, the oldest message (from `store_a`) is dropped on the next push.
+        queue.push(store_b.clone(), vec![0; 50]);
+        queue.push(store_b.clone(), vec![0; 50]);
+
+        // Same thing again, now dropping the next oldest one (from `store_b`).
+        queue.push(store_b.clone(), vec![0; 50]);
+        queue.push(store_a.clone(), vec![0; 10]);
+
+        let dropped_per_store = queue.dropped_per_store.lock().clone();
+        assert_eq!(
+            dropped_per_store[&store_a],
+            DroppedMessages {
+                num_messages: 1,
+                num_bytes: 100,
+            }
+        );
+        assert_eq!(
+            dropped_per_store[&store_b],
+            DroppedMessages {
+                num_messages: 1,
+                num_bytes: 50,
+            }
+        );
+        assert_eq!(queue.messages_static.len(), 1);
+    }
+}
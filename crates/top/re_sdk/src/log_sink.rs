@@ -349,6 +349,20 @@ impl TcpSink {
             client: re_sdk_comms::Client::new(addr, flush_timeout),
         }
     }
+
+    /// Connect to the given address in a background thread, using the given options.
+    /// Retries until successful.
+    ///
+    /// See [`re_sdk_comms::ClientOptions`] for details.
+    #[inline]
+    pub fn new_with_options(
+        addr: std::net::SocketAddr,
+        options: re_sdk_comms::ClientOptions,
+    ) -> Self {
+        Self {
+            client: re_sdk_comms::Client::new_with_options(addr, options),
+        }
+    }
 }
 
 impl LogSink for TcpSink {
@@ -918,7 +918,8 @@ impl RecordingStream {
         batcher_config: ChunkBatcherConfig,
         sink: Box<dyn LogSink>,
     ) -> RecordingStreamResult<Self> {
-        let sink = (info.store_id.kind == StoreKind::Recording)
+        let sink = info
+            .is_recording()
             .then(forced_sink_path)
             .flatten()
             .map_or(sink, |path| {
@@ -1761,6 +1762,31 @@ impl RecordingStream {
         self.set_sink(Box::new(sink));
     }
 
+    /// Swaps the underlying sink for a [`crate::log_sink::TcpSink`] sink pre-configured to use
+    /// the specified address and [`re_sdk_comms::ClientOptions`].
+    ///
+    /// Use this to put an upper bound on how much memory the TCP client uses for data that hasn't
+    /// been sent yet, see [`re_sdk_comms::ClientOptions::max_memory_bytes`].
+    /// This does not bound the queues of the recording stream itself.
+    ///
+    /// This is a convenience wrapper for [`Self::set_sink`] that upholds the same guarantees in
+    /// terms of data durability and ordering.
+    /// See [`Self::set_sink`] for more information.
+    pub fn connect_with_options(
+        &self,
+        addr: std::net::SocketAddr,
+        options: re_sdk_comms::ClientOptions,
+    ) {
+        if forced_sink_path().is_some() {
+            re_log::debug!("Ignored setting new TcpSink since {ENV_FORCE_SAVE} is set");
+            return;
+        }
+
+        let sink = crate::log_sink::TcpSink::new_with_options(addr, options);
+
+        self.set_sink(Box::new(sink));
+    }
+
     /// Spawns a new Rerun Viewer process from an executable available in PATH, then swaps the
     /// underlying sink for a [`crate::log_sink::TcpSink`] sink pre-configured to send data to that
     /// new process.
@@ -991,7 +991,10 @@ fn parse_size(size: &str) -> anyhow::Result<[f32; 2]> {
 fn parse_max_latency(max_latency: Option<&String>) -> f32 {
     max_latency.as_ref().map_or(f32::INFINITY, |time| {
         re_format::parse_duration(time)
-            .unwrap_or_else(|err| panic!("Failed to parse max_latency ({max_latency:?}): {err}"))
+            .unwrap_or_else(|| {
+                panic!("Failed to parse max_latency ({max_latency:?}), expected e.g. `100ms`")
+            })
+            .as_secs_f32()
     })
 }
 
@@ -1,6 +1,6 @@
 use once_cell::sync::Lazy;
 use parking_lot::RwLock;
-use re_sdk::ComponentDescriptor;
+use re_sdk::{external::nohash_hasher::IntMap, ComponentDescriptor};
 
 use crate::{CComponentTypeHandle, CError, CErrorCode};
 
@@ -13,6 +13,9 @@ pub struct ComponentType {
 pub struct ComponentTypeRegistry {
     next_id: CComponentTypeHandle,
     types: Vec<ComponentType>,
+
+    /// Reverse lookup from descriptor to handle.
+    handles: IntMap<ComponentDescriptor, CComponentTypeHandle>,
 }
 
 impl ComponentTypeRegistry {
@@ -21,18 +24,15 @@ impl ComponentTypeRegistry {
         descriptor: ComponentDescriptor,
         datatype: arrow2::datatypes::DataType,
     ) -> CComponentTypeHandle {
-        #[cfg(debug_assertions)]
-        {
-            for ty in &self.types {
-                assert_ne!(
-                    ty.descriptor, descriptor,
-                    "Component type with the same descriptor already registered"
-                );
-            }
-        }
+        debug_assert!(
+            !self.handles.contains_key(&descriptor),
+            "Component type with the same descriptor already registered"
+        );
 
         let id = self.next_id;
         self.next_id += 1;
+        // Duplicates are only possible in release builds; lookups then return the first handle.
+        self.handles.entry(descriptor.clone()).or_insert(id);
         self.types.push(ComponentType {
             descriptor,
             datatype,
@@ -40,6 +40,11 @@ impl ComponentTypeRegistry {
         id
     }
 
+    /// The handle of the component type registered with this descriptor, if any.
+    pub fn lookup(&self, descriptor: &ComponentDescriptor) -> Option<CComponentTypeHandle> {
+        self.handles.get(descriptor).copied()
+    }
+
     #[allow(clippy::result_large_err)]
     pub fn get(&self, id: CComponentTypeHandle) -> Result<&ComponentType, CError> {
         self.types.get(id as usize).ok_or_else(|| {
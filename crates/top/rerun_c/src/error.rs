@@ -126,4 +126,31 @@ mod tests {
             Ok(&description[..num_expected_bytes])
         );
     }
+
+    #[test]
+    fn write_error_truncates_mixed_width_message_on_char_boundary() {
+        #![allow(clippy::ref_as_ptr)]
+        #![allow(unsafe_code)]
+
+        // The single-byte prefix means the 3 byte characters don't line up with the buffer size.
+        let description = format!(
+            "Failed to save to a/{}",
+            "日本語".repeat(CError::MAX_MESSAGE_SIZE_BYTES)
+        );
+        let error = CError::new(CErrorCode::Ok, &description);
+
+        #[allow(trivial_numeric_casts)]
+        let last_byte = error.message[CError::MAX_MESSAGE_SIZE_BYTES - 1] as u8;
+        assert_eq!(last_byte, 0, "the message must always be NUL-terminated");
+
+        let message = unsafe { CStr::from_ptr(&error.message as *const c_char) }
+            .to_str()
+            .expect("truncation must not split a UTF-8 character");
+        assert!(description.starts_with(message));
+        assert!(message.len() < CError::MAX_MESSAGE_SIZE_BYTES);
+        assert!(
+            message.len() + "日".len() >= CError::MAX_MESSAGE_SIZE_BYTES,
+            "only the characters that don't fit should be dropped"
+        );
+    }
 }
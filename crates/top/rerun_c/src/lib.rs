@@ -21,9 +21,9 @@ use arrow_utils::arrow_array_from_c_ffi;
 use re_sdk::{
     external::nohash_hasher::IntMap,
     log::{Chunk, ChunkId, PendingRow, TimeColumn},
-    time::TimeType,
-    ComponentDescriptor, EntityPath, RecordingStream, RecordingStreamBuilder, StoreKind, TimePoint,
-    Timeline,
+    time::{TimeInt, TimeType},
+    ComponentDescriptor, EntityPath, RecordingStream, RecordingStreamBuilder, StoreId, StoreKind,
+    TimePoint, Timeline,
 };
 use recording_streams::{recording_stream, RECORDING_STREAMS};
 
@@ -127,6 +127,35 @@ impl CSpawnOptions {
     }
 }
 
+/// C version of [`re_sdk::external::re_sdk_comms::ClientOptions`].
+///
+/// See `rr_connect_options` in the C header.
+#[derive(Debug, Clone)]
+#[repr(C)]
+pub struct CConnectOptions {
+    pub flush_timeout_sec: f32,
+    pub max_memory_bytes: u64,
+    pub blocking_when_full: bool,
+}
+
+impl CConnectOptions {
+    pub fn as_rust(&self) -> re_sdk::external::re_sdk_comms::ClientOptions {
+        re_sdk::external::re_sdk_comms::ClientOptions {
+            flush_timeout: flush_timeout_from_c(self.flush_timeout_sec),
+            max_memory_bytes: (self.max_memory_bytes != 0).then_some(self.max_memory_bytes),
+            blocking_when_full: self.blocking_when_full,
+        }
+    }
+}
+
+fn flush_timeout_from_c(flush_timeout_sec: f32) -> Option<std::time::Duration> {
+    if flush_timeout_sec >= 0.0 {
+        Some(std::time::Duration::from_secs_f32(flush_timeout_sec))
+    } else {
+        None
+    }
+}
+
 #[repr(u32)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum CStoreKind {
@@ -159,6 +188,14 @@ pub struct CStoreInfo {
     pub recording_id: CStringView,
 
     pub store_kind: CStoreKind,
+
+    /// The fully-qualified id of the store being logged to.
+    ///
+    /// Takes precedence over [`Self::recording_id`] if set.
+    /// Unspecified (null) by default.
+    ///
+    /// Last, so that the layout of the fields before it stays the same.
+    pub store_id: CStringView,
 }
 
 /// See `rr_component_descriptor` in the C header.
@@ -255,6 +292,18 @@ impl TryFrom<CTimeline> for Timeline {
     }
 }
 
+/// See `rr_time_point_entry` in the C header.
+///
+/// A single time value on a given timeline.
+#[repr(C)]
+#[derive(Debug, Clone)]
+pub struct CTimePointEntry {
+    pub timeline: CTimeline,
+
+    /// The time value, interpreted according to the timeline's type.
+    pub time: i64,
+}
+
 /// See `rr_time_column` in the C header.
 /// Equivalent to Rust [`re_sdk::log::TimeColumn`].
 #[repr(C)]
@@ -288,6 +337,7 @@ pub enum CErrorCode {
     RecordingStreamStdoutFailure,
     RecordingStreamSpawnFailure,
     RecordingStreamChunkValidationFailure,
+    RecordingStreamMemorySinkFailure,
 
     _CategoryArrow = 0x0000_1000,
     ArrowFfiSchemaImportError,
@@ -345,33 +395,40 @@ pub extern "C" fn rr_spawn(spawn_opts: *const CSpawnOptions, error: *mut CError)
 }
 
 #[allow(clippy::result_large_err)]
-#[allow(unsafe_code)]
-fn rr_register_component_type_impl(
-    component_type: &CComponentType,
-) -> Result<CComponentTypeHandle, CError> {
+fn component_descriptor_from_c(
+    descriptor: &CComponentDescriptor,
+) -> Result<ComponentDescriptor, CError> {
     let CComponentDescriptor {
         archetype_name,
         archetype_field_name,
         component_name,
-    } = &component_type.descriptor;
+    } = descriptor;
 
     let archetype_name = if !archetype_name.is_null() {
-        Some(archetype_name.as_str("component_type.descriptor.archetype_name")?)
+        Some(archetype_name.as_str("descriptor.archetype_name")?)
     } else {
         None
     };
     let archetype_field_name = if !archetype_field_name.is_null() {
-        Some(archetype_field_name.as_str("component_type.descriptor.archetype_field_name")?)
+        Some(archetype_field_name.as_str("descriptor.archetype_field_name")?)
     } else {
         None
     };
-    let component_name = component_name.as_str("component_type.descriptor.component_name")?;
+    let component_name = component_name.as_str("descriptor.component_name")?;
 
-    let component_descr = ComponentDescriptor {
+    Ok(ComponentDescriptor {
         archetype_name: archetype_name.map(Into::into),
         archetype_field_name: archetype_field_name.map(Into::into),
         component_name: component_name.into(),
-    };
+    })
+}
+
+#[allow(clippy::result_large_err)]
+#[allow(unsafe_code)]
+fn rr_register_component_type_impl(
+    component_type: &CComponentType,
+) -> Result<CComponentTypeHandle, CError> {
+    let component_descr = component_descriptor_from_c(&component_type.descriptor)?;
 
     let schema =
         unsafe { arrow2::ffi::import_field_from_c(&component_type.schema) }.map_err(|err| {
@@ -402,6 +459,32 @@ pub extern "C" fn rr_register_component_type(
     }
 }
 
+#[allow(clippy::result_large_err)]
+fn rr_lookup_component_type_impl(
+    descriptor: &CComponentDescriptor,
+) -> Result<CComponentTypeHandle, CError> {
+    let descriptor = component_descriptor_from_c(descriptor)?;
+    Ok(COMPONENT_TYPES
+        .read()
+        .lookup(&descriptor)
+        .unwrap_or(RR_COMPONENT_TYPE_HANDLE_INVALID))
+}
+
+#[allow(unsafe_code)]
+#[no_mangle]
+pub extern "C" fn rr_lookup_component_type(
+    descriptor: CComponentDescriptor,
+    error: *mut CError,
+) -> u32 {
+    match rr_lookup_component_type_impl(&descriptor) {
+        Ok(id) => id,
+        Err(err) => {
+            err.write_error(error);
+            RR_COMPONENT_TYPE_HANDLE_INVALID
+        }
+    }
+}
+
 #[allow(clippy::result_large_err)]
 fn rr_recording_stream_new_impl(
     store_info: *const CStoreInfo,
@@ -414,6 +497,7 @@ fn rr_recording_stream_new_impl(
     let CStoreInfo {
         application_id,
         recording_id,
+        store_id,
         store_kind,
     } = *store_info;
 
@@ -421,7 +505,6 @@ fn rr_recording_stream_new_impl(
 
     let mut rec_builder = RecordingStreamBuilder::new(application_id)
         //.is_official_example(is_official_example) // TODO(andreas): Is there a meaningful way to expose this?
-        //.store_id(recording_id.clone()) // TODO(andreas): Expose store id.
         .store_source(re_sdk::external::re_log_types::StoreSource::CSdk)
         .default_enabled(default_enabled);
 
@@ -435,6 +518,18 @@ fn rr_recording_stream_new_impl(
         rec_builder = rec_builder.blueprint();
     }
 
+    if !store_id.is_null() {
+        let store_id = store_id.as_str("store_info.store_id")?;
+        if store_id.trim().is_empty() {
+            return Err(CError::new(
+                CErrorCode::InvalidStringArgument,
+                "Argument \"store_info.store_id\" must not be empty if specified.",
+            ));
+        }
+        rec_builder =
+            rec_builder.store_id(StoreId::from_string(store_kind.into(), store_id.to_owned()));
+    }
+
     let rec = rec_builder.buffered().map_err(|err| {
         CError::new(
             CErrorCode::RecordingStreamCreationFailure,
@@ -509,6 +604,12 @@ pub extern "C" fn rr_recording_stream_free(id: CRecordingStream) {
     }
 }
 
+#[allow(unsafe_code)]
+#[no_mangle]
+pub extern "C" fn rr_recording_stream_count() -> u32 {
+    RECORDING_STREAMS.lock().num_streams() as u32
+}
+
 #[allow(unsafe_code)]
 #[no_mangle]
 pub extern "C" fn rr_recording_stream_set_global(id: CRecordingStream, store_kind: CStoreKind) {
@@ -546,14 +647,107 @@ fn rr_recording_stream_is_enabled_impl(id: CRecordingStream) -> Result<bool, CEr
     Ok(recording_stream(id)?.is_enabled())
 }
 
+#[allow(clippy::result_large_err)]
+fn rr_recording_stream_recording_id_impl(stream: CRecordingStream) -> Result<CString, CError> {
+    let store_info = recording_stream(stream)?.store_info().ok_or_else(|| {
+        CError::new(
+            CErrorCode::RecordingStreamRuntimeFailure,
+            "Recording stream is disabled and has no recording id.",
+        )
+    })?;
+
+    CString::new(store_info.store_id.as_str()).map_err(|err| {
+        CError::new(
+            CErrorCode::RecordingStreamRuntimeFailure,
+            &format!("Recording id can't be represented as a C string: {err}"),
+        )
+    })
+}
+
+#[allow(unsafe_code)]
+#[no_mangle]
+pub extern "C" fn rr_recording_stream_recording_id(
+    stream: CRecordingStream,
+    error: *mut CError,
+) -> *const c_char {
+    match rr_recording_stream_recording_id_impl(stream) {
+        Ok(recording_id) => recording_id.into_raw(),
+        Err(err) => {
+            err.write_error(error);
+            std::ptr::null()
+        }
+    }
+}
+
 #[allow(unsafe_code)]
 #[no_mangle]
 pub extern "C" fn rr_recording_stream_flush_blocking(id: CRecordingStream) {
-    if let Some(stream) = RECORDING_STREAMS.lock().remove(id) {
+    // Don't hold the lock while flushing, other threads may want to access other streams meanwhile.
+    let stream = RECORDING_STREAMS.lock().get(id);
+    if let Some(stream) = stream {
         stream.flush_blocking();
     }
 }
 
+/// Signature of the callback passed to [`rr_recording_stream_flush_async`].
+pub type CFlushCallback = extern "C" fn(user_data: *mut std::ffi::c_void);
+
+/// User data pointer that is handed back to the C side on the flush thread.
+struct FlushUserData(*mut std::ffi::c_void);
+
+// SAFETY: We never dereference the pointer ourselves, we only pass it back to the user's callback.
+// It's the caller's responsibility to make sure that the pointee can be accessed from another thread.
+#[allow(unsafe_code)]
+unsafe impl Send for FlushUserData {}
+
+#[allow(clippy::result_large_err)]
+fn rr_recording_stream_flush_async_impl(
+    id: CRecordingStream,
+    callback: Option<CFlushCallback>,
+    user_data: *mut std::ffi::c_void,
+) -> Result<(), CError> {
+    let Some(callback) = callback else {
+        return Err(CError::unexpected_null("callback"));
+    };
+
+    // The flush thread owns its own reference to the stream, so freeing the handle
+    // while the flush is in flight is fine.
+    // Since the stream clone is dropped on the flush thread, this is also unaffected by the
+    // thread-local shutdown issues described in `THREAD_LIFE_TRACKER`.
+    let stream = recording_stream(id)?;
+    let user_data = FlushUserData(user_data);
+
+    std::thread::Builder::new()
+        .name("rr_flush_async".to_owned())
+        .spawn(move || {
+            let user_data = user_data;
+            stream.flush_blocking();
+            drop(stream);
+            callback(user_data.0);
+        })
+        .map_err(|err| {
+            CError::new(
+                CErrorCode::RecordingStreamRuntimeFailure,
+                &format!("Failed to spawn flush thread: {err}"),
+            )
+        })?;
+
+    Ok(())
+}
+
+#[allow(unsafe_code)]
+#[no_mangle]
+pub extern "C" fn rr_recording_stream_flush_async(
+    id: CRecordingStream,
+    callback: Option<CFlushCallback>,
+    user_data: *mut std::ffi::c_void,
+    error: *mut CError,
+) {
+    if let Err(err) = rr_recording_stream_flush_async_impl(id, callback, user_data) {
+        err.write_error(error);
+    }
+}
+
 #[allow(clippy::result_large_err)]
 fn rr_recording_stream_connect_impl(
     stream: CRecordingStream,
@@ -570,12 +764,7 @@ fn rr_recording_stream_connect_impl(
         )
     })?;
 
-    let flush_timeout = if flush_timeout_sec >= 0.0 {
-        Some(std::time::Duration::from_secs_f32(flush_timeout_sec))
-    } else {
-        None
-    };
-    stream.connect_opts(tcp_addr, flush_timeout);
+    stream.connect_opts(tcp_addr, flush_timeout_from_c(flush_timeout_sec));
 
     Ok(())
 }
@@ -588,8 +777,49 @@ pub extern "C" fn rr_recording_stream_connect(
     flush_timeout_sec: f32,
     error: *mut CError,
 ) {
-    if let Err(err) = rr_recording_stream_connect_impl(id, tcp_addr, flush_timeout_sec) {
-        err.write_error(error);
+    match rr_recording_stream_connect_impl(id, tcp_addr, flush_timeout_sec) {
+        Ok(()) => RECORDING_STREAMS.lock().remove_memory_sink(id),
+        Err(err) => err.write_error(error),
+    }
+}
+
+#[allow(clippy::result_large_err)]
+fn rr_recording_stream_connect_with_options_impl(
+    stream: CRecordingStream,
+    tcp_addr: CStringView,
+    connect_opts: *const CConnectOptions,
+) -> Result<(), CError> {
+    let stream = recording_stream(stream)?;
+
+    let tcp_addr = tcp_addr.as_str("tcp_addr")?;
+    let tcp_addr = tcp_addr.parse().map_err(|err| {
+        CError::new(
+            CErrorCode::InvalidSocketAddress,
+            &format!("Failed to parse tcp address {tcp_addr:?}: {err}"),
+        )
+    })?;
+
+    let connect_opts = if connect_opts.is_null() {
+        re_sdk::external::re_sdk_comms::ClientOptions::default()
+    } else {
+        ptr::try_ptr_as_ref(connect_opts, "connect_opts")?.as_rust()
+    };
+    stream.connect_with_options(tcp_addr, connect_opts);
+
+    Ok(())
+}
+
+#[allow(unsafe_code)]
+#[no_mangle]
+pub extern "C" fn rr_recording_stream_connect_with_options(
+    id: CRecordingStream,
+    tcp_addr: CStringView,
+    connect_opts: *const CConnectOptions,
+    error: *mut CError,
+) {
+    match rr_recording_stream_connect_with_options_impl(id, tcp_addr, connect_opts) {
+        Ok(()) => RECORDING_STREAMS.lock().remove_memory_sink(id),
+        Err(err) => err.write_error(error),
     }
 }
 
@@ -607,14 +837,9 @@ fn rr_recording_stream_spawn_impl(
         let spawn_opts = ptr::try_ptr_as_ref(spawn_opts, "spawn_opts")?;
         spawn_opts.as_rust()?
     };
-    let flush_timeout = if flush_timeout_sec >= 0.0 {
-        Some(std::time::Duration::from_secs_f32(flush_timeout_sec))
-    } else {
-        None
-    };
 
     stream
-        .spawn_opts(&spawn_opts, flush_timeout)
+        .spawn_opts(&spawn_opts, flush_timeout_from_c(flush_timeout_sec))
         .map_err(|err| CError::new(CErrorCode::RecordingStreamSpawnFailure, &err.to_string()))?;
 
     Ok(())
@@ -628,8 +853,9 @@ pub extern "C" fn rr_recording_stream_spawn(
     flush_timeout_sec: f32,
     error: *mut CError,
 ) {
-    if let Err(err) = rr_recording_stream_spawn_impl(id, spawn_opts, flush_timeout_sec) {
-        err.write_error(error);
+    match rr_recording_stream_spawn_impl(id, spawn_opts, flush_timeout_sec) {
+        Ok(()) => RECORDING_STREAMS.lock().remove_memory_sink(id),
+        Err(err) => err.write_error(error),
     }
 }
 
@@ -654,8 +880,9 @@ pub extern "C" fn rr_recording_stream_save(
     path: CStringView,
     error: *mut CError,
 ) {
-    if let Err(err) = rr_recording_stream_save_impl(id, path) {
-        err.write_error(error);
+    match rr_recording_stream_save_impl(id, path) {
+        Ok(()) => RECORDING_STREAMS.lock().remove_memory_sink(id),
+        Err(err) => err.write_error(error),
     }
 }
 
@@ -672,11 +899,96 @@ fn rr_recording_stream_stdout_impl(stream: CRecordingStream) -> Result<(), CErro
 #[allow(unsafe_code)]
 #[no_mangle]
 pub extern "C" fn rr_recording_stream_stdout(id: CRecordingStream, error: *mut CError) {
-    if let Err(err) = rr_recording_stream_stdout_impl(id) {
+    match rr_recording_stream_stdout_impl(id) {
+        Ok(()) => RECORDING_STREAMS.lock().remove_memory_sink(id),
+        Err(err) => err.write_error(error),
+    }
+}
+
+#[allow(clippy::result_large_err)]
+fn rr_recording_stream_memory_sink_impl(stream: CRecordingStream) -> Result<(), CError> {
+    let storage = recording_stream(stream)?.memory();
+    RECORDING_STREAMS.lock().set_memory_sink(stream, storage);
+    Ok(())
+}
+
+#[allow(unsafe_code)]
+#[no_mangle]
+pub extern "C" fn rr_recording_stream_memory_sink(id: CRecordingStream, error: *mut CError) {
+    if let Err(err) = rr_recording_stream_memory_sink_impl(id) {
         err.write_error(error);
     }
 }
 
+#[allow(clippy::result_large_err)]
+fn rr_recording_stream_drain_memory_impl(
+    stream: CRecordingStream,
+    out_bytes: *mut CBytesView,
+) -> Result<(), CError> {
+    let out_bytes = ptr::try_ptr_as_mut(out_bytes, "out_bytes")?;
+
+    recording_stream(stream)?; // Check the handle first, to report the right error.
+    let storage = RECORDING_STREAMS
+        .lock()
+        .memory_sink(stream)
+        .ok_or_else(|| {
+            CError::new(
+            CErrorCode::RecordingStreamMemorySinkFailure,
+            "The recording stream has no memory sink, call rr_recording_stream_memory_sink first",
+        )
+        })?;
+
+    let bytes = storage.drain_as_bytes().map_err(|err| {
+        CError::new(
+            CErrorCode::RecordingStreamMemorySinkFailure,
+            &format!("Failed to encode the memory sink's data: {err}"),
+        )
+    })?;
+    let length = u32::try_from(bytes.len()).map_err(|_err| {
+        CError::new(
+            CErrorCode::RecordingStreamMemorySinkFailure,
+            &format!(
+                "Too much data in the memory sink to drain at once: {} bytes",
+                bytes.len()
+            ),
+        )
+    })?;
+
+    *out_bytes = CBytesView {
+        bytes: Box::into_raw(bytes.into_boxed_slice()).cast::<c_uchar>(),
+        length,
+    };
+    Ok(())
+}
+
+#[allow(unsafe_code)]
+#[no_mangle]
+pub extern "C" fn rr_recording_stream_drain_memory(
+    id: CRecordingStream,
+    out_bytes: *mut CBytesView,
+    error: *mut CError,
+) {
+    if let Err(err) = rr_recording_stream_drain_memory_impl(id, out_bytes) {
+        err.write_error(error);
+    }
+}
+
+#[allow(unsafe_code)]
+#[no_mangle]
+pub unsafe extern "C" fn rr_bytes_free(bytes: CBytesView) {
+    if bytes.is_null() {
+        return;
+    }
+
+    // SAFETY: `rr_bytes_free` should only be called on bytes returned by
+    // `rr_recording_stream_drain_memory`, which come from a boxed slice of that exact length.
+    unsafe {
+        let slice =
+            std::ptr::slice_from_raw_parts_mut(bytes.bytes.cast_mut(), bytes.length as usize);
+        drop(Box::from_raw(slice));
+    }
+}
+
 #[allow(clippy::result_large_err)]
 fn rr_recording_stream_set_time_sequence_impl(
     stream: CRecordingStream,
@@ -749,6 +1061,50 @@ pub extern "C" fn rr_recording_stream_set_time_nanos(
     }
 }
 
+#[allow(unsafe_code)]
+#[allow(clippy::result_large_err)]
+fn rr_recording_stream_set_time_point_impl(
+    stream: CRecordingStream,
+    timelines: *const CTimeline,
+    values: *const i64,
+    count: u32,
+) -> Result<(), CError> {
+    let stream = recording_stream(stream)?;
+
+    let (timelines, values) = if count == 0 {
+        (&[][..], &[][..])
+    } else {
+        (
+            ptr::try_ptr_as_slice(timelines, count, "timelines")?,
+            ptr::try_ptr_as_slice(values, count, "values")?,
+        )
+    };
+
+    // Validate everything before touching the stream, so that we never apply only part of it.
+    let mut timepoint = TimePoint::default();
+    for (timeline, value) in timelines.iter().zip(values) {
+        let timeline: Timeline = timeline.clone().try_into()?;
+        timepoint.insert(timeline, TimeInt::new_temporal(*value));
+    }
+
+    stream.set_timepoint(timepoint);
+    Ok(())
+}
+
+#[allow(unsafe_code)]
+#[no_mangle]
+pub extern "C" fn rr_recording_stream_set_time_point(
+    stream: CRecordingStream,
+    timelines: *const CTimeline,
+    values: *const i64,
+    count: u32,
+    error: *mut CError,
+) {
+    if let Err(err) = rr_recording_stream_set_time_point_impl(stream, timelines, values, count) {
+        err.write_error(error);
+    }
+}
+
 #[allow(unsafe_code)]
 #[allow(clippy::result_large_err)]
 fn rr_recording_stream_disable_timeline_impl(
@@ -786,6 +1142,7 @@ pub extern "C" fn rr_recording_stream_reset_time(stream: CRecordingStream) {
 fn rr_recording_stream_log_impl(
     stream: CRecordingStream,
     data_row: CDataRow,
+    timepoint: TimePoint,
     inject_time: bool,
 ) -> Result<(), CError> {
     // Create row-id as early as possible. It has a timestamp and is used to estimate e2e latency.
@@ -826,7 +1183,7 @@ fn rr_recording_stream_log_impl(
 
     let row = PendingRow {
         row_id,
-        timepoint: TimePoint::default(), // we use the one in the recording stream for now
+        timepoint,
         components,
     };
 
@@ -843,7 +1200,60 @@ pub unsafe extern "C" fn rr_recording_stream_log(
     inject_time: bool,
     error: *mut CError,
 ) {
-    if let Err(err) = rr_recording_stream_log_impl(stream, data_row, inject_time) {
+    if let Err(err) =
+        rr_recording_stream_log_impl(stream, data_row, TimePoint::default(), inject_time)
+    {
+        err.write_error(error);
+    }
+}
+
+#[allow(clippy::result_large_err)]
+fn time_point_from_c(entries: &[CTimePointEntry]) -> Result<TimePoint, CError> {
+    let mut timepoint = TimePoint::default();
+    for entry in entries {
+        let timeline: Timeline = entry.timeline.clone().try_into()?;
+        timepoint.insert(timeline, TimeInt::new_temporal(entry.time));
+    }
+    Ok(timepoint)
+}
+
+#[allow(unsafe_code)]
+#[allow(clippy::result_large_err)]
+fn rr_recording_stream_log_with_time_impl(
+    stream: CRecordingStream,
+    data_row: CDataRow,
+    time_point_entries: *const CTimePointEntry,
+    num_time_point_entries: u32,
+) -> Result<(), CError> {
+    let time_point_entries = if num_time_point_entries == 0 {
+        &[]
+    } else {
+        ptr::try_ptr_as_slice(
+            time_point_entries,
+            num_time_point_entries,
+            "time_point_entries",
+        )?
+    };
+    let timepoint = time_point_from_c(time_point_entries)?;
+
+    rr_recording_stream_log_impl(stream, data_row, timepoint, false)
+}
+
+#[allow(unsafe_code)]
+#[no_mangle]
+pub unsafe extern "C" fn rr_recording_stream_log_with_time(
+    stream: CRecordingStream,
+    data_row: CDataRow,
+    time_point_entries: *const CTimePointEntry,
+    num_time_point_entries: u32,
+    error: *mut CError,
+) {
+    if let Err(err) = rr_recording_stream_log_with_time_impl(
+        stream,
+        data_row,
+        time_point_entries,
+        num_time_point_entries,
+    ) {
         err.write_error(error);
     }
 }
@@ -1073,6 +1483,57 @@ pub unsafe extern "C" fn _rr_escape_entity_path_part(part: CStringView) -> *cons
     part.into_raw()
 }
 
+#[allow(unsafe_code)]
+#[no_mangle]
+pub unsafe extern "C" fn _rr_describe_rrd(bytes: CBytesView) -> *const c_char {
+    use re_sdk::external::re_log_encoding::{decoder::decode_bytes, VersionPolicy};
+    use re_sdk::external::re_log_types::LogMsg;
+
+    let Ok(bytes) = bytes.as_bytes("bytes") else {
+        return std::ptr::null();
+    };
+    let Ok(msgs) = decode_bytes(VersionPolicy::Error, bytes) else {
+        return std::ptr::null();
+    };
+
+    let mut description = String::new();
+    for msg in &msgs {
+        let LogMsg::ArrowMsg(_, arrow_msg) = msg else {
+            continue;
+        };
+        let Ok(chunk) = Chunk::from_arrow_msg(arrow_msg) else {
+            return std::ptr::null();
+        };
+
+        description.push_str(&chunk.entity_path().to_string());
+
+        let mut time_columns: Vec<_> = chunk.timelines().values().collect();
+        time_columns.sort_by_key(|time_column| time_column.name().to_owned());
+        for time_column in time_columns {
+            let times: Vec<_> = time_column
+                .times_raw()
+                .iter()
+                .map(|time| time.to_string())
+                .collect();
+            description.push_str(&format!(" {}=[{}]", time_column.name(), times.join(", ")));
+        }
+
+        let mut component_names: Vec<_> = chunk.component_names().collect();
+        component_names.sort();
+        for component_name in component_names {
+            description.push_str(&format!(" {component_name}"));
+        }
+
+        description.push('\n');
+    }
+
+    let Ok(description) = CString::new(description) else {
+        return std::ptr::null();
+    };
+
+    description.into_raw()
+}
+
 #[allow(unsafe_code)]
 #[no_mangle]
 pub unsafe extern "C" fn _rr_free_string(str: *mut c_char) {
@@ -1082,7 +1543,8 @@ pub unsafe extern "C" fn _rr_free_string(str: *mut c_char) {
 
     // Free the string:
     unsafe {
-        // SAFETY: `_rr_free_string` should only be called on strings allocated by `_rr_escape_entity_path_part`.
+        // SAFETY: `_rr_free_string` should only be called on strings allocated by `_rr_escape_entity_path_part`,
+        // `_rr_describe_rrd`, or `rr_recording_stream_recording_id`.
         let _ = CString::from_raw(str);
     }
 }
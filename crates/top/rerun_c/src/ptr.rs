@@ -15,6 +15,17 @@ pub fn try_ptr_as_ref<T>(ptr: *const T, argument_name: &str) -> Result<&T, CErro
     }
 }
 
+#[allow(unsafe_code)]
+#[allow(clippy::result_large_err)]
+pub fn try_ptr_as_mut<'a, T>(ptr: *mut T, argument_name: &str) -> Result<&'a mut T, CError> {
+    let ptr = unsafe { ptr.as_mut() };
+    if let Some(ptr) = ptr {
+        Ok(ptr)
+    } else {
+        Err(CError::unexpected_null(argument_name))
+    }
+}
+
 #[allow(unsafe_code)]
 #[allow(clippy::result_large_err)]
 pub fn try_ptr_as_slice<T>(
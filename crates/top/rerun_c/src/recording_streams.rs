@@ -1,6 +1,6 @@
 use once_cell::sync::Lazy;
 use parking_lot::Mutex;
-use re_sdk::{RecordingStream, StoreKind};
+use re_sdk::{sink::MemorySinkStorage, RecordingStream, StoreKind};
 
 use crate::{
     CError, CRecordingStream, RR_REC_STREAM_CURRENT_BLUEPRINT, RR_REC_STREAM_CURRENT_RECORDING,
@@ -8,18 +8,50 @@ use crate::{
 
 #[derive(Default)]
 pub struct RecStreams {
+    /// The handle to try next in [`Self::insert`].
+    ///
+    /// Wraps around before the `RR_REC_STREAM_CURRENT_*` handles, so that a host creating and
+    /// freeing many short-lived streams doesn't run out of handles.
     next_id: CRecordingStream,
+
     streams: ahash::HashMap<CRecordingStream, RecordingStream>,
+
+    /// Where the streams switched to a memory sink accumulate their data, until drained.
+    memory_sinks: ahash::HashMap<CRecordingStream, MemorySinkStorage>,
 }
 
 impl RecStreams {
+    /// Returns the handle of the newly inserted stream.
+    ///
+    /// Freed handles are only reused once all the others have been handed out, the longest freed
+    /// first. This way, using a handle after freeing it reports an invalid handle, rather than
+    /// silently logging to whichever stream was created right after.
     pub fn insert(&mut self, stream: RecordingStream) -> CRecordingStream {
-        let id = self.next_id;
-        self.next_id += 1;
+        assert!(
+            self.streams.len() < RR_REC_STREAM_CURRENT_BLUEPRINT as usize,
+            "Ran out of recording stream handles"
+        );
+
+        let id = loop {
+            let id = self.next_id;
+            self.next_id = if id + 1 < RR_REC_STREAM_CURRENT_BLUEPRINT {
+                id + 1
+            } else {
+                0
+            };
+            if !self.streams.contains_key(&id) {
+                break id;
+            }
+        };
         self.streams.insert(id, stream);
         id
     }
 
+    /// Number of live streams, not counting the `RR_REC_STREAM_CURRENT_*` ones.
+    pub fn num_streams(&self) -> usize {
+        self.streams.len()
+    }
+
     pub fn get(&self, id: CRecordingStream) -> Option<RecordingStream> {
         match id {
             RR_REC_STREAM_CURRENT_RECORDING => RecordingStream::get(StoreKind::Recording, None)
@@ -30,10 +62,31 @@ impl RecStreams {
         }
     }
 
+    /// Replaces the memory sink storage of the given stream, if any.
+    pub fn set_memory_sink(&mut self, id: CRecordingStream, storage: MemorySinkStorage) {
+        self.memory_sinks.insert(id, storage);
+    }
+
+    /// Forgets the memory sink storage of the given stream, if any.
+    ///
+    /// Must be called whenever its sink is replaced, so that draining it reports an error instead
+    /// of returning the data of a sink that is no longer used.
+    pub fn remove_memory_sink(&mut self, id: CRecordingStream) {
+        self.memory_sinks.remove(&id);
+    }
+
+    pub fn memory_sink(&self, id: CRecordingStream) -> Option<MemorySinkStorage> {
+        self.memory_sinks.get(&id).cloned()
+    }
+
     pub fn remove(&mut self, id: CRecordingStream) -> Option<RecordingStream> {
         match id {
             RR_REC_STREAM_CURRENT_BLUEPRINT | RR_REC_STREAM_CURRENT_RECORDING => None,
-            _ => self.streams.remove(&id),
+            _ => {
+                let stream = self.streams.remove(&id)?;
+                self.memory_sinks.remove(&id);
+                Some(stream)
+            }
         }
     }
 }
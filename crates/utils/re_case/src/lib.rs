@@ -1,5 +1,27 @@
 //! Case conversions, the way Rerun likes them.
 
+/// Acronyms that are kept as a single word: `(PascalCase, snake_case)`.
+const ACRONYMS: &[(&str, &str)] = &[("UVec", "uvec"), ("DVec", "dvec"), ("UInt", "uint")];
+
+/// Uppercases the `d` of dimension suffixes, e.g. `points2d_indicator` -> `points2D_indicator`.
+///
+/// A dimension suffix is a digit followed by a `d` that isn't the start of a lowercase word.
+fn uppercase_dimensions(s: &str) -> String {
+    let mut result = String::with_capacity(s.len());
+    let mut prev_is_digit = false;
+
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        let is_dimension = c == 'd'
+            && prev_is_digit
+            && !chars.peek().is_some_and(|next| next.is_ascii_lowercase());
+        result.push(if is_dimension { 'D' } else { c });
+        prev_is_digit = c.is_ascii_digit();
+    }
+
+    result
+}
+
 /// Converts a snake or pascal case input into a snake case output.
 ///
 /// If the input contains multiple parts separated by dots, only the last part is converted.
@@ -19,10 +41,9 @@ pub fn to_snake_case(s: &str) -> String {
 
     let mut parts: Vec<_> = s.split('.').map(ToOwned::to_owned).collect();
     if let Some(last) = parts.last_mut() {
-        *last = last
-            .replace("UVec", "uvec")
-            .replace("DVec", "dvec")
-            .replace("UInt", "uint");
+        for (pascal, snake) in ACRONYMS {
+            *last = last.replace(pascal, snake);
+        }
         *last = rerun_snake.convert(last.as_str());
     }
     parts.join(".")
@@ -88,6 +109,29 @@ fn test_to_snake_case() {
         to_snake_case("rerun.components.AnnotationContext"),
         "rerun.components.annotation_context"
     );
+
+    assert_eq!(
+        to_snake_case("rerun.datatypes.Vec5D"),
+        "rerun.datatypes.vec5d"
+    );
+    assert_eq!(
+        to_snake_case("rerun.datatypes.vec5d"),
+        "rerun.datatypes.vec5d"
+    );
+
+    assert_eq!(
+        to_snake_case("rerun.archetypes.Points5DIndicator"),
+        "rerun.archetypes.points5d_indicator"
+    );
+
+    assert_eq!(
+        to_snake_case("rerun.datatypes.Mat4x4"),
+        "rerun.datatypes.mat4x4"
+    );
+    assert_eq!(
+        to_snake_case("rerun.datatypes.mat4x4"),
+        "rerun.datatypes.mat4x4"
+    );
 }
 
 /// Converts a snake or pascal case input into a pascal case output.
@@ -109,13 +153,10 @@ pub fn to_pascal_case(s: &str) -> String {
 
     let mut parts: Vec<_> = s.split('.').map(ToOwned::to_owned).collect();
     if let Some(last) = parts.last_mut() {
-        *last = last
-            .replace("uvec", "UVec")
-            .replace("dvec", "DVec")
-            .replace("uint", "UInt")
-            .replace("2d", "2D") // NOLINT
-            .replace("3d", "3D") // NOLINT
-            .replace("4d", "4D");
+        for (pascal, snake) in ACRONYMS {
+            *last = last.replace(snake, pascal);
+        }
+        *last = uppercase_dimensions(last);
         *last = rerun_pascal.convert(last.as_str());
     }
     parts.join(".")
@@ -167,6 +208,35 @@ fn test_to_pascal_case() {
         to_pascal_case("rerun.components.TranslationAndMat3x3"),
         "rerun.components.TranslationAndMat3x3"
     );
+
+    assert_eq!(
+        to_pascal_case("rerun.datatypes.vec5d"),
+        "rerun.datatypes.Vec5D"
+    );
+    assert_eq!(
+        to_pascal_case("rerun.datatypes.Vec5D"),
+        "rerun.datatypes.Vec5D"
+    );
+
+    assert_eq!(
+        to_pascal_case("rerun.archetypes.points5d_indicator"),
+        "rerun.archetypes.Points5DIndicator"
+    );
+
+    assert_eq!(
+        to_pascal_case("rerun.datatypes.mat4x4"),
+        "rerun.datatypes.Mat4x4"
+    );
+    assert_eq!(
+        to_pascal_case("rerun.datatypes.Mat4x4"),
+        "rerun.datatypes.Mat4x4"
+    );
+
+    // Not a dimension:
+    assert_eq!(
+        to_pascal_case("rerun.components.draw_order2dummy"),
+        "rerun.components.DrawOrder2dummy"
+    );
 }
 
 /// Converts a snake or pascal case input into "human case" output, i.e. start with upper case and continue with lower case.
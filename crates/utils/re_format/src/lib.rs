@@ -82,16 +82,14 @@ impl UnsignedAbs for isize {
 ///
 /// The returned value is for human eyes only, and can not be parsed
 /// by the normal `usize::from_str` function.
+///
+/// See [`IntFormatOptions`] for aligning columns of signed numbers.
 pub fn format_int<Int>(number: Int) -> String
 where
     Int: Display + PartialOrd + num_traits::Zero + UnsignedAbs,
     Int::Unsigned: Display + num_traits::Unsigned,
 {
-    if number < Int::zero() {
-        format!("{MINUS}{}", format_uint(number.unsigned_abs()))
-    } else {
-        add_thousands_separators(&number.to_string())
-    }
+    IntFormatOptions::DEFAULT.format(number)
 }
 
 /// Pretty format an unsigned integer by using thousands separators for readability.
@@ -138,6 +136,148 @@ fn test_format_uint() {
     assert_eq!(format_uint(1_234_567_u32), "1 234 567");
 }
 
+/// Format a count of things, e.g. `"1 event"` or `"12 345 events"`.
+///
+/// The number uses thousands separators, just like [`format_uint`],
+/// and the plural is formed by appending an `s` to `singular`.
+pub fn format_count(count: u64, singular: &str) -> String {
+    let s = if count == 1 { "" } else { "s" };
+    format!("{} {singular}{s}", format_uint(count))
+}
+
+/// Format a signed count of things, e.g. `"−1 event"` or `"12 345 events"`.
+///
+/// See [`format_count`].
+pub fn format_count_signed(count: i64, singular: &str) -> String {
+    let s = if count.unsigned_abs() == 1 { "" } else { "s" };
+    format!("{} {singular}{s}", format_int(count))
+}
+
+#[test]
+fn test_format_count() {
+    assert_eq!(format_count(0, "event"), "0 events");
+    assert_eq!(format_count(1, "event"), "1 event");
+    assert_eq!(format_count(2, "event"), "2 events");
+    assert_eq!(
+        format_count(1_234_567, "event"),
+        "1\u{2009}234\u{2009}567 events"
+    );
+
+    assert_eq!(format_count_signed(0, "event"), "0 events");
+    assert_eq!(format_count_signed(1, "event"), "1 event");
+    assert_eq!(format_count_signed(-1, "event"), "−1 event");
+    assert_eq!(
+        format_count_signed(-12_345, "event"),
+        "−12\u{2009}345 events"
+    );
+}
+
+/// Options for how to format a signed integer, e.g. an [`i64`].
+///
+/// Useful for aligning columns of signed numbers in a table.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct IntFormatOptions {
+    /// Always show the sign, even if it is positive (`+`).
+    pub always_sign: bool,
+
+    /// Put a space in front of positive numbers, where the minus sign would go,
+    /// so that positive and negative numbers line up.
+    ///
+    /// Has no effect if [`Self::always_sign`] is set.
+    pub pad_sign: bool,
+}
+
+impl IntFormatOptions {
+    /// Only show the sign of negative numbers, just like [`format_int`].
+    pub const DEFAULT: Self = Self {
+        always_sign: false,
+        pad_sign: false,
+    };
+
+    /// Always show the sign, even if it is positive (`+`).
+    #[inline]
+    pub fn with_always_sign(mut self, always_sign: bool) -> Self {
+        self.always_sign = always_sign;
+        self
+    }
+
+    /// Put a space in front of positive numbers, so that they line up with negative numbers.
+    #[inline]
+    pub fn with_pad_sign(mut self, pad_sign: bool) -> Self {
+        self.pad_sign = pad_sign;
+        self
+    }
+
+    /// Pretty format a signed number by using thousands separators for readability.
+    ///
+    /// The returned value is for human eyes only, and can not be parsed
+    /// by the normal `i64::from_str` function.
+    pub fn format<Int>(&self, number: Int) -> String
+    where
+        Int: Display + PartialOrd + num_traits::Zero + UnsignedAbs,
+        Int::Unsigned: Display + num_traits::Unsigned,
+    {
+        let Self {
+            always_sign,
+            pad_sign,
+        } = *self;
+
+        if number < Int::zero() {
+            format!("{MINUS}{}", format_uint(number.unsigned_abs()))
+        } else {
+            let sign = if always_sign {
+                "+"
+            } else if pad_sign {
+                " "
+            } else {
+                ""
+            };
+            format!("{sign}{}", add_thousands_separators(&number.to_string()))
+        }
+    }
+}
+
+#[test]
+fn test_format_int() {
+    assert_eq!(format_int(0_i32), "0");
+    assert_eq!(format_int(1_234_i32), "1 234");
+    assert_eq!(format_int(-1_234_i32), "−1 234");
+    assert_eq!(format_int(i64::MIN), "−9 223 372 036 854 775 808");
+
+    let always_sign = IntFormatOptions::DEFAULT.with_always_sign(true);
+    assert_eq!(always_sign.format(0_i32), "+0");
+    assert_eq!(always_sign.format(1_234_i32), "+1 234");
+    assert_eq!(always_sign.format(-1_234_i32), "−1 234");
+
+    let pad_sign = IntFormatOptions::DEFAULT.with_pad_sign(true);
+    assert_eq!(pad_sign.format(0_i32), " 0");
+    assert_eq!(pad_sign.format(1_234_i32), " 1 234");
+    assert_eq!(pad_sign.format(-1_234_i32), "−1 234");
+
+    // Both options produce a column where the sign always takes up exactly one character:
+    for options in [always_sign, pad_sign] {
+        let formatted = [1_234_i32, -1_234].map(|number| options.format(number));
+        assert_eq!(
+            formatted[0].chars().count(),
+            formatted[1].chars().count(),
+            "{formatted:?}"
+        );
+    }
+}
+
+/// How to show the exponent of numbers too large to show in full, e.g. `1.23e4` vs `12.3e3`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ExponentStyle {
+    /// One digit before the decimal point, e.g. `1.23e4`.
+    #[default]
+    Scientific,
+
+    /// Exponents are multiples of 3, e.g. `12.3e3`.
+    ///
+    /// This maps directly onto SI prefixes (kilo, mega, micro, …).
+    Engineering,
+}
+
 /// Options for how to format a floating point number, e.g. an [`f64`].
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub struct FloatFormatOptions {
@@ -158,6 +298,9 @@ pub struct FloatFormatOptions {
 
     /// Only add thousands separators to decimals if there are at least this many decimals.
     pub min_decimals_for_thousands_separators: usize,
+
+    /// How to show the exponent when falling back to exponential notation.
+    pub exponent_style: ExponentStyle,
 }
 
 impl FloatFormatOptions {
@@ -169,6 +312,7 @@ impl FloatFormatOptions {
         num_decimals: None,
         strip_trailing_zeros: true,
         min_decimals_for_thousands_separators: 6,
+        exponent_style: ExponentStyle::Scientific,
     };
 
     /// Default options for formatting an [`f64`].
@@ -179,6 +323,7 @@ impl FloatFormatOptions {
         num_decimals: None,
         strip_trailing_zeros: true,
         min_decimals_for_thousands_separators: 6,
+        exponent_style: ExponentStyle::Scientific,
     };
 
     /// Always show the sign, even if it is positive (`+`).
@@ -212,13 +357,36 @@ impl FloatFormatOptions {
         self
     }
 
+    /// How to show the exponent of numbers too large to show in full.
+    #[inline]
+    pub fn with_exponent_style(mut self, exponent_style: ExponentStyle) -> Self {
+        self.exponent_style = exponent_style;
+        self
+    }
+
     /// The returned value is for human eyes only, and can not be parsed
     /// by the normal `f64::from_str` function.
     pub fn format(&self, value: impl Into<f64>) -> String {
         self.format_f64(value.into())
     }
 
-    fn format_f64(&self, mut value: f64) -> String {
+    fn format_f64(&self, value: f64) -> String {
+        let mut out = String::new();
+        self.format_into(value, &mut out);
+        out
+    }
+
+    /// Like [`Self::format`], but appends to `out` instead of returning a new [`String`].
+    ///
+    /// Useful to build a longer string out of many numbers, without a temporary string per
+    /// number. Note that formatting may still allocate internally, e.g. to add thousands separators.
+    ///
+    /// To measure what this saves, format a few thousand axis labels per frame with and without
+    /// reusing `out`, and compare the allocation counts, e.g. in a profiler or with a
+    /// counting global allocator.
+    pub fn format_into(&self, mut value: f64, out: &mut String) {
+        use std::fmt::Write as _;
+
         fn reverse(s: &str) -> String {
             s.chars().rev().collect()
         }
@@ -229,23 +397,24 @@ impl FloatFormatOptions {
             num_decimals,
             strip_trailing_zeros,
             min_decimals_for_thousands_separators,
+            exponent_style,
         } = *self;
 
         if value.is_nan() {
-            return "NaN".to_owned();
+            out.push_str("NaN");
+            return;
         }
 
-        let sign = if value < 0.0 {
+        if value < 0.0 {
             value = -value;
-            "−" // NOTE: the minus character: <https://www.compart.com/en/unicode/U+2212>
+            out.push('−'); // NOTE: the minus character: <https://www.compart.com/en/unicode/U+2212>
         } else if always_sign {
-            "+"
-        } else {
-            ""
-        };
+            out.push('+');
+        }
 
-        let abs_string = if value == f64::INFINITY {
-            "∞".to_owned()
+        // Writing to a `String` never fails, so we ignore the results of `write!`.
+        if value == f64::INFINITY {
+            out.push('∞');
         } else {
             let magnitude = value.log10();
             let max_decimals = precision as f64 - magnitude.max(0.0);
@@ -254,7 +423,14 @@ impl FloatFormatOptions {
                 // A very large number (more digits than we have precision),
                 // so use scientific notation.
                 // TODO(emilk): nice formatting of scientific notation with thousands separators
-                format!("{:.*e}", precision.saturating_sub(1), value)
+                match exponent_style {
+                    ExponentStyle::Scientific => {
+                        write!(out, "{:.*e}", precision.saturating_sub(1), value).ok();
+                    }
+                    ExponentStyle::Engineering => {
+                        out.push_str(&format_engineering(value, precision));
+                    }
+                }
             } else {
                 let max_decimals = max_decimals as usize;
 
@@ -282,21 +458,51 @@ impl FloatFormatOptions {
 
                     let integer_part = add_thousands_separators(integer_part);
 
+                    out.push_str(&integer_part);
+                    out.push('.');
                     if fractional_part.len() < min_decimals_for_thousands_separators {
-                        format!("{integer_part}.{fractional_part}")
+                        out.push_str(fractional_part);
                     } else {
                         // For the fractional part we should start counting thousand separators from the _front_, so we reverse:
-                        let fractional_part =
-                            reverse(&add_thousands_separators(&reverse(fractional_part)));
-                        format!("{integer_part}.{fractional_part}")
+                        out.push_str(&reverse(&add_thousands_separators(&reverse(
+                            fractional_part,
+                        ))));
                     }
                 } else {
-                    add_thousands_separators(&formatted) // it's an integer
+                    out.push_str(&add_thousands_separators(&formatted)); // it's an integer
                 }
             }
-        };
+        }
+    }
+}
+
+/// Format a positive number in engineering notation with `precision` significant digits,
+/// e.g. `12.3e3` or `120e−6`.
+fn format_engineering(value: f64, precision: usize) -> String {
+    let precision = precision.max(1);
+
+    // Let the standard library do the rounding, then move the decimal point:
+    let scientific = format!("{:.*e}", precision - 1, value);
+    let Some((mantissa, exponent)) = scientific.split_once('e') else {
+        return scientific;
+    };
+    let Ok(exponent) = exponent.parse::<i32>() else {
+        return scientific;
+    };
+
+    let mut digits = mantissa.replace('.', "");
+    let engineering_exponent = exponent.div_euclid(3) * 3;
+    let num_integer_digits = 1 + (exponent - engineering_exponent) as usize;
+    while digits.len() < num_integer_digits {
+        digits.push('0');
+    }
 
-        format!("{sign}{abs_string}")
+    let (integer_part, fractional_part) = digits.split_at(num_integer_digits);
+    let exponent = format_int(engineering_exponent);
+    if fractional_part.is_empty() {
+        format!("{integer_part}e{exponent}")
+    } else {
+        format!("{integer_part}.{fractional_part}e{exponent}")
     }
 }
 
@@ -328,6 +534,7 @@ pub fn format_lat_lon(value: f64) -> String {
             num_decimals: Some(6),
             strip_trailing_zeros: false,
             min_decimals_for_thousands_separators: 10,
+            exponent_style: ExponentStyle::Scientific,
         }
         .format_f64(value)
     )
@@ -411,6 +618,92 @@ fn test_format_f64_custom() {
     }
 }
 
+#[test]
+fn test_format_into() {
+    let default = FloatFormatOptions::DEFAULT_f64;
+    let signed = FloatFormatOptions::DEFAULT_f32.with_always_sign(true);
+    let two_decimals = FloatFormatOptions::DEFAULT_f64.with_decimals(2);
+    let engineering = FloatFormatOptions::DEFAULT_f64
+        .with_precision(3)
+        .with_exponent_style(ExponentStyle::Engineering);
+
+    let cases = [
+        (default, 0.0, "0"),
+        (default, -1.5, "−1.5"),
+        (default, 123_456.789, "123 456.789"),
+        (default, 0.000_012_345, "0.000 012 345"),
+        (default, 1e20, "1.00000000000000e20"),
+        (default, f64::NAN, "NaN"),
+        (default, f64::NEG_INFINITY, "−∞"),
+        (signed, 0.0, "+0"),
+        (signed, -1.5, "−1.5"),
+        (signed, 123_456.789, "+123 456.8"),
+        (signed, 0.000_012_345, "+0.000 012 3"),
+        (signed, 1e20, "+1.000000e20"),
+        (two_decimals, 123_456.789, "123 456.79"),
+        (two_decimals, 0.000_012_345, "0"),
+        (engineering, 123_456.789, "123e3"),
+        (engineering, 1e20, "100e18"),
+    ];
+
+    let mut buffer = String::new();
+    for (options, value, expected) in cases {
+        buffer.clear();
+        options.format_into(value, &mut buffer);
+        assert!(
+            buffer == expected,
+            "Expected to format {value} as '{expected}', but got '{buffer}'. Options: {options:#?}"
+        );
+        assert_eq!(options.format(value), expected);
+    }
+
+    // Appends rather than overwrites:
+    let mut buffer = "x = ".to_owned();
+    FloatFormatOptions::DEFAULT_f64.format_into(42.5, &mut buffer);
+    assert_eq!(buffer, "x = 42.5");
+}
+
+#[test]
+fn test_format_f64_engineering() {
+    let engineering = |precision| {
+        FloatFormatOptions::DEFAULT_f64
+            .with_precision(precision)
+            .with_exponent_style(ExponentStyle::Engineering)
+    };
+
+    let cases = [
+        (engineering(3), 12_345.0, "12.3e3"),
+        (engineering(3), -12_345.0, "−12.3e3"),
+        (engineering(3), 1_500.0, "1.50e3"),
+        (engineering(3), 999_999.0, "1.00e6"), // rounding carries over into the next exponent
+        (engineering(2), 123_456.0, "120e3"),
+        (
+            FloatFormatOptions::DEFAULT_f32.with_exponent_style(ExponentStyle::Engineering),
+            11_000_000.0,
+            "11.00000e6",
+        ),
+        (
+            FloatFormatOptions::DEFAULT_f64.with_exponent_style(ExponentStyle::Engineering),
+            -std::f64::consts::PI * 1e20,
+            "−314.159265358979e18",
+        ),
+        // Values that fit are not affected:
+        (engineering(3), 123.0, "123"),
+    ];
+    for (options, value, expected) in cases {
+        let got = options.format(value);
+        assert!(
+            got == expected,
+            "Expected to format {value} as '{expected}', but got '{got}'. Options: {options:#?}"
+        );
+    }
+
+    // Small numbers never fall back to exponential notation, so test the helper directly:
+    assert_eq!(format_engineering(0.00012, 3), "120e−6");
+    assert_eq!(format_engineering(0.0012, 3), "1.20e−3");
+    assert_eq!(format_engineering(12_345.0, 1), "10e3");
+}
+
 /// Parses a number, ignoring whitespace (e.g. thousand separators),
 /// and treating the special minus character `MINUS` (−) as a minus sign.
 pub fn parse_f64(text: &str) -> Option<f64> {
@@ -491,6 +784,80 @@ fn test_format_large_number() {
     }
 }
 
+/// Pretty format a large number by using SI notation (base 10),
+/// always showing `sig_figs` significant figures, e.g.
+///
+/// ```
+/// # use re_format::approximate_large_number_sig;
+/// assert_eq!(approximate_large_number_sig(1_234.0, 2), "1.2k");
+/// assert_eq!(approximate_large_number_sig(12_345.0, 2), "12k");
+/// assert_eq!(approximate_large_number_sig(999_999.0, 2), "1.0M");
+/// assert_eq!(approximate_large_number_sig(1_234_567.0, 3), "1.23M");
+/// ```
+///
+/// Unlike [`approximate_large_number`], the number is rounded _before_ picking the unit,
+/// so you never get things like `1000k`. This makes it a good fit for e.g. axis labels.
+pub fn approximate_large_number_sig(number: f64, sig_figs: usize) -> String {
+    if number < 0.0 {
+        return format!("{MINUS}{}", approximate_large_number_sig(-number, sig_figs));
+    }
+    if number == 0.0 || !number.is_finite() {
+        return format!("{number}");
+    }
+
+    let sig_figs = sig_figs.max(1) as i32;
+
+    // Round to the requested number of significant figures first,
+    // so that e.g. 999 999 becomes 1 000 000 and gets the `M` unit:
+    let magnitude = number.log10().floor() as i32;
+    let step = 10.0_f64.powi(magnitude - sig_figs + 1);
+    let rounded = (number / step).round() * step;
+
+    let (scaled, unit) = if rounded < 1_000.0 {
+        (rounded, "")
+    } else if rounded < 1_000_000.0 {
+        (rounded / 1_000.0, "k")
+    } else if rounded < 1_000_000_000.0 {
+        (rounded / 1_000_000.0, "M")
+    } else {
+        (rounded / 1_000_000_000.0, "G")
+    };
+
+    let scaled_magnitude = scaled.log10().floor() as i32;
+    let decimals = (sig_figs - 1 - scaled_magnitude).max(0) as usize;
+    format!("{scaled:.decimals$}{unit}")
+}
+
+#[test]
+fn test_approximate_large_number_sig() {
+    let test_cases = [
+        (0.0, 2, "0"),
+        (123.0, 2, "120"),
+        (123.0, 3, "123"),
+        (999.0, 2, "1.0k"),
+        (1_234.0, 2, "1.2k"),
+        (12_345.0, 2, "12k"),
+        (123_456.0, 2, "120k"),
+        (999_499.0, 3, "999k"),
+        (999_999.0, 2, "1.0M"),
+        (999_999.0, 3, "1.00M"),
+        (1_000_000.0, 2, "1.0M"),
+        (1_234_567.0, 1, "1M"),
+        (1_234_567.0, 3, "1.23M"),
+        (999_999_999.0, 2, "1.0G"),
+        (1_234_567_890_123.0, 2, "1200G"),
+        (-1_234_567.0, 2, "−1.2M"),
+    ];
+
+    for (value, sig_figs, expected) in test_cases {
+        assert_eq!(
+            approximate_large_number_sig(value, sig_figs),
+            expected,
+            "{value} with {sig_figs} significant figures"
+        );
+    }
+}
+
 // --- Bytes ---
 
 /// Pretty format a number of bytes by using SI notation (base2), e.g.
@@ -699,32 +1066,234 @@ fn test_parse_bytes() {
     }
 }
 
-// --- Durations ---
+/// Why [`parse_bytes_detailed`] failed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ByteParseError {
+    /// The unit is one of bits (e.g. `Mb`) rather than bytes (e.g. `MB`).
+    LooksLikeBits {
+        /// The unit that was used, e.g. `Mb`.
+        bits_unit: &'static str,
+
+        /// The unit of bytes that was probably meant, e.g. `MB`.
+        bytes_unit: &'static str,
+    },
+
+    /// The unit is fine, but the number in front of it isn't an integer.
+    InvalidNumber(String),
+
+    /// Missing or unknown unit.
+    UnknownUnit(String),
+}
+
+impl std::fmt::Display for ByteParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::LooksLikeBits {
+                bits_unit,
+                bytes_unit,
+            } => write!(
+                f,
+                "{bits_unit:?} is a unit of bits, not bytes. Did you mean {bytes_unit:?}?"
+            ),
+            Self::InvalidNumber(number) => write!(f, "Expected an integer, got {number:?}"),
+            Self::UnknownUnit(bytes) => write!(
+                f,
+                "Expected a number of bytes with a unit, like `100MB` or `2GiB`, got {bytes:?}"
+            ),
+        }
+    }
+}
 
-pub fn parse_duration(duration: &str) -> Result<f32, String> {
-    fn parse_num(s: &str) -> Result<f32, String> {
-        s.parse()
-            .map_err(|_ignored| format!("Expected a number, got {s:?}"))
+impl std::error::Error for ByteParseError {}
+
+/// Like [`parse_bytes`], but explains what went wrong.
+///
+/// In particular, units of bits like `Mb` are rejected with [`ByteParseError::LooksLikeBits`],
+/// so that the user can be told they probably meant `MB`.
+pub fn parse_bytes_detailed(bytes: &str) -> Result<i64, ByteParseError> {
+    /// Longest suffixes first, so that e.g. `Mib` isn't mistaken for `b`.
+    const BITS_TO_BYTES_UNITS: &[(&str, &str)] = &[
+        ("Kib", "KiB"),
+        ("kib", "KiB"),
+        ("Mib", "MiB"),
+        ("Gib", "GiB"),
+        ("Tib", "TiB"),
+        ("kb", "kB"),
+        ("Kb", "kB"),
+        ("Mb", "MB"),
+        ("Gb", "GB"),
+        ("Tb", "TB"),
+        ("b", "B"),
+    ];
+    const BYTES_UNITS: &[&str] = &["kB", "MB", "GB", "TB", "KiB", "MiB", "GiB", "TiB", "B"];
+
+    if let Some(number_of_bytes) = parse_bytes(bytes) {
+        return Ok(number_of_bytes);
+    }
+
+    let is_integer = |number: &str| {
+        let number = number.strip_prefix(MINUS).unwrap_or(number);
+        number.parse::<i64>().is_ok()
+    };
+
+    for &(bits_unit, bytes_unit) in BITS_TO_BYTES_UNITS {
+        if let Some(number) = bytes.strip_suffix(bits_unit) {
+            if is_integer(number) {
+                return Err(ByteParseError::LooksLikeBits {
+                    bits_unit,
+                    bytes_unit,
+                });
+            }
+        }
     }
 
-    if let Some(ms) = duration.strip_suffix("ms") {
-        Ok(parse_num(ms)? * 1e-3)
-    } else if let Some(s) = duration.strip_suffix('s') {
-        Ok(parse_num(s)?)
-    } else if let Some(s) = duration.strip_suffix('m') {
-        Ok(parse_num(s)? * 60.0)
-    } else if let Some(s) = duration.strip_suffix('h') {
-        Ok(parse_num(s)? * 60.0 * 60.0)
+    for unit in BYTES_UNITS {
+        if let Some(number) = bytes.strip_suffix(unit) {
+            return Err(ByteParseError::InvalidNumber(number.to_owned()));
+        }
+    }
+
+    Err(ByteParseError::UnknownUnit(bytes.to_owned()))
+}
+
+#[test]
+fn test_parse_bytes_detailed() {
+    assert_eq!(parse_bytes_detailed("1MB"), Ok(1_000_000));
+    assert_eq!(parse_bytes_detailed("1MiB"), Ok(1024 * 1024));
+    assert_eq!(parse_bytes_detailed("−1kB"), Ok(-1_000));
+
+    let looks_like_bits = |bits_unit, bytes_unit| {
+        Err(ByteParseError::LooksLikeBits {
+            bits_unit,
+            bytes_unit,
+        })
+    };
+    assert_eq!(parse_bytes_detailed("1Mb"), looks_like_bits("Mb", "MB"));
+    assert_eq!(parse_bytes_detailed("12kb"), looks_like_bits("kb", "kB"));
+    assert_eq!(parse_bytes_detailed("1Mib"), looks_like_bits("Mib", "MiB"));
+    assert_eq!(parse_bytes_detailed("−8b"), looks_like_bits("b", "B"));
+    assert_eq!(
+        parse_bytes_detailed("1Mb").unwrap_err().to_string(),
+        r#""Mb" is a unit of bits, not bytes. Did you mean "MB"?"#
+    );
+
+    assert_eq!(
+        parse_bytes_detailed("1.5MB"),
+        Err(ByteParseError::InvalidNumber("1.5".to_owned()))
+    );
+    assert_eq!(
+        parse_bytes_detailed("lotsMb"),
+        Err(ByteParseError::UnknownUnit("lotsMb".to_owned()))
+    );
+    assert_eq!(
+        parse_bytes_detailed("100"),
+        Err(ByteParseError::UnknownUnit("100".to_owned()))
+    );
+}
+
+// --- Durations ---
+
+/// Parses a duration with a unit, e.g. `1.5s`, `250ms` or `2min`.
+///
+/// The supported units are `ns`, `us` (or `µs`), `ms`, `s`, `min` (or `m`) and `h`.
+/// Whitespace is ignored and the special minus character `MINUS` (−) is understood,
+/// just like in [`parse_f64`], but negative durations are rejected.
+pub fn parse_duration(duration: &str) -> Option<std::time::Duration> {
+    /// Units in seconds. Longer suffixes first, so that e.g. `ms` isn't mistaken for `s`.
+    const UNITS: &[(&str, f64)] = &[
+        ("min", 60.0),
+        ("ns", 1e-9),
+        ("us", 1e-6),
+        ("µs", 1e-6),
+        ("ms", 1e-3),
+        ("s", 1.0),
+        ("m", 60.0),
+        ("h", 60.0 * 60.0),
+    ];
+
+    let duration = duration.trim();
+    let (number, unit_in_seconds) = UNITS.iter().find_map(|&(unit, unit_in_seconds)| {
+        duration
+            .strip_suffix(unit)
+            .map(|number| (number, unit_in_seconds))
+    })?;
+
+    std::time::Duration::try_from_secs_f64(parse_f64(number)? * unit_in_seconds).ok()
+}
+
+/// Format the time elapsed since some start as `+MM:SS.mmm`, or `+H:MM:SS.mmm` past the hour.
+///
+/// The input is in nanoseconds. Sub-millisecond precision is truncated.
+///
+/// ```
+/// # use re_format::format_elapsed_ns;
+/// assert_eq!(format_elapsed_ns(62_500_000_000), "+01:02.500");
+/// ```
+pub fn format_elapsed_ns(elapsed_ns: i64) -> String {
+    let sign = if elapsed_ns < 0 { MINUS } else { '+' };
+    let total_ms = elapsed_ns.unsigned_abs() / 1_000_000;
+
+    let ms = total_ms % 1_000;
+    let secs = (total_ms / 1_000) % 60;
+    let mins = (total_ms / 60_000) % 60;
+    let hours = total_ms / 3_600_000;
+
+    if hours == 0 {
+        format!("{sign}{mins:02}:{secs:02}.{ms:03}")
     } else {
-        Err(format!(
-            "Expected a suffix of 'ms', 's', 'm' or 'h' in string {duration:?}"
-        ))
+        format!("{sign}{hours}:{mins:02}:{secs:02}.{ms:03}")
+    }
+}
+
+#[test]
+fn test_format_elapsed_ns() {
+    let test_cases = [
+        (0, "+00:00.000"),
+        (999_999, "+00:00.000"),
+        (1_000_000, "+00:00.001"),
+        (62_500_000_000, "+01:02.500"),
+        (3_599_999_000_000, "+59:59.999"),
+        (3_723_004_000_000, "+1:02:03.004"),
+        (-1_500_000_000, "−00:01.500"),
+    ];
+
+    for (elapsed_ns, expected) in test_cases {
+        assert_eq!(format_elapsed_ns(elapsed_ns), expected, "{elapsed_ns}");
     }
 }
 
 #[test]
 fn test_parse_duration() {
-    assert_eq!(parse_duration("3.2s"), Ok(3.2));
-    assert_eq!(parse_duration("250ms"), Ok(0.250));
-    assert_eq!(parse_duration("3m"), Ok(3.0 * 60.0));
+    use std::time::Duration;
+
+    let test_cases = [
+        ("100ns", Some(Duration::from_nanos(100))),
+        ("250us", Some(Duration::from_micros(250))),
+        ("250µs", Some(Duration::from_micros(250))),
+        ("250ms", Some(Duration::from_millis(250))),
+        ("3.2s", Some(Duration::from_millis(3_200))),
+        ("1.5 s", Some(Duration::from_millis(1_500))),
+        ("0s", Some(Duration::ZERO)),
+        ("2min", Some(Duration::from_secs(120))),
+        ("3m", Some(Duration::from_secs(180))),
+        ("1h", Some(Duration::from_secs(3_600))),
+        ("1\u{2009}000ms", Some(Duration::from_secs(1))), // thousands separator
+        ("-1s", None),                                    // hyphen-minus
+        ("−1s", None),                                    // proper minus
+        ("1Mb", None),                                    // not a duration
+        ("1", None),                                      // missing unit
+        ("s", None),                                      // missing number
+        ("infs", None),                                   // out of range
+    ];
+
+    for (text, expected) in test_cases {
+        let parsed = parse_duration(text);
+        match (parsed, expected) {
+            (Some(parsed), Some(expected)) => assert!(
+                parsed.abs_diff(expected) < Duration::from_nanos(1),
+                "{text:?}: {parsed:?} != {expected:?}"
+            ),
+            _ => assert_eq!(parsed, expected, "{text:?}"),
+        }
+    }
 }
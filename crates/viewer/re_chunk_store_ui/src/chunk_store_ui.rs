@@ -5,7 +5,7 @@ use egui_extras::{Column, TableRow};
 use itertools::{Either, Itertools};
 
 use re_chunk_store::{ChunkStore, LatestAtQuery, RangeQuery};
-use re_log_types::{ResolvedTimeRange, StoreKind, TimeType, TimeZone, Timeline, TimelineName};
+use re_log_types::{StoreKind, TimeZone, TimelineName};
 use re_ui::{list_item, UiExt as _};
 use re_viewer_context::ViewerContext;
 
@@ -304,7 +304,11 @@ impl DatastoreUi {
                     ui.style_mut().wrap_mode = Some(egui::TextWrapMode::Truncate);
 
                     if let Some(time_range) = timeline_ranges.get(timeline) {
-                        ui.label(format_time_range(timeline, time_range, time_zone));
+                        ui.label(
+                            timeline
+                                .typ()
+                                .format_range_with_length(*time_range, time_zone),
+                        );
                     } else {
                         ui.label("-");
                     };
@@ -445,32 +449,3 @@ impl DatastoreUi {
         should_copy_chunks
     }
 }
-
-fn format_time_range(
-    timeline: &Timeline,
-    time_range: &ResolvedTimeRange,
-    time_zone: TimeZone,
-) -> String {
-    if time_range.min() == time_range.max() {
-        timeline.typ().format(time_range.min(), time_zone)
-    } else {
-        format!(
-            "{} ({})",
-            timeline.format_time_range(time_range, time_zone),
-            match timeline.typ() {
-                TimeType::Time => {
-                    format!(
-                        "{}s",
-                        re_format::format_f64(
-                            (time_range.max().as_f64() - time_range.min().as_f64())
-                                / 1_000_000_000.0
-                        )
-                    )
-                }
-                TimeType::Sequence => {
-                    format!("{} ticks", re_format::format_uint(time_range.abs_length()))
-                }
-            }
-        )
-    }
-}
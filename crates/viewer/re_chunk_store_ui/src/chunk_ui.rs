@@ -137,7 +137,7 @@ impl ChunkUi {
                     ui.style_mut().wrap_mode = Some(egui::TextWrapMode::Truncate);
 
                     let time = TimeInt::from(time_column.times_raw()[row_index]);
-                    ui.label(time_column.timeline().typ().format(time, time_zone));
+                    ui.label(time_column.timeline().format_time(time, time_zone));
                 });
             }
 
@@ -1,6 +1,6 @@
 use re_types::blueprint::components;
 use re_types_core::ComponentBatch as _;
-use re_viewer_context::external::re_log_types::TimelineName;
+use re_viewer_context::external::re_log_types::{sort_timelines_canonical, TimelineName};
 use re_viewer_context::{MaybeMutRef, ViewerContext};
 
 //TODO(#7498): might be unneeded after the dataframe view update
@@ -16,7 +16,10 @@ pub(crate) fn edit_timeline_name(
         let mut combobox_response = egui::ComboBox::from_id_salt(id_salt)
             .selected_text(current_value.as_str())
             .show_ui(ui, |ui| {
-                for timeline in ctx.recording().timelines() {
+                let mut timelines = ctx.recording().timelines().copied().collect::<Vec<_>>();
+                sort_timelines_canonical(&mut timelines);
+
+                for timeline in timelines {
                     let response = ui.selectable_value(
                         &mut current_value,
                         *timeline.name(),
@@ -41,6 +41,28 @@ pub fn context_menu_ui_for_item(
     item: &Item,
     item_response: &egui::Response,
     selection_update_behavior: SelectionUpdateBehavior,
+) {
+    context_menu_ui_for_item_with_extra_ui(
+        ctx,
+        viewport_blueprint,
+        item,
+        item_response,
+        selection_update_behavior,
+        |_ui| {},
+    );
+}
+
+/// Like [`context_menu_ui_for_item`], with some caller-specific UI shown above the usual actions.
+///
+/// This is for actions that only make sense where the item is shown, and which thus can't be a
+/// regular context menu action.
+pub fn context_menu_ui_for_item_with_extra_ui(
+    ctx: &ViewerContext<'_>,
+    viewport_blueprint: &ViewportBlueprint,
+    item: &Item,
+    item_response: &egui::Response,
+    selection_update_behavior: SelectionUpdateBehavior,
+    extra_ui: impl FnOnce(&mut egui::Ui),
 ) {
     item_response.context_menu(|ui| {
         if ui.input_mut(|i| i.consume_key(egui::Modifiers::NONE, egui::Key::Escape)) {
@@ -48,6 +70,8 @@ pub fn context_menu_ui_for_item(
             return;
         }
 
+        extra_ui(ui);
+
         let mut show_context_menu = |selection: &ItemCollection| {
             let context_menu_ctx = ContextMenuContext {
                 viewer_context: ctx,
@@ -548,7 +548,7 @@ pub fn time_button(
 
     let response = ui.selectable_label(
         is_selected,
-        timeline.typ().format(value, ctx.app_options.time_zone),
+        timeline.format_time(value, ctx.app_options.time_zone),
     );
     if response.clicked() {
         ctx.rec_cfg
@@ -825,7 +825,7 @@ pub fn entity_db_button_ui(
         // blueprint.
         // TODO(jleibs): We should still have an `Activate this Blueprint` button in the selection panel
         // for the blueprint.
-        if store_id.kind == re_log_types::StoreKind::Recording {
+        if store_id.is_recording() {
             ctx.command_sender
                 .send_system(SystemCommand::ActivateRecording(store_id.clone()));
         }
@@ -11,9 +11,11 @@ use egui::{epaint::Vertex, lerp, pos2, remap, Color32, NumExt as _, Rect, Shape}
 
 use re_chunk_store::Chunk;
 use re_chunk_store::RangeQuery;
+use re_entity_db::TimeHistogram;
 use re_log_types::{ComponentPath, ResolvedTimeRange, TimeInt, Timeline};
 use re_viewer_context::{Item, TimeControl, UiLayout, ViewerContext};
 
+use crate::event_navigation::event_histogram;
 use crate::recursive_chunks_per_timeline_subscriber::PathRecursiveChunksPerTimelineStoreSubscriber;
 use crate::TimePanelItem;
 
@@ -433,6 +435,23 @@ pub fn data_density_graph_ui(
                     ui.layer_id(),
                     egui::Id::new("data_tooltip"),
                     |ui| {
+                        if let Some(pointer_pos) = ui.input(|i| i.pointer.hover_pos()) {
+                            let interact_radius = ui.style().interaction.resize_grab_radius_side;
+                            show_bucket_tooltip(
+                                ctx,
+                                ui,
+                                db,
+                                item,
+                                timeline,
+                                hovered_bucket_time_range(
+                                    time_ranges_ui,
+                                    pointer_pos.x,
+                                    interact_radius,
+                                ),
+                            );
+                            ui.separator();
+                        }
+
                         show_row_ids_tooltip(ctx, ui, time_ctrl, db, item, hovered_time);
                     },
                 );
@@ -623,6 +642,44 @@ impl Default for DensityGraphBuilderConfig {
     }
 }
 
+/// The time range of the density graph bucket under the pointer.
+///
+/// The bucket spans the same x range that counts as hovering an event in the graph.
+fn hovered_bucket_time_range(
+    time_ranges_ui: &TimeRangesUi,
+    pointer_x: f32,
+    interact_radius: f32,
+) -> ResolvedTimeRange {
+    time_ranges_ui
+        .time_range_from_x_range((pointer_x - interact_radius)..=(pointer_x + interact_radius))
+}
+
+fn num_events_in_time_range(events: &TimeHistogram, time_range: ResolvedTimeRange) -> u64 {
+    events.range_count(time_range.min().as_i64()..=time_range.max().as_i64())
+}
+
+/// "N events between t0 and t1"
+fn show_bucket_tooltip(
+    ctx: &ViewerContext<'_>,
+    ui: &mut egui::Ui,
+    db: &re_entity_db::EntityDb,
+    item: &TimePanelItem,
+    timeline: Timeline,
+    time_range: ResolvedTimeRange,
+) {
+    let events = event_histogram(db, item, &timeline, time_range);
+    let num_events = num_events_in_time_range(&events, time_range);
+
+    let time_zone = ctx.app_options.time_zone;
+    let time_type = timeline.typ();
+    ui.label(format!(
+        "{} between {} and {}",
+        re_format::format_count(num_events, "event"),
+        time_type.format(time_range.min(), time_zone),
+        time_type.format(time_range.max(), time_zone),
+    ));
+}
+
 fn show_row_ids_tooltip(
     ctx: &ViewerContext<'_>,
     ui: &mut egui::Ui,
@@ -758,3 +815,44 @@ fn make_brighter(color: Color32) -> Color32 {
         b.saturating_add(64),
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use re_log_types::TimeReal;
+    use re_viewer_context::TimeView;
+
+    #[test]
+    fn hovered_bucket_event_count() {
+        let mut events = TimeHistogram::default();
+        for time in [10, 10, 11, 15, 20, 100] {
+            events.increment(time, 1);
+        }
+
+        // One ui point per time unit, starting at time 0:
+        let time_ranges_ui = TimeRangesUi::new(
+            Rangef::new(0.0, 200.0),
+            TimeView {
+                min: TimeReal::from(0),
+                time_spanned: 200.0,
+            },
+            &[ResolvedTimeRange::new(0, 200)],
+        );
+
+        let bucket = |x: f32, radius: f32| {
+            let time_range = hovered_bucket_time_range(&time_ranges_ui, x, radius);
+            (
+                time_range.min().as_i64(),
+                time_range.max().as_i64(),
+                num_events_in_time_range(&events, time_range),
+            )
+        };
+
+        // The bucket is widened to whole time units:
+        assert_eq!(bucket(10.5, 0.25), (10, 11, 3));
+        assert_eq!(bucket(12.5, 0.25), (12, 13, 0));
+        assert_eq!(bucket(15.5, 5.2), (10, 21, 5));
+        assert_eq!(bucket(50.5, 4.8), (45, 56, 0));
+        assert_eq!(bucket(100.5, 0.25), (100, 101, 1));
+    }
+}
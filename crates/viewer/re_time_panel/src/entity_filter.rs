@@ -0,0 +1,234 @@
+//! Filtering the streams tree down to the entities the user is looking for.
+//!
+//! With thousands of entities, scrolling through the whole hierarchy is hopeless: the filter only
+//! keeps the entities whose path matches, along with their ancestors so that they can be reached.
+
+use std::ops::Range;
+
+use nohash_hasher::IntSet;
+
+use re_entity_db::EntityTree;
+use re_log_types::{EntityPath, EntityPathGlob};
+
+/// A filter on entity paths.
+///
+/// Plain text matches anywhere in the path, case-insensitively.
+/// If it parses as an [`EntityPathGlob`] with wildcards, e.g. `world/*/points` or `**/points`,
+/// it must instead match the whole path, with the same case-sensitive rules as entity paths.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EntityFilter {
+    /// Lowercase.
+    pattern: String,
+    glob: Option<EntityPathGlob>,
+}
+
+impl EntityFilter {
+    /// `None` if the filter is empty, i.e. everything should be shown.
+    pub fn new(filter: &str) -> Option<Self> {
+        let filter = filter.trim();
+        if filter.is_empty() {
+            return None;
+        }
+
+        // Only valid globs are used as such. Anything else, e.g. `cam*`, is plain text.
+        let glob = filter
+            .contains('*')
+            .then(|| EntityPathGlob::parse_strict(filter).ok())
+            .flatten();
+
+        Some(Self {
+            pattern: filter.to_lowercase(),
+            glob,
+        })
+    }
+
+    /// Does the path of this entity match the filter?
+    pub fn matches(&self, entity_path: &EntityPath) -> bool {
+        if let Some(glob) = &self.glob {
+            glob.is_match(entity_path)
+        } else {
+            entity_path
+                .to_string()
+                .to_lowercase()
+                .contains(&self.pattern)
+        }
+    }
+
+    /// Runs the filter on every entity of `tree`, down to `max_depth`.
+    pub fn matches_in_tree(self, tree: &EntityTree, max_depth: usize) -> EntityFilterMatches {
+        fn visit(matches: &mut EntityFilterMatches, tree: &EntityTree, max_depth: usize) -> bool {
+            let mut has_matching_descendant = false;
+            if tree.path.len() < max_depth {
+                for child in tree.children.values() {
+                    has_matching_descendant |= visit(matches, child, max_depth);
+                }
+            }
+
+            let is_match = matches.filter.matches(&tree.path);
+            if is_match {
+                matches.matching.insert(tree.path.clone());
+            }
+            if has_matching_descendant {
+                matches.ancestors.insert(tree.path.clone());
+            }
+
+            is_match || has_matching_descendant
+        }
+
+        let mut matches = EntityFilterMatches {
+            filter: self,
+            matching: Default::default(),
+            ancestors: Default::default(),
+        };
+        visit(&mut matches, tree, max_depth);
+        matches
+    }
+
+    /// Where the filter matches in a label, for highlighting it.
+    ///
+    /// Only plain-text filters are highlighted: globs match whole paths, not labels.
+    pub fn match_range(&self, text: &str) -> Option<Range<usize>> {
+        if self.glob.is_some() {
+            return None;
+        }
+
+        let lowercase = text.to_lowercase();
+        if lowercase.len() != text.len() {
+            // Lowercasing changed the byte offsets, so they can't be used on `text`.
+            return None;
+        }
+
+        let start = lowercase.find(&self.pattern)?;
+        Some(start..start + self.pattern.len())
+    }
+}
+
+/// The entities of a tree that survive an [`EntityFilter`].
+#[derive(Clone, Debug)]
+pub struct EntityFilterMatches {
+    filter: EntityFilter,
+
+    /// Entities whose path matches.
+    matching: IntSet<EntityPath>,
+
+    /// Entities with matching descendants.
+    ancestors: IntSet<EntityPath>,
+}
+
+impl EntityFilterMatches {
+    pub fn filter(&self) -> &EntityFilter {
+        &self.filter
+    }
+
+    /// Should this entity be shown at all?
+    pub fn is_shown(&self, entity_path: &EntityPath) -> bool {
+        self.is_match(entity_path) || self.has_matching_descendant(entity_path)
+    }
+
+    /// Does the path of this entity itself match? Only then are its components shown.
+    pub fn is_match(&self, entity_path: &EntityPath) -> bool {
+        self.matching.contains(entity_path)
+    }
+
+    /// Should this entity be expanded to reveal the matches below it?
+    pub fn has_matching_descendant(&self, entity_path: &EntityPath) -> bool {
+        self.ancestors.contains(entity_path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use re_chunk_store::{Chunk, RowId};
+    use re_log_types::{example_components::MyPoint, StoreId, StoreKind, Timeline};
+
+    use super::EntityFilter;
+
+    #[test]
+    fn test_entity_filter() {
+        let mut entity_db = re_entity_db::EntityDb::new(StoreId::random(StoreKind::Recording));
+        for entity_path in [
+            "world/robot/camera",
+            "world/robot/Lidar",
+            "world/points",
+            "plots/speed",
+        ] {
+            let chunk = Chunk::builder(entity_path.into())
+                .with_component_batches(
+                    RowId::new(),
+                    [(Timeline::new_sequence("frame_nr"), 1_i64)],
+                    [&MyPoint::from_iter(0..1) as _],
+                )
+                .build()
+                .unwrap();
+            entity_db.add_chunk(&Arc::new(chunk)).unwrap();
+        }
+
+        let surviving = |filter: &str| {
+            let matches = EntityFilter::new(filter)
+                .unwrap()
+                .matches_in_tree(entity_db.tree(), 64);
+            let mut surviving = Vec::new();
+            entity_db.tree().visit_children_recursively(|entity_path| {
+                if matches.is_shown(entity_path) {
+                    surviving.push(entity_path.to_string());
+                }
+            });
+            surviving.sort();
+            surviving
+        };
+
+        assert_eq!(
+            surviving("lidar"),
+            ["/", "/world", "/world/robot", "/world/robot/Lidar"]
+        );
+        assert_eq!(
+            surviving("ROBOT"),
+            [
+                "/",
+                "/world",
+                "/world/robot",
+                "/world/robot/Lidar",
+                "/world/robot/camera",
+            ]
+        );
+        assert_eq!(surviving("world/p"), ["/", "/world", "/world/points"]);
+        assert_eq!(
+            surviving("world/*/camera"),
+            ["/", "/world", "/world/robot", "/world/robot/camera"]
+        );
+        assert_eq!(surviving("**/speed"), ["/", "/plots", "/plots/speed"]);
+        assert_eq!(
+            surviving("/world"),
+            [
+                "/",
+                "/world",
+                "/world/points",
+                "/world/robot",
+                "/world/robot/Lidar",
+                "/world/robot/camera",
+            ]
+        );
+        assert!(surviving("nothing").is_empty());
+
+        // Globs match whole paths, case-sensitively:
+        assert!(surviving("world/*/cam").is_empty());
+        assert!(surviving("world/*/lidar").is_empty());
+
+        // Not a valid glob, so it is searched for as plain text:
+        assert!(surviving("cam*").is_empty());
+
+        assert_eq!(EntityFilter::new("  "), None);
+    }
+
+    #[test]
+    fn test_match_range() {
+        let filter = EntityFilter::new("Cam").unwrap();
+        assert_eq!(filter.match_range("my_camera"), Some(3..6));
+        assert_eq!(filter.match_range("lidar"), None);
+
+        let glob = EntityFilter::new("cam*").unwrap();
+        assert_eq!(glob.match_range("camera"), None);
+    }
+}
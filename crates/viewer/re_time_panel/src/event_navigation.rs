@@ -0,0 +1,120 @@
+//! Jumping the time marker to the previous/next event of the selected item.
+
+use re_chunk_store::RangeQuery;
+use re_entity_db::{EntityDb, TimeHistogram};
+use re_log_types::{ResolvedTimeRange, Timeline};
+use re_viewer_context::{Item, TimeControl, ViewerContext};
+
+use crate::recursive_chunks_per_timeline_subscriber::PathRecursiveChunksPerTimelineStoreSubscriber;
+use crate::TimePanelItem;
+
+/// Jump to the previous event of the current selection.
+pub const PREVIOUS_EVENT_KEY: egui::Key = egui::Key::Comma;
+
+/// Jump to the next event of the current selection.
+pub const NEXT_EVENT_KEY: egui::Key = egui::Key::Period;
+
+/// Handle the "jump to previous/next event" keyboard shortcuts.
+///
+/// The events are those of the first selected item: a component, or an entity including its whole subtree.
+pub fn handle_event_navigation_shortcuts(
+    ctx: &ViewerContext<'_>,
+    db: &EntityDb,
+    time_ctrl: &mut TimeControl,
+    egui_ctx: &egui::Context,
+) {
+    if egui_ctx.wants_keyboard_input() {
+        return; // Don't steal `,` and `.` from text fields.
+    }
+
+    let (previous, next) = egui_ctx.input_mut(|i| {
+        (
+            i.consume_key(egui::Modifiers::NONE, PREVIOUS_EVENT_KEY),
+            i.consume_key(egui::Modifiers::NONE, NEXT_EVENT_KEY),
+        )
+    });
+    if !previous && !next {
+        return;
+    }
+
+    let Some(item) = ctx.selection().first_item().and_then(time_panel_item) else {
+        return;
+    };
+
+    let events = event_histogram(
+        db,
+        &item,
+        time_ctrl.timeline(),
+        ResolvedTimeRange::EVERYTHING,
+    );
+    if previous {
+        time_ctrl.jump_to_previous_event(&events);
+    }
+    if next {
+        time_ctrl.jump_to_next_event(&events);
+    }
+}
+
+fn time_panel_item(item: &Item) -> Option<TimePanelItem> {
+    match item {
+        Item::ComponentPath(component_path) => {
+            Some(TimePanelItem::component_path(component_path.clone()))
+        }
+        _ => item
+            .entity_path()
+            .map(|entity_path| TimePanelItem::entity_path(entity_path.clone())),
+    }
+}
+
+/// All temporal events of `item` on `timeline` within `time_range`.
+///
+/// For entities, this includes the events of the whole subtree.
+pub fn event_histogram(
+    db: &EntityDb,
+    item: &TimePanelItem,
+    timeline: &Timeline,
+    time_range: ResolvedTimeRange,
+) -> TimeHistogram {
+    re_tracing::profile_function!();
+
+    let mut events = TimeHistogram::default();
+
+    let engine = db.storage_engine();
+    let store = engine.store();
+
+    if let Some(component_name) = item.component_name {
+        let query = RangeQuery::new(*timeline, time_range);
+        for chunk in store.range_relevant_chunks(&query, &item.entity_path, component_name) {
+            if chunk.is_static() {
+                continue;
+            }
+            for (time, _row_id) in chunk.iter_component_indices(timeline, &component_name) {
+                if time_range.contains(time) {
+                    events.increment(time.as_i64(), 1);
+                }
+            }
+        }
+    } else {
+        PathRecursiveChunksPerTimelineStoreSubscriber::access(&store.id(), |chunks_per_timeline| {
+            let Some(info) = chunks_per_timeline
+                .path_recursive_chunks_for_entity_and_timeline(&item.entity_path, timeline)
+            else {
+                return;
+            };
+
+            for info in info.recursive_chunks_info.values() {
+                if !info.resolved_time_range.intersects(time_range) {
+                    continue;
+                }
+                for (time, num_events) in info.chunk.num_events_cumulative_per_unique_time(timeline)
+                {
+                    if time_range.contains(time) {
+                        events.increment(time.as_i64(), num_events as u32);
+                    }
+                }
+            }
+        });
+    }
+
+    events
+}
@@ -7,37 +7,52 @@
 #![allow(clippy::unwrap_used)]
 
 mod data_density_graph;
+mod entity_filter;
+mod event_navigation;
 mod paint_ticks;
 mod recursive_chunks_per_timeline_subscriber;
+mod static_lane;
 mod time_axis;
 mod time_control_ui;
+mod time_minimap;
 mod time_ranges_ui;
 mod time_selection_ui;
 
+use std::collections::{BTreeMap, VecDeque};
+
 use egui::emath::Rangef;
 use egui::{pos2, Color32, CursorIcon, NumExt, Painter, PointerButton, Rect, Shape, Ui, Vec2};
+use nohash_hasher::IntMap;
 
-use re_context_menu::{context_menu_ui_for_item, SelectionUpdateBehavior};
+use re_chunk_store::ChunkStoreGeneration;
+use re_context_menu::{context_menu_ui_for_item_with_extra_ui, SelectionUpdateBehavior};
 use re_data_ui::DataUi as _;
 use re_data_ui::{item_ui::guess_instance_path_icon, sorted_component_list_for_ui};
 use re_entity_db::{EntityDb, EntityTree, InstancePath};
 use re_log_types::{
     external::re_types_core::ComponentName, ComponentPath, EntityPath, EntityPathPart,
-    ResolvedTimeRange, TimeInt, TimeReal, TimeType,
+    ResolvedTimeRange, StoreId, StoreKind, TimeInt, TimeReal, TimeType,
 };
 use re_types::blueprint::components::PanelState;
 use re_ui::{list_item, ContextExt as _, DesignTokens, UiExt as _};
 use re_viewer_context::{
-    CollapseScope, HoverHighlight, Item, RecordingConfig, TimeControl, TimeView, UiLayout,
-    ViewerContext,
+    CollapseScope, CollapsedId, HoverHighlight, Item, PlayState, RecordingConfig, SystemCommand,
+    SystemCommandSender as _, TimeControl, TimeView, UiLayout, ViewerContext,
 };
 use re_viewport_blueprint::ViewportBlueprint;
 
+use entity_filter::{EntityFilter, EntityFilterMatches};
 use recursive_chunks_per_timeline_subscriber::PathRecursiveChunksPerTimelineStoreSubscriber;
 use time_axis::TimelineAxis;
 use time_control_ui::TimeControlUi;
 use time_ranges_ui::TimeRangesUi;
 
+/// Entities deeper than this aren't shown in the streams tree.
+const MAX_TREE_DEPTH: usize = EntityPath::DEFAULT_MAX_DEPTH;
+
+/// How many recordings the width of the entity name columns is remembered for.
+const MAX_COL_WIDTHS_PER_STORE: usize = 32;
+
 #[doc(hidden)]
 pub mod __bench {
     pub use crate::data_density_graph::*;
@@ -91,6 +106,15 @@ enum TimePanelSource {
     Blueprint,
 }
 
+impl TimePanelSource {
+    fn store_kind(self) -> StoreKind {
+        match self {
+            Self::Recording => StoreKind::Recording,
+            Self::Blueprint => StoreKind::Blueprint,
+        }
+    }
+}
+
 impl From<TimePanelSource> for egui::Id {
     fn from(source: TimePanelSource) -> Self {
         match source {
@@ -109,8 +133,16 @@ pub struct TimePanel {
     data_density_graph_painter: data_density_graph::DataDensityGraphPainter,
 
     /// Width of the entity name columns previous frame.
+    ///
+    /// Used for recordings that don't have their own width in [`Self::col_width_per_store`] yet.
     prev_col_width: f32,
 
+    /// Width of the entity name columns, for the recordings most recently shown in this panel.
+    ///
+    /// The most recently shown one is last. Capped to [`MAX_COL_WIDTHS_PER_STORE`] entries, so
+    /// that it doesn't grow forever with all the recordings ever opened.
+    col_width_per_store: VecDeque<(StoreId, f32)>,
+
     /// The right side of the entity name column; updated during its painting.
     #[serde(skip)]
     next_col_right: f32,
@@ -124,6 +156,64 @@ pub struct TimePanel {
 
     /// Which source is the time panel controlling
     source: TimePanelSource,
+
+    /// Also show the (read-only) data density of all other timelines below each closed entity.
+    show_other_timelines: bool,
+
+    /// The time control and time axis view of each of the other timelines, regenerated each frame.
+    #[serde(skip)]
+    other_timelines: Vec<(TimeControl, TimeRangesUi)>,
+
+    /// Only show the selected entities (and their descendants) in the streams tree.
+    show_only_selection: bool,
+
+    /// The selected entities to restrict the streams tree to, regenerated each frame.
+    ///
+    /// Empty if the whole tree should be shown.
+    #[serde(skip)]
+    selection_filter: Vec<EntityPath>,
+
+    /// Only show the entities whose path matches this, as typed by the user in the streams tree.
+    ///
+    /// See [`EntityFilter`].
+    #[serde(skip)]
+    entity_filter: String,
+
+    /// What survives [`Self::entity_filter`].
+    ///
+    /// `None` if the whole tree should be shown.
+    #[serde(skip)]
+    entity_filter_matches: Option<EntityFilterMatches>,
+
+    /// The store, and its generation, that [`Self::entity_filter_matches`] was computed on.
+    ///
+    /// Only once either the filter or this changes do the matches need to be recomputed.
+    #[serde(skip)]
+    entity_filter_matches_source: Option<(StoreId, ChunkStoreGeneration)>,
+
+    /// Where to mark rows with static data, if the recording has any. Regenerated each frame.
+    #[serde(skip)]
+    static_lane_x_range: Option<Rangef>,
+
+    /// Which entities have static data in their subtree, for the store and generation it was
+    /// last checked on.
+    ///
+    /// Finding out means walking the whole subtree when there is none.
+    #[serde(skip)]
+    subtree_has_static_data: Option<(StoreId, ChunkStoreGeneration, IntMap<EntityPath, bool>)>,
+
+    /// Components whose rows were explicitly shown (`true`) or hidden (`false`) in the streams tree.
+    ///
+    /// Other components use [`default_component_visibility`].
+    component_visibility: BTreeMap<ComponentName, bool>,
+
+    /// Also show the rows of hidden components, so that they can be shown again.
+    show_hidden_components: bool,
+}
+
+/// Indicator components are hidden by default: they're an implementation detail.
+fn default_component_visibility(component_name: &ComponentName) -> bool {
+    !component_name.is_indicator_component()
 }
 
 impl Default for TimePanel {
@@ -133,10 +223,22 @@ impl Default for TimePanel {
         Self {
             data_density_graph_painter: Default::default(),
             prev_col_width: 400.0,
+            col_width_per_store: Default::default(),
             next_col_right: 0.0,
             time_ranges_ui: Default::default(),
-            time_control_ui: TimeControlUi,
+            time_control_ui: Default::default(),
             source: TimePanelSource::Recording,
+            show_other_timelines: false,
+            other_timelines: Vec::new(),
+            show_only_selection: false,
+            selection_filter: Vec::new(),
+            entity_filter: String::new(),
+            entity_filter_matches: None,
+            entity_filter_matches_source: None,
+            static_lane_x_range: None,
+            subtree_has_static_data: None,
+            component_visibility: Default::default(),
+            show_hidden_components: false,
         }
     }
 }
@@ -157,6 +259,68 @@ impl TimePanel {
         }
     }
 
+    /// Show the data density of all the other timelines below the entities of the current one.
+    pub fn set_show_other_timelines(&mut self, show_other_timelines: bool) {
+        self.show_other_timelines = show_other_timelines;
+    }
+
+    /// Only show the selected entities (and their descendants) in the streams tree.
+    ///
+    /// The whole tree is shown when nothing is selected.
+    pub fn set_show_only_selection(&mut self, show_only_selection: bool) {
+        self.show_only_selection = show_only_selection;
+    }
+
+    /// Only show the entities whose path matches `filter` in the streams tree, along with their
+    /// ancestors.
+    ///
+    /// Plain text matches anywhere in the path, case-insensitively, while an
+    /// [`re_log_types::EntityPathGlob`] like `world/*/points` must match the whole path.
+    pub fn set_entity_filter(&mut self, filter: impl Into<String>) {
+        self.entity_filter = filter.into();
+    }
+
+    /// Should the rows of this component be shown in the streams tree?
+    pub fn is_component_visible(&self, component_name: &ComponentName) -> bool {
+        self.component_visibility
+            .get(component_name)
+            .copied()
+            .unwrap_or_else(|| default_component_visibility(component_name))
+    }
+
+    /// Show or hide the rows of this component, for all entities.
+    pub fn set_component_visible(&mut self, component_name: ComponentName, visible: bool) {
+        if visible == default_component_visibility(&component_name) {
+            self.component_visibility.remove(&component_name);
+        } else {
+            self.component_visibility.insert(component_name, visible);
+        }
+    }
+
+    /// Also show the rows of hidden components (subdued), so that they can be shown again.
+    pub fn set_show_hidden_components(&mut self, show_hidden_components: bool) {
+        self.show_hidden_components = show_hidden_components;
+    }
+
+    /// Width of the entity name columns for the given recording.
+    fn col_width(&self, store_id: &StoreId) -> f32 {
+        self.col_width_per_store
+            .iter()
+            .find(|(id, _)| id == store_id)
+            .map_or(self.prev_col_width, |(_, col_width)| *col_width)
+    }
+
+    fn set_col_width(&mut self, store_id: &StoreId, col_width: f32) {
+        self.prev_col_width = col_width;
+
+        self.col_width_per_store.retain(|(id, _)| id != store_id);
+        self.col_width_per_store
+            .push_back((store_id.clone(), col_width));
+        if self.col_width_per_store.len() > MAX_COL_WIDTHS_PER_STORE {
+            self.col_width_per_store.pop_front();
+        }
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub fn show_panel(
         &mut self,
@@ -211,7 +375,7 @@ impl TimePanel {
             .max_height((window_height - min_top_space).at_least(min_height).round())
             .default_height((0.25 * window_height).clamp(min_height, 250.0).round());
 
-        egui::TopBottomPanel::show_animated_between_inside(
+        let panel_response = egui::TopBottomPanel::show_animated_between_inside(
             ui,
             state.is_expanded(),
             collapsed,
@@ -238,6 +402,23 @@ impl TimePanel {
             },
         );
 
+        if ui.rect_contains_pointer(panel_response.response.rect) {
+            self.time_control_ui.timeline_shortcuts(
+                &mut time_ctrl_after,
+                entity_db.times_per_timeline(),
+                ui.ctx(),
+            );
+        }
+
+        if self.source == TimePanelSource::Recording {
+            event_navigation::handle_event_navigation_shortcuts(
+                ctx,
+                entity_db,
+                &mut time_ctrl_after,
+                ui.ctx(),
+            );
+        }
+
         // Apply time control if there were any changes.
         // This means that if anyone else meanwhile changed the time control, these changes are lost now.
         // At least though we don't overwrite them if we didn't change anything at all.
@@ -273,11 +454,17 @@ impl TimePanel {
                 .response
                 .rect;
 
-            // Draw separator between top bar and the rest:
+            // Draw separator between top bar and the rest.
+            // It is accented for blueprints, so that both panels can't be confused.
+            let separator_stroke =
+                match re_ui::design_tokens().store_kind_accent_color(self.source.store_kind()) {
+                    Some(accent_color) => egui::Stroke::new(2.0, accent_color),
+                    None => ui.visuals().widgets.noninteractive.bg_stroke,
+                };
             ui.painter().hline(
                 0.0..=top_row_rect.right(),
                 top_row_rect.bottom(),
-                ui.visuals().widgets.noninteractive.bg_stroke,
+                separator_stroke,
             );
 
             ui.spacing_mut().scroll.bar_outer_margin = 4.0; // needed, because we have no panel margin on the right side.
@@ -370,20 +557,30 @@ impl TimePanel {
     ) {
         re_tracing::profile_function!();
 
-        //               |timeline            |
-        // ------------------------------------
-        // tree          |streams             |
-        //               |  . .   .   . . .   |
-        //               |            . . . . |
-        //               ▲
-        //               └ tree_max_y (= time_x_left)
+        //               |static|timeline            |
+        // ---------------------------------------------
+        // tree          |  ◆   |streams             |
+        //               |      |  . .   .   . . .   |
+        //               |  ◆   |            . . . . |
+        //               ▲      ▲
+        //               |      └ time_x_left
+        //               └ tree_x_right
+        //
+        // The static lane is only shown if there is any static data.
 
         self.next_col_right = ui.min_rect().left(); // next_col_right will expand during the call
 
-        let time_x_left =
-            (ui.min_rect().left() + self.prev_col_width + ui.spacing().item_spacing.x)
-                .at_most(ui.max_rect().right() - 100.0)
-                .at_least(80.); // cover the empty recording case
+        let col_width = self.col_width(&entity_db.store_id());
+        let tree_x_right = (ui.min_rect().left() + col_width + ui.spacing().item_spacing.x)
+            .at_most(ui.max_rect().right() - 100.0)
+            .at_least(80.); // cover the empty recording case
+
+        let has_static_data = self.subtree_has_static_data(entity_db, &EntityPath::root());
+        self.static_lane_x_range = has_static_data
+            .then(|| Rangef::new(tree_x_right, tree_x_right + static_lane::STATIC_LANE_WIDTH));
+        let time_x_left = self
+            .static_lane_x_range
+            .map_or(tree_x_right, |static_lane_x_range| static_lane_x_range.max);
 
         // Where the time will be shown.
         let time_bg_x_range = Rangef::new(time_x_left, ui.max_rect().right());
@@ -395,36 +592,83 @@ impl TimePanel {
         };
 
         let side_margin = 26.0; // chosen so that the scroll bar looks approximately centered in the default gap
-        self.time_ranges_ui = initialize_time_ranges_ui(
-            entity_db,
-            time_ctrl,
-            Rangef::new(
-                time_fg_x_range.min + side_margin,
-                time_fg_x_range.max - side_margin,
-            ),
-            time_ctrl.time_view(),
+        let time_x_range = Rangef::new(
+            time_fg_x_range.min + side_margin,
+            time_fg_x_range.max - side_margin,
         );
+        self.time_ranges_ui =
+            initialize_time_ranges_ui(entity_db, time_ctrl, time_x_range, time_ctrl.time_view());
+        self.other_timelines = if self.show_other_timelines {
+            let mut other_timelines = entity_db
+                .timelines()
+                .filter(|timeline| *timeline != time_ctrl.timeline())
+                .copied()
+                .collect::<Vec<_>>();
+            re_log_types::sort_timelines_canonical(&mut other_timelines);
+
+            other_timelines
+                .into_iter()
+                .map(|timeline| {
+                    let mut other_time_ctrl = time_ctrl.clone();
+                    other_time_ctrl.set_timeline(timeline);
+                    let other_time_ranges_ui =
+                        initialize_time_ranges_ui(entity_db, &other_time_ctrl, time_x_range, None);
+                    (other_time_ctrl, other_time_ranges_ui)
+                })
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        // When zoomed in, show where we are within the whole timeline.
+        // This goes above the time area, so that it doesn't interfere with its interactions.
+        if time_ctrl.time_view().is_some() {
+            let (_, minimap_rect) =
+                ui.allocate_space(egui::vec2(col_width, time_minimap::MINIMAP_HEIGHT));
+            time_minimap::time_minimap_ui(
+                entity_db,
+                time_ctrl,
+                &self.time_ranges_ui,
+                ui,
+                Rect::from_x_y_ranges(time_x_range, minimap_rect.y_range()),
+            );
+        }
+
         let full_y_range = Rangef::new(ui.min_rect().bottom(), ui.max_rect().bottom());
 
         let timeline_rect = {
             let top = ui.min_rect().bottom();
 
-            let size = egui::vec2(self.prev_col_width, 28.0);
-            ui.allocate_ui_with_layout(size, egui::Layout::top_down(egui::Align::LEFT), |ui| {
-                ui.set_min_size(size);
-                ui.style_mut().wrap_mode = Some(egui::TextWrapMode::Extend);
-                ui.add_space(4.0); // hack to vertically center the text
-                if self.source == TimePanelSource::Blueprint {
-                    ui.strong("Blueprint Streams");
-                } else {
-                    ui.strong("Streams");
-                }
-            })
-            .response
-            .on_hover_text(
-                "A hierarchical view of the paths used during logging.\n\
+            let size = egui::vec2(col_width, 28.0);
+            ui.allocate_ui_with_layout(
+                size,
+                egui::Layout::left_to_right(egui::Align::Center),
+                |ui| {
+                    ui.set_min_size(size);
+                    ui.style_mut().wrap_mode = Some(egui::TextWrapMode::Extend);
+                    let title = if self.source == TimePanelSource::Blueprint {
+                        "Blueprint Streams"
+                    } else {
+                        "Streams"
+                    };
+                    let mut title = egui::RichText::new(title).strong();
+                    if let Some(accent_color) =
+                        re_ui::design_tokens().store_kind_accent_color(self.source.store_kind())
+                    {
+                        title = title.color(accent_color);
+                    }
+                    ui.label(title).on_hover_text(
+                        "A hierarchical view of the paths used during logging.\n\
                         \n\
                         On the right you can see when there was a log event for a stream.",
+                    );
+
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        self.collapse_buttons_ui(entity_db, ui);
+                        self.show_hidden_components_button_ui(ui);
+                        self.entity_filter_ui(ui);
+                    });
+                },
             );
 
             let bottom = ui.min_rect().bottom();
@@ -442,6 +686,15 @@ impl TimePanel {
         let time_bg_area_painter = ui.painter().with_clip_rect(time_bg_area_rect);
         let time_area_painter = ui.painter().with_clip_rect(time_fg_area_rect);
 
+        if let Some(static_lane_x_range) = self.static_lane_x_range {
+            static_lane::static_lane_ui(
+                ui,
+                static_lane_x_range,
+                timeline_rect.y_range(),
+                full_y_range,
+            );
+        }
+
         if let Some(highlighted_range) = time_ctrl.highlighted_range {
             paint_range_highlight(
                 highlighted_range,
@@ -485,6 +738,11 @@ impl TimePanel {
             &time_bg_area_rect,
             &streams_rect,
         );
+        if self.source == TimePanelSource::Recording {
+            time_area_response.context_menu(|ui| {
+                export_time_range_ui(ctx, entity_db, time_ctrl, &self.time_ranges_ui, ui);
+            });
+        }
 
         // Don't draw on top of the time ticks
         let lower_time_area_painter = ui.painter().with_clip_rect(Rect::from_x_y_ranges(
@@ -493,7 +751,7 @@ impl TimePanel {
         ));
 
         // All the entity rows and their data density graphs
-        ui.full_span_scope(0.0..=time_x_left, |ui| {
+        ui.full_span_scope(0.0..=tree_x_right, |ui| {
             list_item::list_item_scope(ui, "streams_tree", |ui| {
                 self.tree_ui(
                     ctx,
@@ -522,7 +780,7 @@ impl TimePanel {
 
             let shadow_y_end = full_y_range.max;
             let rect = egui::Rect::from_x_y_ranges(
-                time_x_left..=(time_x_left + shadow_width),
+                tree_x_right..=(tree_x_right + shadow_width),
                 shadow_y_start..=shadow_y_end,
             );
             ui.draw_shadow_line(rect, egui::Direction::LeftToRight);
@@ -541,7 +799,10 @@ impl TimePanel {
         self.time_ranges_ui.snap_time_control(time_ctrl);
 
         // remember where to show the time for next frame:
-        self.prev_col_width = self.next_col_right - ui.min_rect().left();
+        self.set_col_width(
+            &entity_db.store_id(),
+            self.next_col_right - ui.min_rect().left(),
+        );
     }
 
     // All the entity rows and their data density graphs:
@@ -558,6 +819,27 @@ impl TimePanel {
     ) {
         re_tracing::profile_function!();
 
+        self.selection_filter = if self.show_only_selection {
+            ctx.selection()
+                .iter_items()
+                .filter_map(|item| item.entity_path())
+                .cloned()
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        let entity_filter = EntityFilter::new(&self.entity_filter);
+        let entity_filter_matches_source = Some((entity_db.store_id(), entity_db.generation()));
+        let previous_filter = self.entity_filter_matches.as_ref().map(|m| m.filter());
+        if previous_filter != entity_filter.as_ref()
+            || self.entity_filter_matches_source != entity_filter_matches_source
+        {
+            self.entity_filter_matches = entity_filter
+                .map(|filter| filter.matches_in_tree(entity_db.tree(), MAX_TREE_DEPTH));
+            self.entity_filter_matches_source = entity_filter_matches_source;
+        }
+
         egui::ScrollArea::vertical()
             .auto_shrink([false; 2])
             // We turn off `drag_to_scroll` so that the `ScrollArea` don't steal input from
@@ -605,6 +887,193 @@ impl TimePanel {
             });
     }
 
+    fn collapsed_id(&self, entity_path: &EntityPath) -> CollapsedId {
+        match self.source {
+            TimePanelSource::Recording => CollapseScope::StreamsTree.entity(entity_path.clone()),
+            TimePanelSource::Blueprint => {
+                CollapseScope::BlueprintStreamsTree.entity(entity_path.clone())
+            }
+        }
+    }
+
+    /// Does this entity or any of its children have static data?
+    ///
+    /// Cached per entity, and only checked again once the store changed.
+    fn subtree_has_static_data(&mut self, entity_db: &EntityDb, entity_path: &EntityPath) -> bool {
+        let store_id = entity_db.store_id();
+        let generation = entity_db.generation();
+        let (_, _, per_entity) = match &mut self.subtree_has_static_data {
+            Some(cache) if cache.0 == store_id && cache.1 == generation => cache,
+            cache => cache.insert((store_id, generation, IntMap::default())),
+        };
+
+        *per_entity.entry(entity_path.clone()).or_insert_with(|| {
+            entity_db.subtree_has_static_data(&entity_db.storage_engine(), entity_path)
+        })
+    }
+
+    /// The id holding the collapse state of this entity in the streams tree.
+    ///
+    /// While filtering, the tree uses its own collapse state, so that the usual one is back once
+    /// the filter is cleared.
+    fn tree_collapse_id(&self, entity_path: &EntityPath) -> egui::Id {
+        let id = self.collapsed_id(entity_path).egui_id();
+        if self.entity_filter_matches.is_some() {
+            id.with("entity_filter")
+        } else {
+            id
+        }
+    }
+
+    /// Buttons to expand, collapse, or reset the collapse state of the whole streams tree.
+    fn collapse_buttons_ui(&self, entity_db: &re_entity_db::EntityDb, ui: &mut egui::Ui) {
+        // Laid out right-to-left.
+        let buttons = [
+            (
+                &re_ui::icons::RESET,
+                "Reset collapse state",
+                "Reset all entities to their default collapse state",
+                None,
+            ),
+            (
+                &re_ui::icons::COLLAPSE,
+                "Collapse all",
+                "Collapse all entities",
+                Some(false),
+            ),
+            (
+                &re_ui::icons::EXPAND,
+                "Expand all",
+                "Expand all entities",
+                Some(true),
+            ),
+        ];
+
+        for (icon, label, hover_text, open) in buttons {
+            let response = ui.small_icon_button(icon).on_hover_text(hover_text);
+            response.widget_info(|| {
+                egui::WidgetInfo::labeled(egui::WidgetType::Button, ui.is_enabled(), label)
+            });
+            if response.clicked() {
+                self.set_tree_open(ui.ctx(), entity_db.tree(), open);
+            }
+        }
+    }
+
+    /// Toggles [`Self::show_hidden_components`].
+    fn show_hidden_components_button_ui(&mut self, ui: &mut egui::Ui) {
+        let (icon, hover_text) = if self.show_hidden_components {
+            (&re_ui::icons::VISIBLE, "Stop showing hidden components")
+        } else {
+            (
+                &re_ui::icons::INVISIBLE,
+                "Show hidden components, so that they can be shown again",
+            )
+        };
+
+        let response = ui.small_icon_button(icon).on_hover_text(hover_text);
+        response.widget_info(|| {
+            egui::WidgetInfo::selected(
+                egui::WidgetType::Checkbox,
+                ui.is_enabled(),
+                self.show_hidden_components,
+                "Show hidden components",
+            )
+        });
+        if response.clicked() {
+            self.show_hidden_components = !self.show_hidden_components;
+        }
+    }
+
+    /// Text field for [`Self::entity_filter`], with a button to clear it.
+    fn entity_filter_ui(&mut self, ui: &mut egui::Ui) {
+        if !self.entity_filter.is_empty()
+            && ui
+                .small_icon_button(&re_ui::icons::CLOSE)
+                .on_hover_text("Clear the filter")
+                .clicked()
+        {
+            self.entity_filter.clear();
+        }
+
+        ui.add(
+            egui::TextEdit::singleline(&mut self.entity_filter)
+                .hint_text("Filter entities")
+                .desired_width(ui.available_width().at_most(200.0)),
+        )
+        .on_hover_text(
+            "Only show the entities whose path contains this text (case-insensitive).\n\
+            Use `*` and `**` to match the whole path instead,\n\
+            e.g. `world/*/points` or `**/points`.",
+        );
+    }
+
+    /// Should this entity be shown, given the [`Self::entity_filter`]?
+    fn passes_entity_filter(&self, entity_path: &EntityPath) -> bool {
+        self.entity_filter_matches
+            .as_ref()
+            .map_or(true, |matches| matches.is_shown(entity_path))
+    }
+
+    /// Should the components of this entity be shown, given the [`Self::entity_filter`]?
+    ///
+    /// Entities that are only shown because of their descendants don't show their components.
+    fn passes_entity_filter_with_components(&self, entity_path: &EntityPath) -> bool {
+        self.entity_filter_matches
+            .as_ref()
+            .map_or(true, |matches| matches.is_match(entity_path))
+    }
+
+    /// Should this entity be shown, given the [`Self::selection_filter`]?
+    ///
+    /// Ancestors of the selected entities are shown too, so that they can be reached.
+    fn passes_selection_filter(&self, entity_path: &EntityPath) -> bool {
+        self.selection_filter.is_empty()
+            || self.selection_filter.iter().any(|selected| {
+                entity_path.starts_with(selected) || selected.is_descendant_of(entity_path)
+            })
+    }
+
+    /// Should the components of this entity be shown, given the [`Self::selection_filter`]?
+    fn passes_selection_filter_with_components(&self, entity_path: &EntityPath) -> bool {
+        self.selection_filter.is_empty()
+            || self
+                .selection_filter
+                .iter()
+                .any(|selected| entity_path.starts_with(selected))
+    }
+
+    /// Expand (`Some(true)`) or collapse (`Some(false)`) an entity and all its descendants.
+    ///
+    /// With `None`, their stored state is forgotten, so that they go back to their default.
+    fn set_tree_open(&self, egui_ctx: &egui::Context, tree: &EntityTree, open: Option<bool>) {
+        let id = self.tree_collapse_id(&tree.path);
+        match open {
+            Some(open) => {
+                let mut collapsing_state =
+                    egui::collapsing_header::CollapsingState::load_with_default_open(
+                        egui_ctx, id, false,
+                    );
+                collapsing_state.set_open(open);
+                collapsing_state.store(egui_ctx);
+            }
+            None => {
+                if let Some(collapsing_state) =
+                    egui::collapsing_header::CollapsingState::load(egui_ctx, id)
+                {
+                    collapsing_state.remove(egui_ctx);
+                }
+            }
+        }
+
+        // Deeper entities aren't shown anyway.
+        if tree.path.len() < MAX_TREE_DEPTH {
+            for child in tree.children.values() {
+                self.set_tree_open(egui_ctx, child, open);
+            }
+        }
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn show_tree(
         &mut self,
@@ -636,7 +1105,15 @@ impl TimePanel {
             show_root_as.to_owned()
         };
 
-        let default_open = tree.path.len() <= 1 && !tree.is_leaf();
+        // Highlight whatever matches the entity filter.
+        let text: egui::WidgetText = match self
+            .entity_filter_matches
+            .as_ref()
+            .and_then(|matches| matches.filter().match_range(&text))
+        {
+            Some(match_range) => highlighted_text(ui, &text, match_range),
+            None => text.into(),
+        };
 
         let item = TimePanelItem::entity_path(tree.path.clone());
         let is_selected = ctx.selection().contains_item(&item.to_item());
@@ -659,12 +1136,13 @@ impl TimePanel {
 
         // Globally unique id - should only be one of these in view at one time.
         // We do this so that we can support "collapse/expand all" command.
-        let id = egui::Id::new(match self.source {
-            TimePanelSource::Recording => CollapseScope::StreamsTree.entity(tree.path.clone()),
-            TimePanelSource::Blueprint => {
-                CollapseScope::BlueprintStreamsTree.entity(tree.path.clone())
-            }
-        });
+        let id = self.tree_collapse_id(&tree.path);
+
+        let default_open = match &self.entity_filter_matches {
+            // While filtering, expand towards the matches.
+            Some(matches) => matches.has_matching_descendant(&tree.path),
+            None => tree.path.len() <= 1 && !tree.is_leaf(),
+        };
 
         let list_item::ShowCollapsingResponse {
             item_response: response,
@@ -720,15 +1198,24 @@ impl TimePanel {
             }
         }
 
-        context_menu_ui_for_item(
+        context_menu_ui_for_item_with_extra_ui(
             ctx,
             viewport_blueprint,
             &item.to_item(),
             &response,
             SelectionUpdateBehavior::UseSelection,
+            |ui| {
+                if self.source == TimePanelSource::Recording {
+                    clear_data_ui(ctx, entity_db, time_ctrl, &tree.path, ui);
+                }
+            },
         );
         ctx.handle_select_hover_drag_interactions(&response, item.to_item(), true);
 
+        if response.double_clicked() {
+            self.zoom_to_entity(entity_db, time_ctrl, &tree.path);
+        }
+
         let is_closed = body_response.is_none();
         let response_rect = response.rect;
         self.next_col_right = self.next_col_right.max(response_rect.right());
@@ -743,6 +1230,21 @@ impl TimePanel {
 
         // ----------------------------------------------
 
+        // Open entities only mark their own static data: their children have their own rows.
+        if let Some(static_lane_x_range) = self.static_lane_x_range {
+            let has_static_data = if is_closed {
+                self.subtree_has_static_data(entity_db, &tree.path)
+            } else {
+                entity_db
+                    .storage_engine()
+                    .store()
+                    .entity_has_static_data(&tree.path)
+            };
+            if is_visible && has_static_data {
+                static_lane::paint_static_marker(ui, static_lane_x_range, response_rect.y_range());
+            }
+        }
+
         // show the data in the time area:
         let tree_has_data_in_current_timeline = entity_db.subtree_has_data_on_timeline(
             &entity_db.storage_engine(),
@@ -771,6 +1273,97 @@ impl TimePanel {
                 );
             }
         }
+
+        if is_closed {
+            self.other_timelines_ui(
+                ctx,
+                entity_db,
+                db,
+                time_area_response,
+                time_area_painter,
+                &item,
+                ui,
+            );
+        }
+    }
+
+    /// Zoom the time view to tightly frame the data of `entity_path` and its children.
+    fn zoom_to_entity(
+        &self,
+        entity_db: &re_entity_db::EntityDb,
+        time_ctrl: &mut TimeControl,
+        entity_path: &EntityPath,
+    ) {
+        let Some(time_view) = subtree_time_view(
+            entity_db,
+            time_ctrl.timeline(),
+            entity_path,
+            &self.time_ranges_ui.x_range(),
+        ) else {
+            return;
+        };
+
+        // A single point in time can't be zoomed to, so we just center on it.
+        let time_view = if 0.0 < time_view.time_spanned {
+            Some(time_view)
+        } else {
+            self.time_ranges_ui.pan_to_center(time_view.min)
+        };
+
+        if let Some(time_view) = time_view {
+            time_ctrl.set_time_view(time_view);
+        }
+    }
+
+    /// Read-only density rows for each of the other timelines, stacked below a closed entity.
+    #[allow(clippy::too_many_arguments)]
+    fn other_timelines_ui(
+        &mut self,
+        ctx: &ViewerContext<'_>,
+        entity_db: &re_entity_db::EntityDb,
+        db: &re_entity_db::EntityDb,
+        time_area_response: &egui::Response,
+        time_area_painter: &egui::Painter,
+        item: &TimePanelItem,
+        ui: &mut egui::Ui,
+    ) {
+        for (other_time_ctrl, other_time_ranges_ui) in &self.other_timelines {
+            let timeline = other_time_ctrl.timeline();
+            if !entity_db.subtree_has_data_on_timeline(
+                &entity_db.storage_engine(),
+                timeline,
+                &item.entity_path,
+            ) {
+                continue;
+            }
+
+            let response = ui.list_item().interactive(false).show_hierarchical(
+                ui,
+                list_item::LabelContent::new(timeline.name().as_str())
+                    .subdued(true)
+                    .truncate(false),
+            );
+
+            let response_rect = response.rect;
+            self.next_col_right = self.next_col_right.max(response_rect.right());
+
+            let row_rect =
+                Rect::from_x_y_ranges(time_area_response.rect.x_range(), response_rect.y_range());
+            if ui.is_rect_visible(row_rect) {
+                data_density_graph::data_density_graph_ui(
+                    &mut self.data_density_graph_painter,
+                    ctx,
+                    other_time_ctrl,
+                    db,
+                    time_area_painter,
+                    ui,
+                    other_time_ranges_ui,
+                    row_rect,
+                    item,
+                    false,
+                );
+            }
+        }
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -785,19 +1378,42 @@ impl TimePanel {
         tree: &EntityTree,
         ui: &mut egui::Ui,
     ) {
-        for (last_component, child) in &tree.children {
-            self.show_tree(
-                ctx,
-                viewport_blueprint,
-                entity_db,
-                time_ctrl,
-                time_area_response,
-                time_area_painter,
-                Some(last_component),
-                child,
-                ui,
-                "/",
-            );
+        // `show_tree` and `show_children` recurse into each other, so absurdly deep trees
+        // (e.g. from a buggy logger) would overflow the stack.
+        if MAX_TREE_DEPTH <= tree.path.len() && !tree.children.is_empty() {
+            ui.list_item()
+                .interactive(false)
+                .show_hierarchical(ui, list_item::LabelContent::new("…").subdued(true))
+                .on_hover_text(format!(
+                    "Entities more than {MAX_TREE_DEPTH} levels deep are not shown"
+                ));
+        } else {
+            for (last_component, child) in &tree.children {
+                if !self.passes_selection_filter(&child.path)
+                    || !self.passes_entity_filter(&child.path)
+                {
+                    continue;
+                }
+
+                self.show_tree(
+                    ctx,
+                    viewport_blueprint,
+                    entity_db,
+                    time_ctrl,
+                    time_area_response,
+                    time_area_painter,
+                    Some(last_component),
+                    child,
+                    ui,
+                    "/",
+                );
+            }
+        }
+
+        if !self.passes_selection_filter_with_components(&tree.path)
+            || !self.passes_entity_filter_with_components(&tree.path)
+        {
+            return;
         }
 
         let engine = entity_db.storage_engine();
@@ -806,6 +1422,11 @@ impl TimePanel {
         // If this is an entity:
         if let Some(components) = store.all_components_for_entity(&tree.path) {
             for component_name in sorted_component_list_for_ui(components.iter()) {
+                let is_component_visible = self.is_component_visible(&component_name);
+                if !is_component_visible && !self.show_hidden_components {
+                    continue;
+                }
+
                 let is_static = store.entity_has_static_component(&tree.path, &component_name);
 
                 let component_path = ComponentPath::new(tree.path.clone(), component_name);
@@ -845,16 +1466,37 @@ impl TimePanel {
                             } else {
                                 &re_ui::icons::COMPONENT_TEMPORAL
                             })
+                            .subdued(!is_component_visible)
                             .truncate(false),
                     );
 
-                context_menu_ui_for_item(
+                let mut toggle_visibility = false;
+                context_menu_ui_for_item_with_extra_ui(
                     ctx,
                     viewport_blueprint,
                     &item.to_item(),
                     &response,
                     SelectionUpdateBehavior::UseSelection,
+                    |ui| {
+                        let label = if is_component_visible {
+                            "Hide component"
+                        } else {
+                            "Show component"
+                        };
+                        if ui
+                            .button(label)
+                            .on_hover_text("In the streams of all entities")
+                            .clicked()
+                        {
+                            toggle_visibility = true;
+                            ui.close_menu();
+                        }
+                        ui.separator();
+                    },
                 );
+                if toggle_visibility {
+                    self.set_component_visible(component_name, !is_component_visible);
+                }
                 ctx.handle_select_hover_drag_interactions(&response, item.to_item(), false);
 
                 let response_rect = response.rect;
@@ -919,6 +1561,15 @@ impl TimePanel {
                 );
 
                 let is_visible = ui.is_rect_visible(full_width_rect);
+                if let Some(static_lane_x_range) = self.static_lane_x_range {
+                    if is_visible && is_static {
+                        static_lane::paint_static_marker(
+                            ui,
+                            static_lane_x_range,
+                            response_rect.y_range(),
+                        );
+                    }
+                }
                 if is_visible && component_has_data_in_current_timeline {
                     // show the data in the time area:
                     let row_rect = Rect::from_x_y_ranges(
@@ -951,7 +1602,7 @@ impl TimePanel {
     }
 
     fn top_row_ui(
-        &self,
+        &mut self,
         ctx: &ViewerContext<'_>,
         entity_db: &re_entity_db::EntityDb,
         ui: &mut egui::Ui,
@@ -976,7 +1627,9 @@ impl TimePanel {
                         ui,
                     );
 
-                    current_time_ui(ctx, ui, time_ctrl);
+                    current_time_ui(ctx, entity_db, ui, time_ctrl);
+                    self.other_timelines_toggle_ui(entity_db, ui);
+                    self.selection_filter_toggle_ui(ui);
 
                     ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
                         help_button(ui);
@@ -993,11 +1646,83 @@ impl TimePanel {
                 .timeline_selector_ui(time_ctrl, times_per_timeline, ui);
             self.time_control_ui.playback_speed_ui(time_ctrl, ui);
             self.time_control_ui.fps_ui(time_ctrl, ui);
-            current_time_ui(ctx, ui, time_ctrl);
+            current_time_ui(ctx, entity_db, ui, time_ctrl);
+            self.other_timelines_toggle_ui(entity_db, ui);
+            self.selection_filter_toggle_ui(ui);
+        }
+    }
+
+    fn other_timelines_toggle_ui(&mut self, entity_db: &re_entity_db::EntityDb, ui: &mut egui::Ui) {
+        if entity_db.timelines().len() < 2 {
+            return;
         }
+
+        ui.re_checkbox(&mut self.show_other_timelines, "All timelines")
+            .on_hover_text(
+                "Also show the data density of all the other timelines below each collapsed entity.\n\
+                Only the current timeline can be interacted with.",
+            );
+    }
+
+    fn selection_filter_toggle_ui(&mut self, ui: &mut egui::Ui) {
+        ui.re_checkbox(&mut self.show_only_selection, "Only selection")
+            .on_hover_text(
+                "Only show the selected entities, their children and their components.\n\
+                Everything is shown when nothing is selected.",
+            );
+    }
+}
+
+/// Buttons to drop the data logged to an entity and all its children from the recording.
+///
+/// Only offered in the streams tree, where the data being dropped is what is shown.
+fn clear_data_ui(
+    ctx: &ViewerContext<'_>,
+    entity_db: &EntityDb,
+    time_ctrl: &TimeControl,
+    entity_path: &EntityPath,
+    ui: &mut egui::Ui,
+) {
+    let mut command = None;
+    if ui.button("Clear all data under this path").clicked() {
+        command = Some(SystemCommand::DropEntity(
+            entity_db.store_id(),
+            entity_path.clone(),
+        ));
+    }
+    if ui.button("Clear events on current timeline only").clicked() {
+        command = Some(SystemCommand::DropEntityOnTimeline {
+            store_id: entity_db.store_id(),
+            entity_path: entity_path.clone(),
+            timeline: *time_ctrl.timeline(),
+        });
+    }
+    ui.separator();
+
+    if let Some(command) = command {
+        ctx.command_sender.send_system(command);
+        ui.close_menu();
     }
 }
 
+/// `text`, with the given byte range highlighted.
+fn highlighted_text(ui: &Ui, text: &str, highlighted: std::ops::Range<usize>) -> egui::WidgetText {
+    let font_id = egui::TextStyle::Body.resolve(ui.style());
+
+    // Leave the color to the list item, which knows about hover and selection.
+    let normal = egui::TextFormat::simple(font_id, Color32::PLACEHOLDER);
+    let highlight = egui::TextFormat {
+        background: ui.visuals().selection.bg_fill,
+        ..normal.clone()
+    };
+
+    let mut job = egui::text::LayoutJob::default();
+    job.append(&text[..highlighted.start], 0.0, normal.clone());
+    job.append(&text[highlighted.clone()], 0.0, highlight);
+    job.append(&text[highlighted.end..], 0.0, normal);
+    job.into()
+}
+
 /// Draw the hovered/selected highlight background for a timeline row.
 fn highlight_timeline_row(
     ui: &Ui,
@@ -1100,7 +1825,7 @@ fn collapsed_time_marker_and_time(
         }
     }
 
-    current_time_ui(ctx, ui, time_ctrl);
+    current_time_ui(ctx, entity_db, ui, time_ctrl);
 }
 
 fn paint_range_highlight(
@@ -1134,18 +1859,35 @@ fn help_button(ui: &mut egui::Ui) {
         Drag main area to pan.\n\
         Zoom: Ctrl/cmd + scroll, or drag up/down with secondary mouse button.\n\
         Double-click to reset view.\n\
+        Right-click to export the loop selection or visible range as a new recording.\n\
         \n\
-        Press the space bar to play/pause.",
+        Press the space bar to play/pause.\n\
+        Press Tab or Shift+Tab to switch to the next or previous timeline.\n\
+        Press , or . to jump to the previous or next event of the selected entity or component.",
     );
 }
 
-fn current_time_ui(ctx: &ViewerContext<'_>, ui: &mut egui::Ui, time_ctrl: &mut TimeControl) {
+fn current_time_ui(
+    ctx: &ViewerContext<'_>,
+    entity_db: &re_entity_db::EntityDb,
+    ui: &mut egui::Ui,
+    time_ctrl: &mut TimeControl,
+) {
     if let Some(time_int) = time_ctrl.time_int() {
         let time_type = time_ctrl.time_type();
         match time_type {
             re_log_types::TimeType::Time => {
                 // TODO(#7653): parse time stamps
                 ui.monospace(time_type.format(time_int, ctx.app_options.time_zone));
+
+                if ctx.app_options.show_time_since_start {
+                    if let Some(since_start) =
+                        time_since_start_text(entity_db, time_ctrl.timeline(), time_int)
+                    {
+                        ui.monospace(since_start)
+                            .on_hover_text("Time since the first event on this timeline");
+                    }
+                }
             }
             re_log_types::TimeType::Sequence => {
                 // NOTE: egui uses `f64` for all numbers internally, so we get precision problems if the integer gets too big.
@@ -1169,6 +1911,23 @@ fn current_time_ui(ctx: &ViewerContext<'_>, ui: &mut egui::Ui, time_ctrl: &mut T
     }
 }
 
+/// How long after the first event on `timeline` `time` is, e.g. `+01:02.500`.
+///
+/// `None` for sequence timelines, or if there is no data on the timeline.
+fn time_since_start_text(
+    entity_db: &re_entity_db::EntityDb,
+    timeline: &re_log_types::Timeline,
+    time: TimeInt,
+) -> Option<String> {
+    if timeline.typ() != TimeType::Time {
+        return None;
+    }
+    let start = entity_db.time_range_for(timeline)?.min();
+    Some(re_format::format_elapsed_ns(
+        time.as_i64().saturating_sub(start.as_i64()),
+    ))
+}
+
 // ----------------------------------------------------------------------------
 
 fn initialize_time_ranges_ui(
@@ -1222,6 +1981,45 @@ fn view_everything(x_range: &Rangef, timeline_axis: &TimelineAxis) -> TimeView {
     }
 }
 
+/// Find a nice view of all the data logged to `entity_path` and its children on `timeline`.
+///
+/// Returns `None` if there is no such data.
+fn subtree_time_view(
+    entity_db: &re_entity_db::EntityDb,
+    timeline: &re_log_types::Timeline,
+    entity_path: &EntityPath,
+    x_range: &Rangef,
+) -> Option<TimeView> {
+    re_tracing::profile_function!();
+
+    let times = PathRecursiveChunksPerTimelineStoreSubscriber::access(
+        &entity_db.store_id(),
+        |subscriber| {
+            let mut times = re_entity_db::TimeHistogram::default();
+            let chunks =
+                subscriber.path_recursive_chunks_for_entity_and_timeline(entity_path, timeline);
+            for info in chunks
+                .iter()
+                .flat_map(|chunks| chunks.recursive_chunks_info.values())
+            {
+                if let Some(time_column) = info.chunk.timelines().get(timeline) {
+                    for time in time_column.times() {
+                        times.increment(time.as_i64(), 1);
+                    }
+                }
+            }
+            times
+        },
+    )?;
+
+    if times.is_empty() {
+        return None;
+    }
+
+    let timeline_axis = TimelineAxis::new(timeline.typ(), &times);
+    Some(view_everything(x_range, &timeline_axis))
+}
+
 /// Visually separate the different time segments
 fn paint_time_ranges_gaps(
     time_ranges_ui: &TimeRangesUi,
@@ -1361,6 +2159,43 @@ fn paint_time_ranges_gaps(
     }
 }
 
+/// Context menu button to copy the loop selection, or else the visible time range, into a new recording.
+fn export_time_range_ui(
+    ctx: &ViewerContext<'_>,
+    entity_db: &re_entity_db::EntityDb,
+    time_ctrl: &TimeControl,
+    time_ranges_ui: &TimeRangesUi,
+    ui: &mut egui::Ui,
+) {
+    let (label, time_range) = if let Some(selection) = time_ctrl.loop_selection() {
+        (
+            "Export loop selection as new recording",
+            ResolvedTimeRange::new(selection.min.floor(), selection.max.ceil()),
+        )
+    } else {
+        (
+            "Export visible time range as new recording",
+            time_ranges_ui.visible_time_range(),
+        )
+    };
+
+    let response = ui.button(label).on_hover_text(format!(
+        "Create a new recording with only the data in {}",
+        time_ctrl
+            .time_type()
+            .format_range(time_range, ctx.app_options.time_zone)
+    ));
+    if response.clicked() {
+        ctx.command_sender
+            .send_system(SystemCommand::ExportTimeRangeAsRecording {
+                store_id: entity_db.store_id(),
+                timeline: *time_ctrl.timeline(),
+                time_range,
+            });
+        ui.close_menu();
+    }
+}
+
 /// Returns a scroll delta
 #[must_use]
 fn interact_with_streams_rect(
@@ -1464,6 +2299,13 @@ fn time_marker_ui(
 
                 is_hovering_time_cursor = response.hovered();
 
+                let is_following = time_ctrl.play_state() == PlayState::Following;
+                let response = if is_following {
+                    response.on_hover_text("Following the latest data. Drag to stop following.")
+                } else {
+                    response
+                };
+
                 if response.dragged() {
                     if let Some(pointer_pos) = pointer_pos {
                         if let Some(time) = time_ranges_ui.time_from_x_f32(pointer_pos.x) {
@@ -1476,12 +2318,18 @@ fn time_marker_ui(
                     }
                 }
 
-                ui.paint_time_cursor(
-                    time_area_painter,
-                    &response,
-                    x,
-                    Rangef::new(timeline_rect.top(), ui.max_rect().bottom()),
-                );
+                let y_range = Rangef::new(timeline_rect.top(), ui.max_rect().bottom());
+                if is_following && !response.dragged() {
+                    // Make it obvious that the cursor is pinned to the latest data:
+                    ui.paint_time_cursor_with_stroke(
+                        time_area_painter,
+                        ui.visuals().selection.stroke,
+                        x,
+                        y_range,
+                    );
+                } else {
+                    ui.paint_time_cursor(time_area_painter, &response, x, y_range);
+                }
             }
         }
     }
@@ -1543,3 +2391,171 @@ fn time_marker_ui(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use re_log_types::{StoreId, StoreKind};
+
+    use super::{subtree_time_view, time_since_start_text, TimePanel};
+
+    #[test]
+    fn test_col_width_per_recording() {
+        let mut panel = TimePanel::default();
+        let rec_a = StoreId::random(StoreKind::Recording);
+        let rec_b = StoreId::random(StoreKind::Recording);
+
+        panel.set_col_width(&rec_a, 150.0);
+        panel.set_col_width(&rec_b, 600.0);
+        assert_eq!(panel.col_width(&rec_a), 150.0);
+        assert_eq!(panel.col_width(&rec_b), 600.0);
+
+        // New recordings start out with whatever was used last:
+        let rec_c = StoreId::random(StoreKind::Recording);
+        assert_eq!(panel.col_width(&rec_c), 600.0);
+
+        // Survives a round-trip through the app state:
+        let panel: TimePanel = ron::from_str(&ron::to_string(&panel).unwrap()).unwrap();
+        assert_eq!(panel.col_width(&rec_a), 150.0);
+        assert_eq!(panel.col_width(&rec_b), 600.0);
+    }
+
+    #[test]
+    fn test_col_width_from_single_value() {
+        // What was stored before the width was remembered per recording:
+        let panel: TimePanel = ron::from_str("(prev_col_width: 250.0)").unwrap();
+        assert_eq!(
+            panel.col_width(&StoreId::random(StoreKind::Recording)),
+            250.0
+        );
+    }
+
+    #[test]
+    fn test_col_width_per_store_is_capped() {
+        let mut panel = TimePanel::default();
+        let first = StoreId::random(StoreKind::Recording);
+        panel.set_col_width(&first, 150.0);
+
+        for _ in 0..super::MAX_COL_WIDTHS_PER_STORE - 1 {
+            panel.set_col_width(&StoreId::random(StoreKind::Recording), 600.0);
+        }
+        assert_eq!(panel.col_width(&first), 150.0);
+
+        // Showing it again makes it the most recently used one:
+        panel.set_col_width(&first, 150.0);
+        let second = panel.col_width_per_store[0].0.clone();
+        panel.set_col_width(&StoreId::random(StoreKind::Recording), 600.0);
+        assert_eq!(panel.col_width(&first), 150.0);
+        assert!(panel
+            .col_width_per_store
+            .iter()
+            .all(|(store_id, _)| store_id != &second));
+
+        // Once too many other recordings were shown, it is forgotten:
+        for _ in 0..super::MAX_COL_WIDTHS_PER_STORE {
+            panel.set_col_width(&StoreId::random(StoreKind::Recording), 600.0);
+        }
+        assert_eq!(
+            panel.col_width_per_store.len(),
+            super::MAX_COL_WIDTHS_PER_STORE
+        );
+        assert_eq!(panel.col_width(&first), 600.0);
+    }
+
+    #[test]
+    fn test_subtree_time_view() {
+        use std::sync::Arc;
+
+        use re_chunk_store::{Chunk, RowId};
+        use re_log_types::{example_components::MyPoint, TimeReal, Timeline};
+
+        use crate::PathRecursiveChunksPerTimelineStoreSubscriber;
+
+        PathRecursiveChunksPerTimelineStoreSubscriber::ensure_registered();
+
+        let frame_nr = Timeline::new_sequence("frame_nr");
+        let mut entity_db = re_entity_db::EntityDb::new(StoreId::random(StoreKind::Recording));
+        for (entity_path, frames) in [
+            ("parent/child", vec![40_i64, 42, 45]),
+            ("parent/other_child", vec![41_i64, 43]),
+            ("other", vec![0_i64, 1_000]),
+        ] {
+            let mut builder = Chunk::builder(entity_path.into());
+            for frame in frames {
+                builder = builder.with_component_batches(
+                    RowId::new(),
+                    [(frame_nr, frame)],
+                    [&MyPoint::from_iter(0..1) as _],
+                );
+            }
+            entity_db
+                .add_chunk(&Arc::new(builder.build().unwrap()))
+                .unwrap();
+        }
+
+        let x_range = egui::Rangef::new(0.0, 500.0);
+
+        let time_view =
+            subtree_time_view(&entity_db, &frame_nr, &"parent".into(), &x_range).unwrap();
+        assert_eq!(time_view.min, TimeReal::from(40_i64));
+        assert_eq!(time_view.time_spanned, 5.0);
+
+        let time_view =
+            subtree_time_view(&entity_db, &frame_nr, &"parent/child".into(), &x_range).unwrap();
+        assert_eq!(time_view.min, TimeReal::from(40_i64));
+        assert_eq!(time_view.time_spanned, 5.0);
+
+        assert!(subtree_time_view(&entity_db, &frame_nr, &"nothing".into(), &x_range).is_none());
+        assert!(subtree_time_view(
+            &entity_db,
+            &Timeline::new_sequence("other_timeline"),
+            &"parent".into(),
+            &x_range
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn test_time_since_start_text() {
+        use std::sync::Arc;
+
+        use re_chunk_store::{Chunk, RowId};
+        use re_log_types::{example_components::MyPoint, TimeInt, TimeZone, Timeline};
+
+        let log_time = Timeline::new_temporal("log_time");
+        let start = TimeInt::new_temporal(1_646_087_742_000_000_000);
+        let later = TimeInt::new_temporal(start.as_i64() + 62_500_000_000);
+
+        let mut entity_db = re_entity_db::EntityDb::new(StoreId::random(StoreKind::Recording));
+        for time in [start, later] {
+            let chunk = Chunk::builder("points".into())
+                .with_component_batches(
+                    RowId::new(),
+                    [(log_time, time)],
+                    [&MyPoint::from_iter(0..1) as _],
+                )
+                .build()
+                .unwrap();
+            entity_db.add_chunk(&Arc::new(chunk)).unwrap();
+        }
+
+        assert_eq!(
+            log_time.format_time(later, TimeZone::Utc),
+            "2022-02-28 22:36:44.500Z"
+        );
+        assert_eq!(
+            time_since_start_text(&entity_db, &log_time, later).as_deref(),
+            Some("+01:02.500")
+        );
+        assert_eq!(
+            time_since_start_text(&entity_db, &log_time, start).as_deref(),
+            Some("+00:00.000")
+        );
+
+        let frame_nr = Timeline::new_sequence("frame_nr");
+        assert_eq!(
+            time_since_start_text(&entity_db, &frame_nr, later),
+            None,
+            "only for timestamps"
+        );
+    }
+}
@@ -0,0 +1,62 @@
+//! A narrow lane between the streams tree and the time area, marking which rows have static data.
+//!
+//! Static data isn't logged at any particular time, so it never shows up in the data density
+//! graphs: without this lane there is no telling static rows from empty ones at a glance.
+
+use egui::{emath::Rangef, Rect};
+
+use re_ui::DesignTokens;
+
+/// Width of the static lane, when shown.
+pub const STATIC_LANE_WIDTH: f32 = 40.0;
+
+/// The background and header of the lane.
+///
+/// `header_y_range` is the height of the time ticks, `full_y_range` of the whole time area.
+pub fn static_lane_ui(
+    ui: &egui::Ui,
+    lane_x_range: Rangef,
+    header_y_range: Rangef,
+    full_y_range: Rangef,
+) {
+    let lane_rect = Rect::from_x_y_ranges(lane_x_range, full_y_range);
+    ui.painter()
+        .rect_filled(lane_rect, 0.0, ui.visuals().faint_bg_color);
+    ui.painter().vline(
+        lane_x_range.max,
+        full_y_range,
+        ui.visuals().widgets.noninteractive.bg_stroke,
+    );
+
+    let header_rect = Rect::from_x_y_ranges(lane_x_range, header_y_range);
+    ui.painter().text(
+        header_rect.center(),
+        egui::Align2::CENTER_CENTER,
+        "Static",
+        egui::TextStyle::Small.resolve(ui.style()),
+        ui.visuals().weak_text_color(),
+    );
+
+    let response = ui.interact(
+        header_rect,
+        ui.id().with("static_lane"),
+        egui::Sense::hover(),
+    );
+    response.widget_info(|| {
+        egui::WidgetInfo::labeled(egui::WidgetType::Label, ui.is_enabled(), "Static")
+    });
+    response.on_hover_text("Rows with static data, which isn't logged at any particular time");
+}
+
+/// Marks the row spanning `row_y_range` as having static data.
+pub fn paint_static_marker(ui: &egui::Ui, lane_x_range: Rangef, row_y_range: Rangef) {
+    let rect = Rect::from_center_size(
+        egui::pos2(lane_x_range.center(), row_y_range.center()),
+        DesignTokens::small_icon_size(),
+    );
+
+    re_ui::icons::COMPONENT_STATIC
+        .as_image()
+        .tint(ui.visuals().widgets.inactive.fg_stroke.color)
+        .paint_at(ui, rect);
+}
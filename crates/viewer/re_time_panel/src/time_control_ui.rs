@@ -1,18 +1,95 @@
+use std::collections::BTreeSet;
+
 use egui::NumExt as _;
 
 use re_entity_db::TimesPerTimeline;
-use re_log_types::TimeType;
+use re_log_types::{sort_timelines_canonical, TimeType, Timeline, TimelineName};
 use re_ui::{list_item, UiExt as _};
 
-use re_viewer_context::{Looping, PlayState, TimeControl};
+use re_viewer_context::{Looping, PlayState, PlaybackDirection, TimeControl};
 
 #[derive(serde::Deserialize, serde::Serialize, Default)]
-pub struct TimeControlUi;
+#[serde(default)]
+pub struct TimeControlUi {
+    /// The order in which timelines are listed in the timeline selector.
+    ///
+    /// Timelines not in here (e.g. new ones) are listed last, in their natural order.
+    timeline_order: Vec<TimelineName>,
+
+    /// Timelines hidden from the timeline selector.
+    ///
+    /// The active timeline is always shown, even if it is in here.
+    hidden_timelines: BTreeSet<TimelineName>,
+}
 
 impl TimeControlUi {
-    #[allow(clippy::unused_self)]
-    pub fn timeline_selector_ui(
+    /// All the given timelines, in the order chosen by the user.
+    fn ordered_timelines<'a>(
         &self,
+        timelines: impl Iterator<Item = &'a Timeline>,
+    ) -> Vec<Timeline> {
+        let mut timelines = timelines.copied().collect::<Vec<_>>();
+        sort_timelines_canonical(&mut timelines);
+        // Stable sort, so that unordered timelines keep their canonical order.
+        timelines.sort_by_key(|timeline| {
+            self.timeline_order
+                .iter()
+                .position(|name| name == timeline.name())
+                .unwrap_or(usize::MAX)
+        });
+        timelines
+    }
+
+    /// The timelines to show in the timeline selector, in order: all but the hidden ones,
+    /// except for the active one which is always shown.
+    fn visible_timelines<'a>(
+        &self,
+        active_timeline: &Timeline,
+        timelines: impl Iterator<Item = &'a Timeline>,
+    ) -> Vec<Timeline> {
+        let mut timelines = self.ordered_timelines(timelines);
+        timelines.retain(|timeline| {
+            timeline == active_timeline || !self.hidden_timelines.contains(timeline.name())
+        });
+        timelines
+    }
+
+    fn set_timeline_hidden(&mut self, timeline: TimelineName, hidden: bool) {
+        if hidden {
+            self.hidden_timelines.insert(timeline);
+        } else {
+            self.hidden_timelines.remove(&timeline);
+        }
+    }
+
+    /// Move the timeline at index `from` of `ordered_timelines` to index `to`.
+    fn move_timeline(&mut self, ordered_timelines: &[Timeline], from: usize, to: usize) {
+        if from == to || ordered_timelines.len() <= from.max(to) {
+            return;
+        }
+
+        // Remember the whole order, so that it sticks even once new timelines show up.
+        let mut order = ordered_timelines
+            .iter()
+            .map(|timeline| *timeline.name())
+            .collect::<Vec<_>>();
+        let moved = order.remove(from);
+        order.insert(to, moved);
+
+        // Keep around timelines of other recordings, at the end.
+        let others = self
+            .timeline_order
+            .iter()
+            .filter(|name| !order.contains(name))
+            .copied()
+            .collect::<Vec<_>>();
+        order.extend(others);
+
+        self.timeline_order = order;
+    }
+
+    pub fn timeline_selector_ui(
+        &mut self,
         time_control: &mut TimeControl,
         times_per_timeline: &TimesPerTimeline,
         ui: &mut egui::Ui,
@@ -25,26 +102,33 @@ impl TimeControlUi {
             ui.visuals_mut().widgets.hovered.expansion = 0.0;
             ui.visuals_mut().widgets.open.expansion = 0.0;
 
-            egui::ComboBox::from_id_salt("timeline")
+            let response = egui::ComboBox::from_id_salt("timeline")
                 .selected_text(time_control.timeline().name().as_str())
                 .show_ui(ui, |ui| {
-                    for timeline in times_per_timeline.timelines() {
+                    let visible_timelines = self
+                        .visible_timelines(time_control.timeline(), times_per_timeline.timelines());
+                    for timeline in visible_timelines {
                         if ui
                             .selectable_label(
-                                timeline == time_control.timeline(),
+                                &timeline == time_control.timeline(),
                                 timeline.name().as_str(),
                             )
                             .clicked()
                         {
-                            time_control.set_timeline(*timeline);
+                            time_control.set_timeline(timeline);
                         }
                     }
                 })
-                .response
-                .on_hover_ui(|ui| {
-                    list_item::list_item_scope(ui, "tooltip", |ui| {
-                        ui.markdown_ui(
-                            r"
+                .response;
+
+            response.context_menu(|ui| {
+                self.timeline_visibility_ui(time_control, times_per_timeline, ui);
+            });
+
+            response.on_hover_ui(|ui| {
+                list_item::list_item_scope(ui, "tooltip", |ui| {
+                    ui.markdown_ui(
+                        r"
 Select timeline.
 
 Each piece of logged data is associated with one or more timelines.
@@ -54,17 +138,109 @@ The logging SDK always creates two timelines for you:
 * `log_time` - a temporal timeline with the time of the log call
 
 You can also define your own timelines, e.g. for sensor time or camera frame number.
+
+Right-click to reorder or hide timelines.
 "
-                            .trim(),
-                        );
-
-                        ui.re_hyperlink(
-                            "Full documentation",
-                            "https://rerun.io/docs/concepts/timelines",
-                        );
-                    });
+                        .trim(),
+                    );
+
+                    ui.re_hyperlink(
+                        "Full documentation",
+                        "https://rerun.io/docs/concepts/timelines",
+                    );
+                });
+            });
+        });
+    }
+
+    /// Lets the user drag the timelines around to reorder them, and hide some of them.
+    fn timeline_visibility_ui(
+        &mut self,
+        time_control: &TimeControl,
+        times_per_timeline: &TimesPerTimeline,
+        ui: &mut egui::Ui,
+    ) {
+        let timelines = self.ordered_timelines(times_per_timeline.timelines());
+
+        let mut drag_source = None; // Drag this…
+        let mut drop_target = None; // …onto this.
+
+        for (index, timeline) in timelines.iter().enumerate() {
+            let is_active = timeline == time_control.timeline();
+
+            let response = ui
+                .horizontal(|ui| {
+                    ui.dnd_drag_source(
+                        egui::Id::new(("timeline_order", timeline.name())),
+                        index,
+                        |ui| {
+                            ui.add(
+                                re_ui::icons::DND_MOVE
+                                    .as_image()
+                                    .fit_to_exact_size(re_ui::DesignTokens::small_icon_size()),
+                            );
+                        },
+                    )
+                    .response
+                    .on_hover_text("Drag to reorder");
+
+                    let mut visible = is_active || !self.hidden_timelines.contains(timeline.name());
+                    if ui
+                        .add_enabled(
+                            !is_active,
+                            egui::Checkbox::new(&mut visible, timeline.name().as_str()),
+                        )
+                        .on_disabled_hover_text("The active timeline can't be hidden")
+                        .changed()
+                    {
+                        self.set_timeline_hidden(*timeline.name(), !visible);
+                    }
                 })
+                .response;
+
+            if let Some(dragged_index) = response.dnd_release_payload::<usize>() {
+                drag_source = Some(*dragged_index);
+                drop_target = Some(index);
+            }
+        }
+
+        if let (Some(from), Some(to)) = (drag_source, drop_target) {
+            self.move_timeline(&timelines, from, to);
+        }
+    }
+
+    /// Cycle through the timelines with `Tab` and `Shift+Tab`.
+    ///
+    /// Does nothing if some widget has keyboard focus, so that we don't steal `Tab` from
+    /// text fields or from egui's focus navigation.
+    ///
+    /// Hidden timelines are skipped.
+    pub fn timeline_shortcuts(
+        &self,
+        time_control: &mut TimeControl,
+        times_per_timeline: &TimesPerTimeline,
+        egui_ctx: &egui::Context,
+    ) {
+        if egui_ctx.wants_keyboard_input() || egui_ctx.memory(|mem| mem.focused().is_some()) {
+            return;
+        }
+
+        // Check shift first, since `consume_key` ignores extra shift modifiers.
+        let (previous, next) = egui_ctx.input_mut(|i| {
+            let previous = i.consume_key(egui::Modifiers::SHIFT, egui::Key::Tab);
+            let next = i.consume_key(egui::Modifiers::NONE, egui::Key::Tab);
+            (previous, next)
         });
+        if previous || next {
+            let visible_timelines =
+                self.visible_timelines(time_control.timeline(), times_per_timeline.timelines());
+            if previous {
+                time_control.cycle_timeline(&visible_timelines, false);
+            }
+            if next {
+                time_control.cycle_timeline(&visible_timelines, true);
+            }
+        }
     }
 
     #[allow(clippy::unused_self)]
@@ -100,6 +276,7 @@ You can also define your own timelines, e.g. for sensor time or camera frame num
             self.pause_button_ui(time_control, ui);
             self.step_time_button_ui(time_control, ui, times_per_timeline);
             self.loop_button_ui(time_control, ui);
+            self.playback_direction_button_ui(time_control, ui);
         });
     }
 
@@ -218,6 +395,27 @@ You can also define your own timelines, e.g. for sensor time or camera frame num
         });
     }
 
+    #[allow(clippy::unused_self)]
+    fn playback_direction_button_ui(&self, time_control: &mut TimeControl, ui: &mut egui::Ui) {
+        let is_backward = time_control.playback_direction() == PlaybackDirection::Backward;
+        let hover_text = if is_backward {
+            "Playing backwards"
+        } else {
+            "Playing forwards"
+        };
+        if ui
+            .selectable_label(is_backward, "⏪")
+            .on_hover_text(hover_text)
+            .clicked()
+        {
+            time_control.set_playback_direction(if is_backward {
+                PlaybackDirection::Forward
+            } else {
+                PlaybackDirection::Backward
+            });
+        }
+    }
+
     #[allow(clippy::unused_self)]
     pub fn playback_speed_ui(&self, time_control: &mut TimeControl, ui: &mut egui::Ui) {
         let mut speed = time_control.speed();
@@ -245,3 +443,53 @@ fn toggle_playback_text(egui_ctx: &egui::Context) -> String {
         Default::default()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use re_log_types::Timeline;
+
+    use super::TimeControlUi;
+
+    #[test]
+    fn test_timeline_order_and_visibility() {
+        let log_time = Timeline::new_temporal("log_time");
+        let frame_nr = Timeline::new_sequence("frame_nr");
+        let sim_time = Timeline::new_temporal("sim_time");
+        let timelines = [log_time, frame_nr, sim_time];
+
+        let mut time_control_ui = TimeControlUi::default();
+        assert_eq!(
+            time_control_ui.visible_timelines(&log_time, timelines.iter()),
+            timelines
+        );
+
+        // Move `sim_time` to the front, and hide `frame_nr`:
+        time_control_ui.move_timeline(&timelines, 2, 0);
+        time_control_ui.set_timeline_hidden(*frame_nr.name(), true);
+        assert_eq!(
+            time_control_ui.visible_timelines(&log_time, timelines.iter()),
+            [sim_time, log_time]
+        );
+
+        // The active timeline is always shown:
+        assert_eq!(
+            time_control_ui.visible_timelines(&frame_nr, timelines.iter()),
+            [sim_time, log_time, frame_nr]
+        );
+
+        // New timelines show up last:
+        let new_timeline = Timeline::new_sequence("new_timeline");
+        assert_eq!(
+            time_control_ui.visible_timelines(&log_time, [new_timeline, log_time, sim_time].iter()),
+            [sim_time, log_time, new_timeline]
+        );
+
+        // Survives a round-trip through the app state:
+        let time_control_ui: TimeControlUi =
+            ron::from_str(&ron::to_string(&time_control_ui).unwrap()).unwrap();
+        assert_eq!(
+            time_control_ui.visible_timelines(&log_time, timelines.iter()),
+            [sim_time, log_time]
+        );
+    }
+}
@@ -0,0 +1,142 @@
+//! A thin overview of the whole timeline, shown above the time area when zoomed in.
+
+use egui::{emath::Rangef, CursorIcon, NumExt as _, PointerButton, Rect};
+
+use re_entity_db::EntityDb;
+use re_log_types::TimeInt;
+use re_viewer_context::TimeControl;
+
+use super::time_ranges_ui::TimeRangesUi;
+
+/// Height of the minimap strip.
+pub const MINIMAP_HEIGHT: f32 = 10.0;
+
+/// Shows the data of the whole timeline in `rect`, along with a rectangle marking the part of it
+/// that is currently in view in `time_ranges_ui`.
+///
+/// Dragging that rectangle pans the view, and clicking anywhere else centers the view there.
+pub fn time_minimap_ui(
+    entity_db: &EntityDb,
+    time_ctrl: &mut TimeControl,
+    time_ranges_ui: &TimeRangesUi,
+    ui: &egui::Ui,
+    rect: Rect,
+) {
+    re_tracing::profile_function!();
+
+    let full_time_ranges_ui =
+        crate::initialize_time_ranges_ui(entity_db, time_ctrl, rect.x_range(), None);
+
+    let painter = ui.painter().with_clip_rect(rect);
+    painter.rect_filled(rect, 2.0, ui.visuals().extreme_bg_color);
+
+    paint_low_res_density(
+        entity_db,
+        time_ctrl,
+        &full_time_ranges_ui,
+        ui,
+        &painter,
+        rect,
+    );
+
+    let visible_time_range = time_ranges_ui.visible_time_range();
+    let (Some(min_x), Some(max_x)) = (
+        full_time_ranges_ui.x_from_time_f32(visible_time_range.min().into()),
+        full_time_ranges_ui.x_from_time_f32(visible_time_range.max().into()),
+    ) else {
+        return;
+    };
+    let viewport_rect = Rect::from_x_y_ranges(
+        Rangef::new(min_x, max_x.at_least(min_x + 4.0)),
+        rect.y_range(),
+    )
+    .intersect(rect);
+
+    let response = ui.interact(
+        rect,
+        ui.id().with("time_minimap"),
+        egui::Sense::click_and_drag(),
+    );
+    response.widget_info(|| {
+        egui::WidgetInfo::labeled(egui::WidgetType::Other, ui.is_enabled(), "Time minimap")
+    });
+
+    let pointer_pos = response.hover_pos();
+    let is_hovering_viewport = pointer_pos.is_some_and(|pos| viewport_rect.contains(pos));
+    if response.dragged_by(PointerButton::Primary) {
+        ui.ctx().set_cursor_icon(CursorIcon::Grabbing);
+    } else if is_hovering_viewport {
+        ui.ctx().set_cursor_icon(CursorIcon::Grab);
+    }
+
+    // Where the center of the view should move to:
+    let target_x = if response.dragged_by(PointerButton::Primary) {
+        Some(viewport_rect.center().x + response.drag_delta().x)
+    } else if response.clicked() && !is_hovering_viewport {
+        response.interact_pointer_pos().map(|pos| pos.x)
+    } else {
+        None
+    };
+    if let Some(new_view) = target_x
+        .and_then(|x| full_time_ranges_ui.time_from_x_f32(x))
+        .and_then(|time| time_ranges_ui.pan_to_center(time))
+    {
+        time_ctrl.set_time_view(new_view);
+    }
+
+    let visuals = ui.visuals();
+    painter.rect(
+        viewport_rect,
+        2.0,
+        visuals.selection.bg_fill.gamma_multiply(0.3),
+        if is_hovering_viewport || response.dragged() {
+            visuals.widgets.hovered.fg_stroke
+        } else {
+            visuals.selection.stroke
+        },
+    );
+}
+
+/// Paint where there is data on the whole timeline, with about one bucket per couple of points.
+fn paint_low_res_density(
+    entity_db: &EntityDb,
+    time_ctrl: &TimeControl,
+    full_time_ranges_ui: &TimeRangesUi,
+    ui: &egui::Ui,
+    painter: &egui::Painter,
+    rect: Rect,
+) {
+    let Some(times) = entity_db.time_histogram(time_ctrl.timeline()) else {
+        return;
+    };
+
+    let cutoff_size = (2.0 / full_time_ranges_ui.points_per_time) as u64;
+    let buckets = times.range(.., cutoff_size).collect::<Vec<_>>();
+    let max_count = buckets
+        .iter()
+        .map(|(_, count)| *count)
+        .max()
+        .unwrap_or_default()
+        .at_least(1);
+
+    let y_range = rect.shrink(2.0).y_range();
+    let color = ui.visuals().text_color();
+
+    for (range, count) in buckets {
+        let (Some(min_x), Some(max_x)) = (
+            full_time_ranges_ui.x_from_time_f32(TimeInt::new_temporal(range.min).into()),
+            full_time_ranges_ui.x_from_time_f32(TimeInt::new_temporal(range.max).into()),
+        ) else {
+            continue;
+        };
+
+        // Make sure even single events are visible:
+        let x_range = Rangef::new(min_x, max_x.at_least(min_x + 1.0));
+        let opacity = (count as f32 / max_count as f32).sqrt().at_least(0.2);
+        painter.rect_filled(
+            Rect::from_x_y_ranges(x_range, y_range),
+            0.0,
+            color.gamma_multiply(opacity),
+        );
+    }
+}
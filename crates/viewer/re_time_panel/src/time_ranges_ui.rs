@@ -321,6 +321,16 @@ impl TimeRangesUi {
         )
     }
 
+    /// The UI x-range we are viewing.
+    pub fn x_range(&self) -> Rangef {
+        Rangef::new(*self.x_range.start() as f32, *self.x_range.end() as f32)
+    }
+
+    /// The time range covered by the whole visible x range.
+    pub fn visible_time_range(&self) -> ResolvedTimeRange {
+        self.time_range_from_x_range(*self.x_range.start() as f32..=*self.x_range.end() as f32)
+    }
+
     /// Pan the view, returning the new view.
     pub fn pan(&self, delta_x: f32) -> Option<TimeView> {
         Some(TimeView {
@@ -329,6 +339,13 @@ impl TimeRangesUi {
         })
     }
 
+    /// Pan the view so that `time` ends up in its middle, returning the new view.
+    pub fn pan_to_center(&self, time: TimeReal) -> Option<TimeView> {
+        let x = self.x_from_time(time)?;
+        let center_x = 0.5 * (*self.x_range.start() + *self.x_range.end());
+        self.pan((x - center_x) as f32)
+    }
+
     /// Zoom the view around the given x, returning the new view.
     pub fn zoom_at(&self, x: f32, zoom_factor: f32) -> Option<TimeView> {
         let x = x as f64;
@@ -434,3 +451,22 @@ fn test_time_ranges_ui_2() {
         );
     }
 }
+
+#[test]
+fn test_pan_to_center() {
+    let time_range_ui = TimeRangesUi::new(
+        Rangef::new(0.0, 100.0),
+        TimeView {
+            min: TimeReal::from(0.0),
+            time_spanned: 10.0,
+        },
+        &[ResolvedTimeRange::new(0, 100)],
+    );
+
+    let view = time_range_ui.pan_to_center(TimeReal::from(50.0)).unwrap();
+    assert!(
+        (view.min - TimeReal::from(45.0)).abs().as_f64() < 0.1,
+        "{view:?}"
+    );
+    assert_eq!(view.time_spanned, 10.0);
+}
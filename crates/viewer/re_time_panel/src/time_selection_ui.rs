@@ -62,7 +62,7 @@ pub fn loop_selection_ui(
             let full_y_range = rect.top()..=time_area_painter.clip_rect().bottom();
 
             if is_active {
-                let full_rect = Rect::from_x_y_ranges(rect.x_range(), full_y_range);
+                let full_rect = Rect::from_x_y_ranges(rect.x_range(), full_y_range.clone());
                 let rounding = re_ui::DesignTokens::normal_rounding();
                 time_area_painter.rect_filled(full_rect, rounding, selection_color);
             } else {
@@ -70,6 +70,21 @@ pub fn loop_selection_ui(
                 time_area_painter.rect_filled(rect, rounding, selection_color);
             }
 
+            // Thin guides down through the streams, to make it easy to see which events are inside the loop.
+            // Styled like the time marker, but dimmer so that it doesn't compete with it.
+            let edge_stroke = egui::Stroke::new(
+                1.0,
+                ui.visuals()
+                    .widgets
+                    .inactive
+                    .fg_stroke
+                    .color
+                    .gamma_multiply(if is_active { 0.4 } else { 0.2 }),
+            );
+            for x in [rect.left(), rect.right()] {
+                time_area_painter.vline(x, full_y_range.clone(), edge_stroke);
+            }
+
             if is_active && !selected_range.is_empty() {
                 paint_range_text(time_ctrl, selected_range, ui, time_area_painter, rect);
             }
@@ -1,14 +1,18 @@
 use std::sync::Arc;
 
 use egui::Vec2;
+use egui_kittest::kittest::Queryable as _;
 
 use re_chunk_store::{Chunk, LatestAtQuery, RowId};
-use re_log_types::example_components::MyPoint;
+use re_entity_db::InstancePath;
+use re_log_types::example_components::{MyColor, MyPoint};
 use re_log_types::external::re_types_core::Component;
-use re_log_types::{build_frame_nr, EntityPath};
+use re_log_types::{
+    build_frame_nr, build_log_time, EntityPath, Time, TimeInt, TimePoint, TimeReal,
+};
 use re_time_panel::TimePanel;
-use re_viewer_context::blueprint_timeline;
 use re_viewer_context::test_context::TestContext;
+use re_viewer_context::{blueprint_timeline, CollapseScope, Item, TimeView};
 use re_viewport_blueprint::ViewportBlueprint;
 
 #[test]
@@ -16,24 +20,14 @@ pub fn time_panel_two_sections_should_match_snapshot() {
     TimePanel::ensure_registered_subscribers();
     let mut test_context = TestContext::default();
 
-    let points1 = MyPoint::from_iter(0..1);
-    for i in 0..2 {
-        let entity_path = EntityPath::from(format!("/entity/{i}"));
-        let mut builder = Chunk::builder(entity_path.clone());
-        for frame in [10, 11, 12, 15, 18, 100, 102, 104].map(|frame| frame + i) {
-            builder = builder.with_sparse_component_batches(
-                RowId::new(),
-                [build_frame_nr(frame)],
-                [(MyPoint::descriptor(), Some(&points1 as _))],
-            );
-        }
-        test_context
-            .recording_store
-            .add_chunk(&Arc::new(builder.build().unwrap()))
-            .unwrap();
-    }
+    add_two_sections(&mut test_context, |frame| [build_frame_nr(frame)].into());
 
-    run_time_panel_and_save_snapshot(test_context, "time_panel_two_sections");
+    snapshot_time_panel(
+        test_context,
+        TimePanel::default(),
+        "time_panel_two_sections",
+        PANEL_SIZE,
+    );
 }
 
 #[test]
@@ -69,16 +63,381 @@ pub fn time_panel_dense_data_should_match_snapshot() {
         .add_chunk(&Arc::new(builder.build().unwrap()))
         .unwrap();
 
-    run_time_panel_and_save_snapshot(test_context, "time_panel_dense_data");
+    snapshot_time_panel(
+        test_context,
+        TimePanel::default(),
+        "time_panel_dense_data",
+        PANEL_SIZE,
+    );
 }
 
-fn run_time_panel_and_save_snapshot(mut test_context: TestContext, _snapshot_name: &str) {
+#[test]
+pub fn time_panel_other_timelines() {
+    TimePanel::ensure_registered_subscribers();
+    let mut test_context = TestContext::default();
+
+    add_two_sections(&mut test_context, |frame| {
+        [
+            build_frame_nr(frame),
+            build_log_time(Time::from_seconds_since_epoch(frame as f64 * frame as f64)),
+        ]
+        .into()
+    });
+    test_context.set_time("frame_nr", TimeInt::new_temporal(10));
+
+    // Closed entities get a row for each of their other timelines:
     let mut panel = TimePanel::default();
+    for show_other_timelines in [false, true] {
+        panel.set_show_other_timelines(show_other_timelines);
+        let mut harness = time_panel_harness(&mut test_context, &mut panel, PANEL_SIZE);
+        harness.run();
+
+        let num_log_time_rows = harness.query_all_by_label("log_time").count();
+        assert_eq!(num_log_time_rows > 0, show_other_timelines);
+    }
+}
+
+#[test]
+pub fn time_panel_collapse_expand_all() {
+    TimePanel::ensure_registered_subscribers();
+    let mut test_context = TestContext::default();
+
+    add_entity_tree(&mut test_context);
+
+    let mut panel = TimePanel::default();
+    let mut harness = time_panel_harness(&mut test_context, &mut panel, PANEL_SIZE);
+
+    harness.run();
+
+    let is_open = |harness: &egui_kittest::Harness<'_>, entity_path: &str| {
+        CollapseScope::StreamsTree
+            .entity(entity_path.into())
+            .is_open(&harness.ctx)
+    };
+
+    harness.get_by_label("Collapse all").click();
+    harness.run();
+    for entity_path in ["/", "/parent", "/parent/child", "/other"] {
+        assert_eq!(is_open(&harness, entity_path), Some(false), "{entity_path}");
+    }
+
+    harness.get_by_label("Expand all").click();
+    harness.run();
+    for entity_path in ["/", "/parent", "/parent/child", "/other"] {
+        assert_eq!(is_open(&harness, entity_path), Some(true), "{entity_path}");
+    }
+
+    // After a reset, the default heuristic applies again: only the top levels are open.
+    harness.get_by_label("Reset collapse state").click();
+    harness.run();
+    assert_eq!(is_open(&harness, "/"), Some(true));
+    assert_eq!(is_open(&harness, "/parent"), Some(true));
+    assert_eq!(is_open(&harness, "/parent/child"), Some(false));
+}
+
+#[test]
+pub fn time_panel_deep_tree_does_not_overflow() {
+    TimePanel::ensure_registered_subscribers();
+    let mut test_context = TestContext::default();
+
+    let points1 = MyPoint::from_iter(0..1);
+    let entity_path = vec!["deep"; 1000].join("/");
+    let chunk = Chunk::builder(entity_path.as_str().into())
+        .with_sparse_component_batches(
+            RowId::new(),
+            [build_frame_nr(1)],
+            [(MyPoint::descriptor(), Some(&points1 as _))],
+        )
+        .build()
+        .unwrap();
+    test_context
+        .recording_store
+        .add_chunk(&Arc::new(chunk))
+        .unwrap();
+
+    let mut panel = TimePanel::default();
+    let mut harness = time_panel_harness(&mut test_context, &mut panel, Vec2::new(800.0, 3000.0));
+    harness.run();
+
+    harness.get_by_label("Expand all").click();
+    harness.run();
+
+    // The tree is cut short instead of recursing all the way down:
+    assert!(harness.query_by_label("…").is_some());
+}
+
+#[test]
+pub fn time_panel_hide_component() {
+    TimePanel::ensure_registered_subscribers();
+    let mut test_context = TestContext::default();
+
+    let points1 = MyPoint::from_iter(0..1);
+    let colors1 = vec![MyColor::from_rgb(255, 0, 0)];
+    let chunk = Chunk::builder("/entity".into())
+        .with_sparse_component_batches(
+            RowId::new(),
+            [build_frame_nr(1)],
+            [
+                (MyPoint::descriptor(), Some(&points1 as _)),
+                (MyColor::descriptor(), Some(&colors1 as _)),
+            ],
+        )
+        .build()
+        .unwrap();
+    test_context
+        .recording_store
+        .add_chunk(&Arc::new(chunk))
+        .unwrap();
+
+    let mut panel = TimePanel::default();
+
+    // Indicators are hidden by default:
+    assert!(!panel.is_component_visible(&"rerun.components.Points2DIndicator".into()));
+    assert!(panel.is_component_visible(&MyColor::name()));
+
+    let mut show_rows = |panel: &mut TimePanel| {
+        let mut harness = time_panel_harness(&mut test_context, panel, PANEL_SIZE);
+        harness.run();
+        harness.get_by_label("Expand all").click();
+        harness.run();
+        ["example.MyPoint", "example.MyColor"].map(|label| harness.query_by_label(label).is_some())
+    };
+
+    assert_eq!(show_rows(&mut panel), [true, true]);
+
+    panel.set_component_visible(MyColor::name(), false);
+    assert_eq!(show_rows(&mut panel), [true, false]);
+
+    // The escape hatch shows it again, so that it can be un-hidden:
+    panel.set_show_hidden_components(true);
+    assert_eq!(show_rows(&mut panel), [true, true]);
+
+    panel.set_show_hidden_components(false);
+    panel.set_component_visible(MyColor::name(), true);
+    assert_eq!(show_rows(&mut panel), [true, true]);
+}
+
+#[test]
+pub fn time_panel_only_selection() {
+    TimePanel::ensure_registered_subscribers();
+    let mut test_context = TestContext::default();
+
+    add_entity_tree(&mut test_context);
+
+    let mut panel = TimePanel::default();
+    panel.set_show_only_selection(true);
+
+    // Nothing is selected yet, so everything is shown:
+    {
+        let mut harness = time_panel_harness(&mut test_context, &mut panel, PANEL_SIZE);
+        harness.run();
+        for label in ["parent/", "child", "other_child", "other"] {
+            assert!(harness.query_by_label(label).is_some(), "{label}");
+        }
+    }
 
-    //TODO(ab): this contains a lot of boilerplate which should be provided by helpers
-    let mut harness = egui_kittest::Harness::builder()
-        .with_size(Vec2::new(700.0, 300.0))
-        .build_ui(|ui| {
+    test_context.edit_selection(|selection_state| {
+        selection_state.set_selection(Item::InstancePath(InstancePath::entity_all(
+            "/parent/child".into(),
+        )));
+    });
+
+    // Only the selected entity is shown, along with its ancestors:
+    let mut harness = time_panel_harness(&mut test_context, &mut panel, PANEL_SIZE);
+    harness.run();
+    for label in ["parent/", "child"] {
+        assert!(harness.query_by_label(label).is_some(), "{label}");
+    }
+    for label in ["other_child", "other"] {
+        assert!(harness.query_by_label(label).is_none(), "{label}");
+    }
+}
+
+#[test]
+pub fn time_panel_entity_filter() {
+    TimePanel::ensure_registered_subscribers();
+    let mut test_context = TestContext::default();
+
+    add_entity_tree(&mut test_context);
+
+    let mut panel = TimePanel::default();
+
+    // Collapsed by the user before filtering:
+    {
+        let mut harness = time_panel_harness(&mut test_context, &mut panel, PANEL_SIZE);
+        harness.run();
+        harness.get_by_label("Collapse all").click();
+        harness.run();
+    }
+
+    // The matches are expanded to, even though their parent was collapsed:
+    panel.set_entity_filter("OTHER_c");
+    {
+        let mut harness = time_panel_harness(&mut test_context, &mut panel, PANEL_SIZE);
+        harness.run();
+        for label in ["parent/", "other_child"] {
+            assert!(harness.query_by_label(label).is_some(), "{label}");
+        }
+        for label in ["child", "other"] {
+            assert!(harness.query_by_label(label).is_none(), "{label}");
+        }
+
+        // Collapse all and expand all act on the filtered tree:
+        harness.get_by_label("Collapse all").click();
+        harness.run();
+        assert!(harness.query_by_label("other_child").is_none());
+
+        harness.get_by_label("Expand all").click();
+        harness.run();
+        assert!(harness.query_by_label("other_child").is_some());
+    }
+
+    // Once cleared, the tree is back as it was:
+    panel.set_entity_filter("");
+    let mut harness = time_panel_harness(&mut test_context, &mut panel, PANEL_SIZE);
+    harness.run();
+    for entity_path in ["/", "/parent"] {
+        assert_eq!(
+            CollapseScope::StreamsTree
+                .entity(entity_path.into())
+                .is_open(&harness.ctx),
+            Some(false),
+            "{entity_path}"
+        );
+    }
+    assert!(harness.query_by_label("other_child").is_none());
+}
+
+#[test]
+pub fn time_panel_minimap() {
+    TimePanel::ensure_registered_subscribers();
+    let mut test_context = TestContext::default();
+
+    add_two_sections(&mut test_context, |frame| [build_frame_nr(frame)].into());
+
+    test_context.set_time("frame_nr", TimeInt::new_temporal(12));
+    let mut panel = TimePanel::default();
+
+    // Only shown once zoomed in:
+    {
+        let mut harness = time_panel_harness(&mut test_context, &mut panel, PANEL_SIZE);
+        harness.run();
+        assert!(harness.query_by_label("Time minimap").is_none());
+    }
+
+    test_context
+        .recording_config
+        .time_ctrl
+        .write()
+        .set_time_view(TimeView {
+            min: TimeReal::from(10_i64),
+            time_spanned: 6.0,
+        });
+    let mut harness = time_panel_harness(&mut test_context, &mut panel, PANEL_SIZE);
+    harness.run();
+    assert!(harness.query_by_label("Time minimap").is_some());
+}
+
+#[test]
+pub fn time_panel_static_lane() {
+    TimePanel::ensure_registered_subscribers();
+    let mut test_context = TestContext::default();
+
+    let points1 = MyPoint::from_iter(0..1);
+    let add_chunk = |test_context: &mut TestContext, entity_path: &str, timepoint: TimePoint| {
+        let chunk = Chunk::builder(entity_path.into())
+            .with_sparse_component_batches(
+                RowId::new(),
+                timepoint,
+                [(MyPoint::descriptor(), Some(&points1 as _))],
+            )
+            .build()
+            .unwrap();
+        test_context
+            .recording_store
+            .add_chunk(&Arc::new(chunk))
+            .unwrap();
+    };
+
+    for frame in [10, 20, 30] {
+        add_chunk(
+            &mut test_context,
+            "/temporal",
+            [build_frame_nr(frame)].into(),
+        );
+        add_chunk(&mut test_context, "/mixed", [build_frame_nr(frame)].into());
+    }
+
+    // Without any static data, there is no static lane:
+    let mut panel = TimePanel::default();
+    {
+        let mut harness = time_panel_harness(&mut test_context, &mut panel, PANEL_SIZE);
+        harness.run();
+        assert!(harness.query_by_label("Static").is_none());
+    }
+
+    add_chunk(&mut test_context, "/static", TimePoint::default());
+    add_chunk(&mut test_context, "/mixed", TimePoint::default());
+    let mut harness = time_panel_harness(&mut test_context, &mut panel, PANEL_SIZE);
+    harness.run();
+    assert!(harness.query_by_label("Static").is_some());
+}
+
+/// Size of the time panel in the tests, unless they need something else.
+const PANEL_SIZE: Vec2 = Vec2::new(700.0, 300.0);
+
+/// Log `/entity/0` and `/entity/1`, each with events in two sections far apart on `frame_nr`.
+///
+/// `timepoint` gives the time point of an event from its frame number.
+fn add_two_sections(test_context: &mut TestContext, timepoint: impl Fn(i64) -> TimePoint) {
+    let points1 = MyPoint::from_iter(0..1);
+    for i in 0..2 {
+        let entity_path = EntityPath::from(format!("/entity/{i}"));
+        let mut builder = Chunk::builder(entity_path.clone());
+        for frame in [10, 11, 12, 15, 18, 100, 102, 104].map(|frame| frame + i) {
+            builder = builder.with_sparse_component_batches(
+                RowId::new(),
+                timepoint(frame),
+                [(MyPoint::descriptor(), Some(&points1 as _))],
+            );
+        }
+        test_context
+            .recording_store
+            .add_chunk(&Arc::new(builder.build().unwrap()))
+            .unwrap();
+    }
+}
+
+/// Log `/parent/child`, `/parent/other_child` and `/other`, each with a single event.
+fn add_entity_tree(test_context: &mut TestContext) {
+    let points1 = MyPoint::from_iter(0..1);
+    for entity_path in ["/parent/child", "/parent/other_child", "/other"] {
+        let chunk = Chunk::builder(entity_path.into())
+            .with_sparse_component_batches(
+                RowId::new(),
+                [build_frame_nr(1)],
+                [(MyPoint::descriptor(), Some(&points1 as _))],
+            )
+            .build()
+            .unwrap();
+        test_context
+            .recording_store
+            .add_chunk(&Arc::new(chunk))
+            .unwrap();
+    }
+}
+
+/// A harness showing `panel` for the recording and blueprint of `test_context`.
+///
+/// System commands sent by the panel are handled after each frame.
+fn time_panel_harness<'a>(
+    test_context: &'a mut TestContext,
+    panel: &'a mut TimePanel,
+    size: Vec2,
+) -> egui_kittest::Harness<'a> {
+    egui_kittest::Harness::builder()
+        .with_size(size)
+        .build_ui(move |ui| {
             test_context.run(&ui.ctx().clone(), |viewer_ctx| {
                 let blueprint = ViewportBlueprint::try_from_db(
                     viewer_ctx.store_context.blueprint,
@@ -99,8 +458,17 @@ fn run_time_panel_and_save_snapshot(mut test_context: TestContext, _snapshot_nam
             });
 
             test_context.handle_system_commands();
-        });
+        })
+}
 
+/// Show `panel` for the recording of `test_context`, and compare it to the snapshot called `snapshot_name`.
+fn snapshot_time_panel(
+    mut test_context: TestContext,
+    mut panel: TimePanel,
+    _snapshot_name: &str,
+    size: Vec2,
+) {
+    let mut harness = time_panel_harness(&mut test_context, &mut panel, size);
     harness.run();
 
     //TODO(#8245): enable this everywhere when we have a software renderer setup
@@ -68,6 +68,7 @@ pub enum UICommand {
     PlaybackStepBack,
     PlaybackStepForward,
     PlaybackRestart,
+    PlaybackResetTimeControl,
 
     // Dev-tools:
     #[cfg(not(target_arch = "wasm32"))]
@@ -212,6 +213,10 @@ impl UICommand {
                 "Move the time marker to the next point in time with any data",
             ),
             Self::PlaybackRestart => ("Restart", "Restart from beginning of timeline"),
+            Self::PlaybackResetTimeControl => (
+                "Reset time controls",
+                "Clear the loop selection, reset the zoom of the time panel, and restart from the beginning of the timeline",
+            ),
 
             #[cfg(not(target_arch = "wasm32"))]
             Self::ScreenshotWholeApp => (
@@ -352,6 +357,7 @@ impl UICommand {
             Self::PlaybackStepBack => smallvec![key(Key::ArrowLeft)],
             Self::PlaybackStepForward => smallvec![key(Key::ArrowRight)],
             Self::PlaybackRestart => smallvec![cmd(Key::ArrowLeft)],
+            Self::PlaybackResetTimeControl => smallvec![cmd_shift(Key::ArrowLeft)],
 
             #[cfg(not(target_arch = "wasm32"))]
             Self::ScreenshotWholeApp => smallvec![],
@@ -442,6 +442,20 @@ impl DesignTokens {
         egui::Stroke::new(2.0, self.color(ColorToken::blue(S350)))
     }
 
+    /// Accent used to tell apart UI showing a blueprint from UI showing a recording,
+    /// e.g. the blueprint time panel.
+    ///
+    /// `None` for recordings, which keep the normal colors.
+    pub fn store_kind_accent_color(
+        &self,
+        store_kind: re_log_types::StoreKind,
+    ) -> Option<egui::Color32> {
+        match store_kind {
+            re_log_types::StoreKind::Recording => None,
+            re_log_types::StoreKind::Blueprint => Some(self.color(ColorToken::purple(S325))),
+        }
+    }
+
     pub fn text(&self, text: impl Into<String>, token: ColorToken) -> egui::RichText {
         egui::RichText::new(text).color(self.color(token))
     }
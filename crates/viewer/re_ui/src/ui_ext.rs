@@ -815,6 +815,19 @@ pub trait UiExt {
             ui.visuals().widgets.inactive.fg_stroke
         };
 
+        self.paint_time_cursor_with_stroke(painter, stroke, x, y);
+    }
+
+    /// Paints a time cursor with a specific stroke, e.g. to highlight a special state.
+    ///
+    /// See also [`Self::paint_time_cursor`].
+    fn paint_time_cursor_with_stroke(
+        &self,
+        painter: &egui::Painter,
+        stroke: egui::Stroke,
+        x: f32,
+        y: Rangef,
+    ) {
         let Rangef {
             min: y_min,
             max: y_max,
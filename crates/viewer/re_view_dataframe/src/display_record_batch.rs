@@ -253,7 +253,7 @@ impl DisplayColumn {
                     let timestamp = TimeInt::try_from(time_data.value(row_index));
                     match timestamp {
                         Ok(timestamp) => {
-                            ui.label(timeline.typ().format(timestamp, ctx.app_options.time_zone));
+                            ui.label(timeline.format_time(timestamp, ctx.app_options.time_zone));
                         }
                         Err(err) => {
                             ui.error_with_details_on_hover(err.to_string());
@@ -33,6 +33,7 @@ enum TimeControlCommand {
     StepForward,
     Restart,
     Follow,
+    ResetAll,
 }
 
 // ----------------------------------------------------------------------------
@@ -559,9 +560,40 @@ impl App {
                     .redo();
             }
 
-            SystemCommand::DropEntity(blueprint_id, entity_path) => {
-                let blueprint_db = store_hub.entity_db_mut(&blueprint_id);
-                blueprint_db.drop_entity_path_recursive(&entity_path);
+            SystemCommand::DropEntity(store_id, entity_path) => {
+                let db = store_hub.entity_db_mut(&store_id);
+                db.drop_entity_path_recursive(&entity_path);
+            }
+
+            SystemCommand::DropEntityOnTimeline {
+                store_id,
+                entity_path,
+                timeline,
+            } => {
+                let db = store_hub.entity_db_mut(&store_id);
+                db.drop_entity_path_recursive_on_timeline(&entity_path, &timeline);
+            }
+
+            SystemCommand::ExportTimeRangeAsRecording {
+                store_id,
+                timeline,
+                time_range,
+            } => {
+                let Some(db) = store_hub.store_bundle().get(&store_id) else {
+                    re_log::warn!("Can't export data from unknown store {store_id}");
+                    return;
+                };
+
+                let new_id = re_log_types::StoreId::random(StoreKind::Recording);
+                match db.clone_with_new_id_and_time_range(new_id.clone(), &timeline, time_range) {
+                    Ok(new_db) => {
+                        store_hub.insert_entity_db(new_db);
+                        store_hub.set_active_recording_id(new_id);
+                    }
+                    Err(err) => {
+                        re_log::error!("Failed to export time range: {err}");
+                    }
+                }
             }
 
             #[cfg(debug_assertions)]
@@ -579,6 +611,18 @@ impl App {
                 }
             }
 
+            SystemCommand::ResetTimeControl { rec_id } => {
+                let Some(entity_db) = store_hub.store_bundle().get(&rec_id) else {
+                    return;
+                };
+                if let Some(rec_cfg) = self.state.recording_config_mut(&rec_id) {
+                    rec_cfg
+                        .time_ctrl
+                        .write()
+                        .reset_all(entity_db.times_per_timeline());
+                }
+            }
+
             SystemCommand::SetFocus(item) => {
                 self.state.focused_item = Some(item);
             }
@@ -848,6 +892,9 @@ impl App {
             UICommand::PlaybackRestart => {
                 self.run_time_control_command(store_context, TimeControlCommand::Restart);
             }
+            UICommand::PlaybackResetTimeControl => {
+                self.run_time_control_command(store_context, TimeControlCommand::ResetAll);
+            }
 
             #[cfg(not(target_arch = "wasm32"))]
             UICommand::ScreenshotWholeApp => {
@@ -939,6 +986,9 @@ impl App {
             TimeControlCommand::Restart => {
                 time_ctrl.restart(times_per_timeline);
             }
+            TimeControlCommand::ResetAll => {
+                time_ctrl.reset_all(times_per_timeline);
+            }
         }
     }
 
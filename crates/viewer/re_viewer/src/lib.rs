@@ -118,7 +118,8 @@ impl AppEnvironment {
             StoreSource::File { .. }
             | StoreSource::Unknown
             | StoreSource::Viewer
-            | StoreSource::Other(_) => {
+            | StoreSource::Other(_)
+            | StoreSource::MessageProxy { .. } => {
                 // We should not really get here
 
                 #[cfg(debug_assertions)]
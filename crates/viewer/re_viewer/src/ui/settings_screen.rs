@@ -89,6 +89,11 @@ fn settings_screen_ui_impl(ui: &mut egui::Ui, app_options: &mut AppOptions, keep
         "Unix epoch",
     )
     .on_hover_text("Display timestamps in seconds since unix epoch");
+    ui.re_checkbox(
+        &mut app_options.show_time_since_start,
+        "Show time since start",
+    )
+    .on_hover_text("Also show the current time relative to the first event on the timeline");
 
     //
     // Map view
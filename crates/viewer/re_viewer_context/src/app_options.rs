@@ -31,6 +31,9 @@ pub struct AppOptions {
     #[serde(rename = "time_zone_for_timestamps")]
     pub time_zone: TimeZone,
 
+    /// Next to the current timestamp, also show how long after the start of the recording it is.
+    pub show_time_since_start: bool,
+
     /// Preferred method for video decoding on web.
     pub video_decoder_hw_acceleration: DecodeHardwareAcceleration,
 
@@ -83,6 +86,7 @@ impl Default for AppOptions {
             blueprint_gc: true,
 
             time_zone: TimeZone::Utc,
+            show_time_since_start: false,
 
             video_decoder_hw_acceleration: DecodeHardwareAcceleration::default(),
             video_decoder_override_ffmpeg_path: false,
@@ -63,11 +63,29 @@ pub enum SystemCommand {
     ///
     /// Also drops all recursive children.
     ///
-    /// The [`StoreId`] should generally be the currently selected blueprint
-    /// but is tracked manually to ensure self-consistency if the blueprint
+    /// The [`StoreId`] is generally either the currently selected blueprint or the active recording,
+    /// but is tracked manually to ensure self-consistency if the store
     /// is both modified and changed in the same frame.
     DropEntity(StoreId, EntityPath),
 
+    /// Drop all the data a specific entity and its recursive children have on a given timeline.
+    ///
+    /// See [`re_entity_db::EntityDb::drop_entity_path_recursive_on_timeline`].
+    DropEntityOnTimeline {
+        store_id: StoreId,
+        entity_path: EntityPath,
+        timeline: re_chunk::Timeline,
+    },
+
+    /// Copy the data of a recording within a time range into a new recording, and make it active.
+    ///
+    /// See [`re_entity_db::EntityDb::clone_with_new_id_and_time_range`].
+    ExportTimeRangeAsRecording {
+        store_id: StoreId,
+        timeline: re_chunk::Timeline,
+        time_range: re_log_types::ResolvedTimeRange,
+    },
+
     /// Show a timeline of the blueprint data.
     #[cfg(debug_assertions)]
     EnableInspectBlueprintTimeline(bool),
@@ -81,6 +99,14 @@ pub enum SystemCommand {
         timeline: re_chunk::Timeline,
     },
 
+    /// Reset the time control of the given recording: clear the loop selection, reset the
+    /// zoom of the time panel and restart from the beginning of the active timeline.
+    ///
+    /// See [`crate::TimeControl::reset_all`].
+    ResetTimeControl {
+        rec_id: StoreId,
+    },
+
     /// Sets the focus to the given item.
     ///
     /// The focused item is cleared out every frame.
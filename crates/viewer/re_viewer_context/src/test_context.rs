@@ -1,14 +1,19 @@
+use std::collections::HashMap;
 use std::sync::Arc;
 
+use re_chunk::RowId;
 use re_chunk_store::LatestAtQuery;
-use re_entity_db::EntityDb;
-use re_log_types::{StoreId, StoreKind};
+use re_entity_db::{EntityDb, StoreBundle};
+use re_log_types::{
+    LogMsg, ResolvedTimeRange, SetStoreInfo, StoreId, StoreInfo, StoreKind, StoreSource, Time,
+    TimeInt,
+};
 use re_types_core::reflection::Reflection;
 
 use crate::{
     blueprint_timeline, command_channel, ApplicationSelectionState, CommandReceiver, CommandSender,
     ComponentUiRegistry, ItemCollection, RecordingConfig, StoreContext, SystemCommand,
-    ViewClassRegistry, ViewerContext,
+    SystemCommandSender as _, ViewClassRegistry, ViewerContext,
 };
 
 /// Harness to execute code that rely on [`crate::ViewerContext`].
@@ -34,14 +39,41 @@ pub struct TestContext {
     pub component_ui_registry: ComponentUiRegistry,
     pub reflection: Reflection,
 
+    /// Recordings added with [`Self::add_recording`] that aren't active right now.
+    ///
+    /// This is what's exposed as [`StoreContext::bundle`].
+    inactive_recordings: StoreBundle,
+    inactive_recording_configs: HashMap<StoreId, RecordingConfig>,
+
     command_sender: CommandSender,
     command_receiver: CommandReceiver,
 }
 
+/// The application id of the stores of a [`TestContext`].
+const TEST_APP_ID: &str = "rerun_test";
+
+fn empty_store(kind: StoreKind) -> EntityDb {
+    let store_id = StoreId::random(kind);
+    let mut entity_db = EntityDb::new(store_id.clone());
+    entity_db.set_store_info(SetStoreInfo {
+        row_id: *RowId::new(),
+        info: StoreInfo {
+            application_id: TEST_APP_ID.into(),
+            store_id,
+            cloned_from: None,
+            is_official_example: false,
+            started: Time::now(),
+            store_source: StoreSource::Other("TestContext".to_owned()),
+            store_version: None,
+        },
+    });
+    entity_db
+}
+
 impl Default for TestContext {
     fn default() -> Self {
-        let recording_store = EntityDb::new(StoreId::random(StoreKind::Recording));
-        let blueprint_store = EntityDb::new(StoreId::random(StoreKind::Blueprint));
+        let recording_store = empty_store(StoreKind::Recording);
+        let blueprint_store = empty_store(StoreKind::Blueprint);
 
         let (command_sender, command_receiver) = command_channel();
 
@@ -65,6 +97,8 @@ impl Default for TestContext {
             blueprint_query,
             component_ui_registry,
             reflection,
+            inactive_recordings: Default::default(),
+            inactive_recording_configs: Default::default(),
             command_sender,
             command_receiver,
         }
@@ -79,6 +113,182 @@ impl TestContext {
         self.selection_state.on_frame_start(|_| true, None);
     }
 
+    /// Add another, empty, recording without making it active.
+    ///
+    /// Use [`Self::set_active_recording`] to switch to it.
+    pub fn add_recording(&mut self, store_info: StoreInfo) -> StoreId {
+        let store_id = store_info.store_id.clone();
+        assert_eq!(store_id.kind, StoreKind::Recording);
+
+        let mut recording = EntityDb::new(store_id.clone());
+        recording.set_store_info(SetStoreInfo {
+            row_id: *RowId::new(),
+            info: store_info,
+        });
+        self.inactive_recordings.insert(recording);
+
+        store_id
+    }
+
+    /// Make the given recording the active one, i.e. the [`Self::recording_store`].
+    ///
+    /// Each recording keeps its own [`RecordingConfig`].
+    pub fn set_active_recording(&mut self, store_id: &StoreId) {
+        if self.recording_store.store_id() == *store_id {
+            return;
+        }
+
+        let recording = self
+            .inactive_recordings
+            .remove(store_id)
+            .unwrap_or_else(|| panic!("Unknown recording {store_id}"));
+        let recording_config = self
+            .inactive_recording_configs
+            .remove(store_id)
+            .unwrap_or_default();
+
+        let previous_recording = std::mem::replace(&mut self.recording_store, recording);
+        let previous_config = std::mem::replace(&mut self.recording_config, recording_config);
+        self.inactive_recording_configs
+            .insert(previous_recording.store_id(), previous_config);
+        self.inactive_recordings.insert(previous_recording);
+    }
+
+    /// Make `timeline` the active timeline and move the time cursor to `time`.
+    ///
+    /// Panics if the recording has no data on a timeline with that name.
+    pub fn set_time(&mut self, timeline: &str, time: TimeInt) {
+        let timeline = *self
+            .recording_store
+            .timelines()
+            .find(|t| t.name().as_str() == timeline)
+            .unwrap_or_else(|| panic!("The recording has no timeline named {timeline:?}"));
+
+        self.command_sender
+            .send_system(SystemCommand::SetActiveTimeline {
+                rec_id: self.recording_store.store_id(),
+                timeline,
+            });
+        self.handle_system_commands();
+
+        self.recording_config.time_ctrl.write().set_time(time);
+    }
+
+    /// Move the time cursor of the active timeline forward by `delta`.
+    ///
+    /// If the time wasn't set yet, this starts from the beginning of the timeline.
+    pub fn advance_time(&mut self, delta: TimeInt) {
+        let mut time_ctrl = self.recording_config.time_ctrl.write();
+        let times_per_timeline = self.recording_store.times_per_timeline();
+        time_ctrl.select_a_valid_timeline(times_per_timeline);
+
+        let time = time_ctrl
+            .time_int()
+            .or_else(|| {
+                time_ctrl
+                    .full_range(times_per_timeline)
+                    .map(|range| range.min())
+            })
+            .unwrap_or(TimeInt::ZERO);
+        time_ctrl.set_time(time + delta);
+    }
+
+    /// Set the loop selection of the active timeline, like shift-dragging in the time panel does.
+    ///
+    /// This doesn't turn on looping. Use `None` to remove the selection.
+    pub fn set_loop_selection(&mut self, range: Option<ResolvedTimeRange>) {
+        let mut time_ctrl = self.recording_config.time_ctrl.write();
+        match range {
+            Some(range) => time_ctrl.set_loop_selection(range.into()),
+            None => time_ctrl.remove_loop_selection(),
+        }
+    }
+
+    /// Save the recording and the blueprint to a single `.rrd` file.
+    ///
+    /// The blueprint is activated when the file is loaded, so that opening it in the viewer
+    /// reproduces both the data and the layout. Useful to debug failing tests.
+    pub fn save_session_to_file(&self, path: impl AsRef<std::path::Path>) -> anyhow::Result<()> {
+        use anyhow::Context as _;
+
+        let messages = self
+            .recording_store
+            .to_messages(None)
+            .chain(self.blueprint_store.to_messages(None));
+
+        let bytes = re_log_encoding::encoder::encode_as_bytes_local(messages)?;
+
+        let path = path.as_ref();
+        std::fs::write(path, bytes).with_context(|| format!("Failed to write file at {path:?}"))?;
+
+        Ok(())
+    }
+
+    /// Replace the recording (and blueprint, if any) with the contents of an `.rrd` file.
+    ///
+    /// See [`Self::load_rrd_bytes`].
+    pub fn load_rrd(&mut self, path: impl AsRef<std::path::Path>) -> anyhow::Result<()> {
+        use anyhow::Context as _;
+
+        let path = path.as_ref();
+        let file = std::fs::File::open(path).with_context(|| format!("Failed to open {path:?}"))?;
+        self.load_rrd_from(std::io::BufReader::new(file))
+    }
+
+    /// Replace the recording (and blueprint, if any) with the contents of an encoded `.rrd` stream.
+    ///
+    /// A [`TestContext`] only holds a single recording and blueprint, so if the stream contains
+    /// several, the first recording is used, together with the blueprint that was activated
+    /// (or else the first blueprint).
+    pub fn load_rrd_bytes(&mut self, bytes: &[u8]) -> anyhow::Result<()> {
+        self.load_rrd_from(bytes)
+    }
+
+    fn load_rrd_from(&mut self, read: impl std::io::Read) -> anyhow::Result<()> {
+        use anyhow::Context as _;
+
+        let decoder =
+            re_log_encoding::decoder::Decoder::new(re_log_encoding::VersionPolicy::Warn, read)?;
+
+        let mut bundle = re_entity_db::StoreBundle::default();
+        let mut recording_id = None;
+        let mut blueprint_id = None;
+        let mut active_blueprint_id = None;
+
+        for msg in decoder {
+            let msg = msg?;
+            let store_id = msg.store_id().clone();
+
+            if let LogMsg::BlueprintActivationCommand(cmd) = &msg {
+                if cmd.make_active {
+                    active_blueprint_id = Some(store_id.clone());
+                }
+            }
+
+            let first_of_kind = match store_id.kind {
+                StoreKind::Recording => &mut recording_id,
+                StoreKind::Blueprint => &mut blueprint_id,
+            };
+            first_of_kind.get_or_insert_with(|| store_id.clone());
+
+            bundle.entry(&store_id).add(&msg)?;
+        }
+
+        let recording_id = recording_id.context("The stream contains no recording")?;
+        self.recording_store = bundle
+            .remove(&recording_id)
+            .context("The recording is missing")?;
+        self.recording_config = RecordingConfig::default();
+
+        if let Some(blueprint_id) = active_blueprint_id.or(blueprint_id) {
+            self.blueprint_store = bundle
+                .remove(&blueprint_id)
+                .context("The blueprint is missing")?;
+        }
+
+        Ok(())
+    }
+
     /// Run the provided closure with a [`ViewerContext`] produced by the [`Self`].
     ///
     /// IMPORTANT: call [`Self::handle_system_commands`] after calling this function if your test
@@ -87,11 +297,11 @@ impl TestContext {
         re_ui::apply_style_and_install_loaders(egui_ctx);
 
         let store_context = StoreContext {
-            app_id: "rerun_test".into(),
+            app_id: TEST_APP_ID.into(),
             blueprint: &self.blueprint_store,
             default_blueprint: None,
             recording: &self.recording_store,
-            bundle: &Default::default(),
+            bundle: &self.inactive_recordings,
             caches: &Default::default(),
             hub: &Default::default(),
         };
@@ -148,6 +358,24 @@ impl TestContext {
         });
     }
 
+    /// The recording or blueprint store with the given id.
+    fn store_mut(&mut self, store_id: &StoreId) -> &mut EntityDb {
+        if store_id == &self.recording_store.store_id() {
+            &mut self.recording_store
+        } else {
+            assert_eq!(store_id, &self.blueprint_store.store_id());
+            &mut self.blueprint_store
+        }
+    }
+
+    /// Returns all the system commands queued so far, without handling them.
+    ///
+    /// Use this instead of [`Self::handle_system_commands`] to assert on which commands were
+    /// emitted.
+    pub fn drain_system_commands(&mut self) -> Vec<SystemCommand> {
+        std::iter::from_fn(|| self.command_receiver.recv_system()).collect()
+    }
+
     /// Best-effort attempt to meaningfully handle some of the system commands.
     pub fn handle_system_commands(&mut self) {
         while let Some(command) = self.command_receiver.recv_system() {
@@ -165,11 +393,50 @@ impl TestContext {
                 }
 
                 SystemCommand::DropEntity(store_id, entity_path) => {
-                    assert_eq!(store_id, self.blueprint_store.store_id());
-                    self.blueprint_store
+                    self.store_mut(&store_id)
                         .drop_entity_path_recursive(&entity_path);
                 }
 
+                SystemCommand::DropEntityOnTimeline {
+                    store_id,
+                    entity_path,
+                    timeline,
+                } => {
+                    self.store_mut(&store_id)
+                        .drop_entity_path_recursive_on_timeline(&entity_path, &timeline);
+                }
+
+                SystemCommand::ExportTimeRangeAsRecording {
+                    store_id,
+                    timeline,
+                    time_range,
+                } => {
+                    let db = [&self.recording_store, &self.blueprint_store]
+                        .into_iter()
+                        .find(|db| db.store_id() == store_id)
+                        .or_else(|| self.inactive_recordings.get(&store_id));
+                    let Some(db) = db else {
+                        re_log::warn!("Can't export data from unknown store {store_id}");
+                        continue;
+                    };
+
+                    let new_id = StoreId::random(StoreKind::Recording);
+                    match db.clone_with_new_id_and_time_range(new_id.clone(), &timeline, time_range)
+                    {
+                        Ok(new_db) => {
+                            self.inactive_recordings.insert(new_db);
+                            self.set_active_recording(&new_id);
+                        }
+                        Err(err) => {
+                            re_log::error!("Failed to export time range: {err}");
+                        }
+                    }
+                }
+
+                SystemCommand::ActivateRecording(store_id) => {
+                    self.set_active_recording(&store_id);
+                }
+
                 SystemCommand::SetSelection(item) => {
                     self.selection_state.set_selection(item);
                 }
@@ -182,6 +449,14 @@ impl TestContext {
                         .set_timeline(timeline);
                 }
 
+                SystemCommand::ResetTimeControl { rec_id } => {
+                    assert_eq!(rec_id, self.recording_store.store_id());
+                    self.recording_config
+                        .time_ctrl
+                        .write()
+                        .reset_all(self.recording_store.times_per_timeline());
+                }
+
                 // not implemented
                 SystemCommand::SetFocus(_)
                 | SystemCommand::ActivateApp(_)
@@ -192,7 +467,6 @@ impl TestContext {
                 | SystemCommand::ResetViewer
                 | SystemCommand::ClearActiveBlueprint
                 | SystemCommand::ClearAndGenerateBlueprint
-                | SystemCommand::ActivateRecording(_)
                 | SystemCommand::CloseStore(_)
                 | SystemCommand::UndoBlueprint { .. }
                 | SystemCommand::RedoBlueprint { .. }
@@ -216,8 +490,12 @@ impl TestContext {
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::Item;
+    use crate::{Item, SystemCommandSender as _};
+    use re_chunk::{Chunk, RowId};
     use re_entity_db::InstancePath;
+    use re_log_types::{
+        example_components::MyIndex, EntityPath, ResolvedTimeRange, TimePoint, Timeline,
+    };
 
     /// Test that `TestContext:edit_selection` works as expected, aka. its side effects are visible
     /// from `TestContext::run`.
@@ -238,4 +516,397 @@ mod test {
             );
         });
     }
+
+    #[test]
+    fn test_switch_recordings() {
+        let mut test_context = TestContext::default();
+        let first_id = test_context.recording_store.store_id();
+        log_index(
+            &mut test_context,
+            "first",
+            TimePoint::default().with(Timeline::new_sequence("frame_nr"), TimeInt::new_temporal(1)),
+        );
+
+        let mut store_info = test_context.recording_store.store_info().unwrap().clone();
+        store_info.store_id = StoreId::random(StoreKind::Recording);
+        let second_id = test_context.add_recording(store_info);
+
+        let active_store_id = |test_context: &TestContext| {
+            let mut active_store_id = None;
+            test_context.run_in_egui_central_panel(|ctx, _| {
+                active_store_id = Some(ctx.store_context.recording.store_id());
+                assert!(!ctx.store_context.bundle.contains(&ctx.recording_id()));
+            });
+            active_store_id.unwrap()
+        };
+
+        assert_eq!(active_store_id(&test_context), first_id);
+
+        test_context.set_active_recording(&second_id);
+        assert_eq!(active_store_id(&test_context), second_id);
+        assert!(!has_data(
+            &test_context,
+            &Timeline::new_sequence("frame_nr"),
+            "first"
+        ));
+
+        // Switching via the system command, like the recording selector does:
+        test_context
+            .command_sender
+            .send_system(SystemCommand::ActivateRecording(first_id.clone()));
+        test_context.handle_system_commands();
+        assert_eq!(active_store_id(&test_context), first_id);
+        assert!(has_data(
+            &test_context,
+            &Timeline::new_sequence("frame_nr"),
+            "first"
+        ));
+    }
+
+    #[test]
+    fn test_set_and_advance_time() {
+        let mut test_context = TestContext::default();
+        let frame_nr = Timeline::new_sequence("frame_nr");
+        let other = Timeline::new_sequence("other");
+
+        for frame in [3, 10] {
+            log_index(
+                &mut test_context,
+                "entity",
+                TimePoint::default()
+                    .with(frame_nr, TimeInt::new_temporal(frame))
+                    .with(other, TimeInt::new_temporal(100 + frame)),
+            );
+        }
+
+        test_context.advance_time(TimeInt::new_temporal(2));
+        {
+            let time_ctrl = test_context.recording_config.time_ctrl.read();
+            assert_eq!(time_ctrl.timeline(), &frame_nr);
+            assert_eq!(time_ctrl.time_i64(), Some(5), "starts at the first event");
+        }
+
+        test_context.set_time("other", TimeInt::new_temporal(105));
+        test_context.advance_time(TimeInt::new_temporal(3));
+
+        test_context.run_in_egui_central_panel(|ctx, _| {
+            let query = ctx.current_query();
+            assert_eq!(query.timeline(), other);
+            assert_eq!(query.at(), TimeInt::new_temporal(108));
+        });
+    }
+
+    #[test]
+    fn test_set_loop_selection() {
+        let mut test_context = TestContext::default();
+        for frame in [0, 100] {
+            log_index(
+                &mut test_context,
+                "entity",
+                TimePoint::default()
+                    .with(
+                        Timeline::new_sequence("frame_nr"),
+                        TimeInt::new_temporal(frame),
+                    )
+                    .with(
+                        Timeline::new_sequence("other"),
+                        TimeInt::new_temporal(frame),
+                    ),
+            );
+        }
+        test_context.set_time("frame_nr", TimeInt::new_temporal(0));
+
+        let loop_selection = |test_context: &TestContext| {
+            test_context
+                .recording_config
+                .time_ctrl
+                .read()
+                .loop_selection()
+        };
+
+        let range = ResolvedTimeRange::new(10, 20);
+        test_context.set_loop_selection(Some(range));
+        assert_eq!(loop_selection(&test_context), Some(range.into()));
+        assert!(
+            test_context
+                .recording_config
+                .time_ctrl
+                .read()
+                .active_loop_selection()
+                .is_none(),
+            "setting a selection doesn't turn on looping"
+        );
+
+        // The selection is per timeline:
+        test_context.set_time("other", TimeInt::new_temporal(0));
+        assert_eq!(loop_selection(&test_context), None);
+        test_context.set_time("frame_nr", TimeInt::new_temporal(0));
+        assert_eq!(loop_selection(&test_context), Some(range.into()));
+
+        test_context.set_loop_selection(None);
+        assert_eq!(loop_selection(&test_context), None);
+    }
+
+    #[test]
+    fn test_save_session_to_file() {
+        let mut test_context = TestContext::default();
+        log_index(
+            &mut test_context,
+            "entity",
+            TimePoint::default().with(Timeline::new_sequence("frame_nr"), TimeInt::new_temporal(1)),
+        );
+
+        let path =
+            std::env::temp_dir().join(format!("{}.rrd", test_context.recording_store.store_id()));
+        test_context.save_session_to_file(&path).unwrap();
+
+        let decoder = re_log_encoding::decoder::Decoder::new(
+            re_log_encoding::VersionPolicy::Error,
+            std::fs::File::open(&path).unwrap(),
+        )
+        .unwrap();
+        let messages = decoder.collect::<Result<Vec<_>, _>>().unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let recording_id = test_context.recording_store.store_id();
+        let blueprint_id = test_context.blueprint_store.store_id();
+
+        let has_store_info = |store_id: &StoreId| {
+            messages.iter().any(
+                |msg| matches!(msg, LogMsg::SetStoreInfo(info) if &info.info.store_id == store_id),
+            )
+        };
+        assert!(has_store_info(&recording_id));
+        assert!(has_store_info(&blueprint_id));
+
+        assert!(messages
+            .iter()
+            .any(|msg| matches!(msg, LogMsg::ArrowMsg(store_id, _) if store_id == &recording_id)));
+        assert!(messages.iter().any(|msg| matches!(
+            msg,
+            LogMsg::BlueprintActivationCommand(cmd) if cmd.blueprint_id == blueprint_id && cmd.make_active
+        )));
+    }
+
+    #[test]
+    fn test_load_rrd() {
+        let frame_nr = Timeline::new_sequence("frame_nr");
+
+        let mut original = TestContext::default();
+        log_index(
+            &mut original,
+            "entity",
+            TimePoint::default().with(frame_nr, TimeInt::new_temporal(1)),
+        );
+
+        let path =
+            std::env::temp_dir().join(format!("{}.rrd", original.recording_store.store_id()));
+        original.save_session_to_file(&path).unwrap();
+
+        let mut test_context = TestContext::default();
+        test_context.load_rrd(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(
+            test_context.recording_store.store_id(),
+            original.recording_store.store_id()
+        );
+        assert_eq!(
+            test_context.blueprint_store.store_id(),
+            original.blueprint_store.store_id()
+        );
+        assert!(has_data(&test_context, &frame_nr, "entity"));
+
+        // The same, but from bytes:
+        let bytes = re_log_encoding::encoder::encode_as_bytes_local(
+            original.recording_store.to_messages(None),
+        )
+        .unwrap();
+        let mut test_context = TestContext::default();
+        test_context.load_rrd_bytes(&bytes).unwrap();
+        assert_eq!(
+            test_context.recording_store.store_id(),
+            original.recording_store.store_id()
+        );
+        assert!(has_data(&test_context, &frame_nr, "entity"));
+    }
+
+    fn log_index(test_context: &mut TestContext, entity_path: &str, timepoint: TimePoint) {
+        let chunk = Chunk::builder(entity_path.into())
+            .with_component_batches(RowId::new(), timepoint, [&MyIndex::from_iter(0..1) as _])
+            .build()
+            .unwrap();
+        test_context
+            .recording_store
+            .add_chunk(&Arc::new(chunk))
+            .unwrap();
+    }
+
+    fn has_data(test_context: &TestContext, timeline: &Timeline, entity_path: &str) -> bool {
+        test_context
+            .recording_store
+            .storage_engine()
+            .store()
+            .entity_has_data_on_timeline(timeline, &EntityPath::from(entity_path))
+    }
+
+    /// Test that emitted system commands can be collected instead of being handled.
+    #[test]
+    fn test_drain_system_commands() {
+        let mut test_context = TestContext::default();
+
+        let item = Item::InstancePath(InstancePath::entity_all("/entity/path".into()));
+
+        test_context.run_in_egui_central_panel(|ctx, _| {
+            ctx.command_sender
+                .send_system(SystemCommand::SetSelection(item.clone()));
+        });
+
+        let commands = test_context.drain_system_commands();
+        assert!(
+            commands.iter().any(|command| matches!(
+                command,
+                SystemCommand::SetSelection(selected) if selected == &item
+            )),
+            "{commands:?}"
+        );
+
+        // The commands were collected, not handled.
+        assert!(test_context.selection_state.selected_items().is_empty());
+        assert!(test_context.drain_system_commands().is_empty());
+    }
+
+    /// Test that dropping an entity from the recording works through the system commands.
+    #[test]
+    fn test_drop_recording_entity() {
+        let mut test_context = TestContext::default();
+        let frame_nr = Timeline::new_sequence("frame_nr");
+        let at = |frame| TimePoint::default().with(frame_nr, TimeInt::new_temporal(frame));
+
+        log_index(&mut test_context, "cleared", at(1));
+        log_index(&mut test_context, "cleared", at(2));
+        log_index(&mut test_context, "cleared/child", at(3));
+        log_index(&mut test_context, "kept", at(4));
+
+        let store_id = test_context.recording_store.store_id();
+        test_context
+            .command_sender
+            .send_system(SystemCommand::DropEntity(store_id, "cleared".into()));
+        test_context.handle_system_commands();
+
+        assert!(!has_data(&test_context, &frame_nr, "cleared"));
+        assert!(!has_data(&test_context, &frame_nr, "cleared/child"));
+        assert!(has_data(&test_context, &frame_nr, "kept"));
+
+        let histogram = test_context
+            .recording_store
+            .time_histogram(&frame_nr)
+            .unwrap();
+        assert_eq!(histogram.range_count(1..=3), 0);
+        assert_eq!(histogram.range_count(4..=4), 1);
+    }
+
+    /// Test that dropping an entity on a single timeline leaves the other timelines alone.
+    #[test]
+    fn test_drop_recording_entity_on_timeline() {
+        let mut test_context = TestContext::default();
+        let frame_nr = Timeline::new_sequence("frame_nr");
+        let other = Timeline::new_sequence("other");
+
+        log_index(
+            &mut test_context,
+            "entity",
+            TimePoint::default().with(frame_nr, TimeInt::new_temporal(1)),
+        );
+        log_index(
+            &mut test_context,
+            "entity/child",
+            TimePoint::default().with(frame_nr, TimeInt::new_temporal(2)),
+        );
+        log_index(
+            &mut test_context,
+            "entity",
+            TimePoint::default().with(other, TimeInt::new_temporal(3)),
+        );
+
+        let store_id = test_context.recording_store.store_id();
+        test_context
+            .command_sender
+            .send_system(SystemCommand::DropEntityOnTimeline {
+                store_id,
+                entity_path: "entity".into(),
+                timeline: frame_nr,
+            });
+        test_context.handle_system_commands();
+
+        assert!(!has_data(&test_context, &frame_nr, "entity"));
+        assert!(!has_data(&test_context, &frame_nr, "entity/child"));
+        assert!(has_data(&test_context, &other, "entity"));
+        assert!(test_context
+            .recording_store
+            .time_histogram(&frame_nr)
+            .map_or(true, |histogram| histogram.is_empty()));
+    }
+
+    #[test]
+    fn test_export_time_range_as_recording() {
+        let mut test_context = TestContext::default();
+        let frame_nr = Timeline::new_sequence("frame_nr");
+
+        // A single chunk spanning the whole range, which has to be split:
+        let mut builder = Chunk::builder("entity".into());
+        for frame in 0..10 {
+            builder = builder.with_component_batches(
+                RowId::new(),
+                TimePoint::default().with(frame_nr, TimeInt::new_temporal(frame)),
+                [&MyIndex::from_iter(0..1) as _],
+            );
+        }
+        test_context
+            .recording_store
+            .add_chunk(&Arc::new(builder.build().unwrap()))
+            .unwrap();
+
+        // Chunks that are either completely inside or outside the range:
+        log_index(
+            &mut test_context,
+            "inside",
+            TimePoint::default().with(frame_nr, TimeInt::new_temporal(4)),
+        );
+        log_index(
+            &mut test_context,
+            "outside",
+            TimePoint::default().with(frame_nr, TimeInt::new_temporal(20)),
+        );
+        log_index(&mut test_context, "static", TimePoint::default());
+
+        let store_id = test_context.recording_store.store_id();
+        test_context
+            .command_sender
+            .send_system(SystemCommand::ExportTimeRangeAsRecording {
+                store_id: store_id.clone(),
+                timeline: frame_nr,
+                time_range: ResolvedTimeRange::new(3, 6),
+            });
+        test_context.handle_system_commands();
+
+        // The export is added as a new recording, and made active:
+        let recording = &test_context.recording_store;
+        assert_ne!(recording.store_id(), store_id);
+        assert!(test_context.inactive_recordings.get(&store_id).is_some());
+
+        let histogram = recording.time_histogram(&frame_nr).unwrap();
+        assert_eq!(histogram.min_key(), Some(3));
+        assert_eq!(histogram.max_key(), Some(6));
+        assert_eq!(histogram.total_count(), 4 + 1);
+
+        assert!(has_data(&test_context, &frame_nr, "entity"));
+        assert!(has_data(&test_context, &frame_nr, "inside"));
+        assert!(!has_data(&test_context, &frame_nr, "outside"));
+        assert!(recording
+            .storage_engine()
+            .store()
+            .iter_chunks()
+            .any(|chunk| chunk.is_static() && chunk.entity_path() == &EntityPath::from("static")));
+    }
 }
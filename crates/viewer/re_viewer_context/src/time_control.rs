@@ -1,6 +1,6 @@
 use std::collections::BTreeMap;
 
-use re_entity_db::{TimeCounts, TimesPerTimeline};
+use re_entity_db::{TimeCounts, TimeHistogram, TimesPerTimeline};
 use re_log_types::{
     Duration, ResolvedTimeRange, ResolvedTimeRangeF, TimeInt, TimeReal, TimeType, Timeline,
 };
@@ -89,6 +89,45 @@ pub enum PlayState {
     Following,
 }
 
+/// Which way time moves when playing.
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, Hash, serde::Deserialize, serde::Serialize,
+)]
+pub enum PlaybackDirection {
+    /// Time moves towards the end of the timeline.
+    #[default]
+    Forward,
+
+    /// Time moves towards the start of the timeline, e.g. to review what led up to an event.
+    Backward,
+}
+
+impl PlaybackDirection {
+    /// Where playback starts when (re)starting from the end.
+    fn start(self, values: &TimeCounts) -> TimeInt {
+        match self {
+            Self::Forward => min(values),
+            Self::Backward => max(values),
+        }
+    }
+
+    /// Has `time` reached the end of `range`, in this direction?
+    fn is_at_end(self, time: TimeReal, range: ResolvedTimeRange) -> bool {
+        match self {
+            Self::Forward => range.max() <= time,
+            Self::Backward => time <= range.min(),
+        }
+    }
+
+    /// Where playback stops in `range`, in this direction.
+    fn end(self, range: ResolvedTimeRange) -> TimeInt {
+        match self {
+            Self::Forward => range.max(),
+            Self::Backward => range.min(),
+        }
+    }
+}
+
 // TODO(andreas): This should be a blueprint property and follow the usual rules of how we determine fallbacks.
 #[derive(serde::Deserialize, serde::Serialize, Clone, PartialEq)]
 enum ActiveTimeline {
@@ -127,6 +166,8 @@ pub struct TimeControl {
 
     looping: Looping,
 
+    playback_direction: PlaybackDirection,
+
     /// Range with special highlight.
     ///
     /// This is used during UI interactions. E.g. to show visual history range that's highlighted.
@@ -143,13 +184,16 @@ impl Default for TimeControl {
             following: true,
             speed: 1.0,
             looping: Looping::Off,
+            playback_direction: PlaybackDirection::Forward,
             highlighted_range: None,
         }
     }
 }
 
 impl TimeControl {
-    /// Move the time forward (if playing), and perhaps pause if we've reached the end.
+    /// Move the time (if playing), and perhaps pause if we've reached the end.
+    ///
+    /// See [`Self::playback_direction`] for which way time moves.
     #[must_use]
     pub fn update(
         &mut self,
@@ -186,11 +230,14 @@ impl TimeControl {
                     .entry(*self.timeline)
                     .or_insert_with(|| TimeState::new(full_range.min()));
 
-                if self.looping == Looping::Off && full_range.max() <= state.time {
+                let direction = self.playback_direction;
+
+                if self.looping == Looping::Off && direction.is_at_end(state.time, full_range) {
                     // We've reached the end of the data
-                    state.time = full_range.max().into();
+                    state.time = direction.end(full_range).into();
 
-                    if more_data_is_coming {
+                    // New data is only ever added at the end, so that's only worth waiting for when going forward.
+                    if more_data_is_coming && direction == PlaybackDirection::Forward {
                         // then let's wait for it without pausing!
                         return NeedsRepaint::No; // ui will wake up when more data arrives
                     } else {
@@ -206,19 +253,33 @@ impl TimeControl {
                 };
 
                 if let Some(loop_range) = loop_range {
-                    state.time = state.time.max(loop_range.min);
+                    state.time = match direction {
+                        PlaybackDirection::Forward => state.time.max(loop_range.min),
+                        PlaybackDirection::Backward => state.time.min(loop_range.max),
+                    };
                 }
 
-                match self.timeline.typ() {
-                    TimeType::Sequence => {
-                        state.time += TimeReal::from(state.fps * dt);
-                    }
-                    TimeType::Time => state.time += TimeReal::from(Duration::from_secs(dt)),
+                let step = match self.timeline.typ() {
+                    TimeType::Sequence => TimeReal::from(state.fps * dt),
+                    TimeType::Time => TimeReal::from(Duration::from_secs(dt)),
+                };
+                match direction {
+                    PlaybackDirection::Forward => state.time += step,
+                    PlaybackDirection::Backward => state.time -= step,
                 }
 
                 if let Some(loop_range) = loop_range {
-                    if loop_range.max < state.time {
-                        state.time = loop_range.min; // loop!
+                    match direction {
+                        PlaybackDirection::Forward => {
+                            if loop_range.max < state.time {
+                                state.time = loop_range.min; // loop!
+                            }
+                        }
+                        PlaybackDirection::Backward => {
+                            if state.time < loop_range.min {
+                                state.time = loop_range.max; // loop!
+                            }
+                        }
                     }
                 }
 
@@ -278,13 +339,14 @@ impl TimeControl {
 
                 // Start from beginning if we are at the end:
                 if let Some(time_points) = times_per_timeline.get(&self.timeline) {
+                    let direction = self.playback_direction;
                     if let Some(state) = self.states.get_mut(&self.timeline) {
-                        if max(time_points) <= state.time {
-                            state.time = min(time_points).into();
+                        if direction.is_at_end(state.time, range(time_points)) {
+                            state.time = direction.start(time_points).into();
                         }
                     } else {
                         self.states
-                            .insert(*self.timeline, TimeState::new(min(time_points)));
+                            .insert(*self.timeline, TimeState::new(direction.start(time_points)));
                     }
                 }
             }
@@ -329,6 +391,31 @@ impl TimeControl {
         }
     }
 
+    /// Move the time marker to the closest event in `events` that lies strictly before the current time.
+    ///
+    /// Unlike [`Self::step_time_back`], this does not wrap around: if there is no earlier event,
+    /// the time is left as is.
+    pub fn jump_to_previous_event(&mut self, events: &TimeHistogram) {
+        if let Some(previous) = self
+            .time()
+            .and_then(|time| previous_event_time(time, events))
+        {
+            self.pause();
+            self.set_time(previous);
+        }
+    }
+
+    /// Move the time marker to the closest event in `events` that lies strictly after the current time.
+    ///
+    /// Unlike [`Self::step_time_fwd`], this does not wrap around: if there is no later event,
+    /// the time is left as is.
+    pub fn jump_to_next_event(&mut self, events: &TimeHistogram) {
+        if let Some(next) = self.time().and_then(|time| next_event_time(time, events)) {
+            self.pause();
+            self.set_time(next);
+        }
+    }
+
     pub fn step_time_fwd(&mut self, times_per_timeline: &TimesPerTimeline) {
         let Some(time_values) = times_per_timeline.get(self.timeline()) else {
             return;
@@ -385,9 +472,10 @@ impl TimeControl {
 
             // Start from beginning if we are at the end:
             if let Some(time_points) = times_per_timeline.get(&self.timeline) {
+                let direction = self.playback_direction;
                 if let Some(state) = self.states.get_mut(&self.timeline) {
-                    if max(time_points) <= state.time {
-                        state.time = min(time_points).into();
+                    if direction.is_at_end(state.time, range(time_points)) {
+                        state.time = direction.start(time_points).into();
                         self.playing = true;
                         self.following = false;
                         return;
@@ -403,6 +491,20 @@ impl TimeControl {
         }
     }
 
+    /// Which way time moves when playing.
+    pub fn playback_direction(&self) -> PlaybackDirection {
+        self.playback_direction
+    }
+
+    /// Set which way time moves when playing.
+    pub fn set_playback_direction(&mut self, playback_direction: PlaybackDirection) {
+        self.playback_direction = playback_direction;
+        if playback_direction == PlaybackDirection::Backward {
+            // It makes no sense to play backwards and follow the latest data.
+            self.following = false;
+        }
+    }
+
     /// playback speed
     pub fn speed(&self) -> f32 {
         self.speed
@@ -446,6 +548,26 @@ impl TimeControl {
         }
     }
 
+    /// Switch to the timeline after (or before) the current one in `timelines`, wrapping around.
+    ///
+    /// If the current timeline isn't part of `timelines`, the first one is selected.
+    pub fn cycle_timeline(&mut self, timelines: &[Timeline], forward: bool) {
+        let num_timelines = timelines.len();
+        if num_timelines == 0 {
+            return;
+        }
+
+        let index = match timelines
+            .iter()
+            .position(|timeline| timeline == self.timeline())
+        {
+            Some(index) if forward => (index + 1) % num_timelines,
+            Some(index) => (index + num_timelines - 1) % num_timelines,
+            None => 0,
+        };
+        self.set_timeline(timelines[index]);
+    }
+
     /// The currently selected timeline
     #[inline]
     pub fn timeline(&self) -> &Timeline {
@@ -571,6 +693,14 @@ impl TimeControl {
             state.view = None;
         }
     }
+
+    /// Reset the current timeline to how it was when first opened: no loop selection,
+    /// the whole timeline in view, and the time at the start.
+    pub fn reset_all(&mut self, times_per_timeline: &TimesPerTimeline) {
+        self.remove_loop_selection();
+        self.reset_time_view();
+        self.restart(times_per_timeline);
+    }
 }
 
 fn min(values: &TimeCounts) -> TimeInt {
@@ -656,3 +786,274 @@ fn step_back_time_looped(
         step_back_time(time, values).into()
     }
 }
+
+/// The time of the closest event in `events` strictly before `time`, if any.
+fn previous_event_time(time: TimeReal, events: &TimeHistogram) -> Option<TimeInt> {
+    // The histogram iterator is forward-only, so walk up to the cursor and keep the last key.
+    events
+        .range(..time.ceil().as_i64(), 1)
+        .last()
+        .map(|(range, _count)| TimeInt::new_temporal(range.max))
+}
+
+/// The time of the closest event in `events` strictly after `time`, if any.
+fn next_event_time(time: TimeReal, events: &TimeHistogram) -> Option<TimeInt> {
+    events
+        .range(
+            (
+                std::ops::Bound::Excluded(time.floor().as_i64()),
+                std::ops::Bound::Unbounded,
+            ),
+            1,
+        )
+        .next()
+        .map(|(range, _count)| TimeInt::new_temporal(range.min))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn histogram(times: &[i64]) -> TimeHistogram {
+        let mut hist = TimeHistogram::default();
+        for &time in times {
+            hist.increment(time, 1);
+        }
+        hist
+    }
+
+    #[test]
+    fn event_navigation() {
+        let events = histogram(&[-5, 10, 10, 20, 1_000]);
+
+        let next = |time: f64| next_event_time(TimeReal::from(time), &events).map(TimeInt::as_i64);
+        let previous =
+            |time: f64| previous_event_time(TimeReal::from(time), &events).map(TimeInt::as_i64);
+
+        assert_eq!(next(-100.0), Some(-5));
+        assert_eq!(
+            next(-5.0),
+            Some(10),
+            "the event under the cursor is skipped"
+        );
+        assert_eq!(next(10.5), Some(20));
+        assert_eq!(next(999.9), Some(1_000));
+        assert_eq!(next(1_000.0), None);
+
+        assert_eq!(previous(2_000.0), Some(1_000));
+        assert_eq!(
+            previous(1_000.0),
+            Some(20),
+            "the event under the cursor is skipped"
+        );
+        assert_eq!(previous(10.5), Some(10));
+        assert_eq!(previous(-4.5), Some(-5));
+        assert_eq!(previous(-5.0), None);
+
+        assert_eq!(next_event_time(TimeReal::from(0.0), &histogram(&[])), None);
+        assert_eq!(
+            previous_event_time(TimeReal::from(0.0), &histogram(&[])),
+            None
+        );
+    }
+
+    #[test]
+    fn jump_to_event() {
+        let events = histogram(&[10, 20, 30]);
+
+        let mut time_ctrl = TimeControl::default();
+        time_ctrl.set_time(TimeInt::new_temporal(15));
+
+        time_ctrl.jump_to_next_event(&events);
+        assert_eq!(time_ctrl.time_i64(), Some(20));
+        time_ctrl.jump_to_next_event(&events);
+        assert_eq!(time_ctrl.time_i64(), Some(30));
+        time_ctrl.jump_to_next_event(&events);
+        assert_eq!(time_ctrl.time_i64(), Some(30), "no wrap-around");
+
+        time_ctrl.jump_to_previous_event(&events);
+        time_ctrl.jump_to_previous_event(&events);
+        time_ctrl.jump_to_previous_event(&events);
+        assert_eq!(time_ctrl.time_i64(), Some(10), "no wrap-around");
+    }
+
+    /// Log an event for a single entity at `timepoint`.
+    fn log_event(db: &mut re_entity_db::EntityDb, timepoint: impl Into<re_log_types::TimePoint>) {
+        use re_chunk::{Chunk, RowId};
+        use re_log_types::example_components::MyIndex;
+
+        let chunk = Chunk::builder("entity".into())
+            .with_component_batches(RowId::new(), timepoint, [&MyIndex::from_iter(0..1) as _])
+            .build()
+            .unwrap();
+        db.add_chunk(&std::sync::Arc::new(chunk)).unwrap();
+    }
+
+    /// An [`re_entity_db::EntityDb`] with an event at each of `frames` on `timeline`.
+    fn db_with_frames(timeline: Timeline, frames: &[i64]) -> re_entity_db::EntityDb {
+        use re_log_types::{StoreId, StoreKind};
+
+        let mut db = re_entity_db::EntityDb::new(StoreId::random(StoreKind::Recording));
+        for &frame in frames {
+            log_event(&mut db, [(timeline, frame)]);
+        }
+        db
+    }
+
+    #[test]
+    fn following_tracks_latest_data() {
+        let timeline = Timeline::new_sequence("frame");
+        let mut entity_db = db_with_frames(timeline, &[1]);
+
+        let mut time_ctrl = TimeControl::default();
+        time_ctrl.set_play_state(entity_db.times_per_timeline(), PlayState::Following);
+        let _ = time_ctrl.update(entity_db.times_per_timeline(), 0.1, true);
+        assert_eq!(time_ctrl.time_i64(), Some(1));
+
+        for frame in [5, 10] {
+            log_event(&mut entity_db, [(timeline, frame)]);
+            let _ = time_ctrl.update(entity_db.times_per_timeline(), 0.1, true);
+            assert_eq!(time_ctrl.time_i64(), Some(frame), "should follow new data");
+        }
+
+        // This is what dragging the time marker does:
+        time_ctrl.set_time(TimeInt::new_temporal(5));
+        time_ctrl.pause();
+
+        log_event(&mut entity_db, [(timeline, 20_i64)]);
+        let _ = time_ctrl.update(entity_db.times_per_timeline(), 0.1, true);
+        assert_eq!(time_ctrl.play_state(), PlayState::Paused);
+        assert_eq!(
+            time_ctrl.time_i64(),
+            Some(5),
+            "should stay put after the user moved the time"
+        );
+    }
+
+    #[test]
+    fn backward_playback() {
+        let timeline = Timeline::new_sequence("frame");
+        let db = db_with_frames(timeline, &[10, 20, 30]);
+        let times_per_timeline = db.times_per_timeline();
+
+        let mut time_ctrl = TimeControl::default();
+        time_ctrl.set_timeline_and_time(timeline, TimeInt::new_temporal(30));
+        time_ctrl.set_playback_direction(PlaybackDirection::Backward);
+        time_ctrl.set_play_state(times_per_timeline, PlayState::Playing);
+        assert_eq!(
+            time_ctrl.time_i64(),
+            Some(30),
+            "the end of the timeline is the start when playing backwards"
+        );
+
+        // At 30 fps, each update of 0.1s moves three frames.
+        let mut previous_time = time_ctrl.time().unwrap();
+        for _ in 0..3 {
+            let _ = time_ctrl.update(times_per_timeline, 0.1, false);
+            let time = time_ctrl.time().unwrap();
+            assert!(
+                time < previous_time,
+                "{time:?} should be before {previous_time:?}"
+            );
+            previous_time = time;
+        }
+        assert_eq!(time_ctrl.time_i64(), Some(21));
+
+        for _ in 0..10 {
+            let _ = time_ctrl.update(times_per_timeline, 0.1, false);
+        }
+        assert_eq!(time_ctrl.time_i64(), Some(10), "clamped to the start");
+        assert_eq!(time_ctrl.play_state(), PlayState::Paused);
+
+        // Playing again starts over from the end:
+        time_ctrl.set_play_state(times_per_timeline, PlayState::Playing);
+        assert_eq!(time_ctrl.time_i64(), Some(30));
+    }
+
+    #[test]
+    fn backward_playback_loops() {
+        let timeline = Timeline::new_sequence("frame");
+        let db = db_with_frames(timeline, &[10, 20, 30]);
+        let times_per_timeline = db.times_per_timeline();
+
+        let mut time_ctrl = TimeControl::default();
+        time_ctrl.set_timeline_and_time(timeline, TimeInt::new_temporal(12));
+        time_ctrl.set_playback_direction(PlaybackDirection::Backward);
+        time_ctrl.set_looping(Looping::All);
+        time_ctrl.set_play_state(times_per_timeline, PlayState::Playing);
+
+        let _ = time_ctrl.update(times_per_timeline, 0.1, false);
+        assert_eq!(time_ctrl.time_i64(), Some(30), "wraps around to the end");
+        assert_eq!(time_ctrl.play_state(), PlayState::Playing);
+
+        time_ctrl.set_loop_selection(ResolvedTimeRangeF::new(15, 25));
+        time_ctrl.set_looping(Looping::Selection);
+        let _ = time_ctrl.update(times_per_timeline, 0.1, false);
+        assert_eq!(
+            time_ctrl.time_i64(),
+            Some(22),
+            "starts from the end of the loop selection"
+        );
+        for _ in 0..3 {
+            let _ = time_ctrl.update(times_per_timeline, 0.1, false);
+        }
+        assert_eq!(time_ctrl.time_i64(), Some(25), "wraps around");
+    }
+
+    #[test]
+    fn reset_all() {
+        let timeline = Timeline::new_sequence("frame");
+        let db = db_with_frames(timeline, &[10, 20, 30]);
+        let times_per_timeline = db.times_per_timeline();
+
+        let mut time_ctrl = TimeControl::default();
+        time_ctrl.set_timeline_and_time(timeline, TimeInt::new_temporal(25));
+        time_ctrl.set_loop_selection(ResolvedTimeRangeF::new(15, 25));
+        time_ctrl.set_looping(Looping::Selection);
+        time_ctrl.set_time_view(TimeView {
+            min: TimeReal::from(15_i64),
+            time_spanned: 10.0,
+        });
+
+        time_ctrl.reset_all(times_per_timeline);
+
+        assert_eq!(time_ctrl.timeline(), &timeline);
+        assert_eq!(time_ctrl.time_i64(), Some(10));
+        assert_eq!(time_ctrl.loop_selection(), None);
+        assert_eq!(time_ctrl.looping(), Looping::Off);
+        assert_eq!(time_ctrl.time_view(), None);
+    }
+
+    #[test]
+    fn cycle_timelines() {
+        let timelines = [
+            Timeline::new_sequence("frame_nr"),
+            Timeline::new_temporal("sim_time"),
+            Timeline::log_time(),
+        ];
+
+        let mut time_ctrl = TimeControl::default();
+        time_ctrl.set_timeline(timelines[0]);
+
+        time_ctrl.cycle_timeline(&timelines, true);
+        assert_eq!(time_ctrl.timeline(), &timelines[1]);
+        time_ctrl.cycle_timeline(&timelines, true);
+        assert_eq!(time_ctrl.timeline(), &timelines[2]);
+        time_ctrl.cycle_timeline(&timelines, true);
+        assert_eq!(time_ctrl.timeline(), &timelines[0], "wraps around");
+
+        time_ctrl.cycle_timeline(&timelines, false);
+        assert_eq!(time_ctrl.timeline(), &timelines[2], "wraps around");
+        time_ctrl.cycle_timeline(&timelines, false);
+        assert_eq!(time_ctrl.timeline(), &timelines[1]);
+
+        // A timeline that isn't part of the cycle starts it over:
+        time_ctrl.set_timeline(Timeline::new_sequence("other"));
+        time_ctrl.cycle_timeline(&timelines, false);
+        assert_eq!(time_ctrl.timeline(), &timelines[0]);
+
+        // Nothing to cycle through:
+        time_ctrl.cycle_timeline(&[], true);
+        assert_eq!(time_ctrl.timeline(), &timelines[0]);
+    }
+}
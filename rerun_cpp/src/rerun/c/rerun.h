@@ -145,6 +145,34 @@ typedef struct rr_spawn_options {
     rr_string executable_path;
 } rr_spawn_options;
 
+/// Options to control the behavior of `rr_recording_stream_connect_with_options`.
+///
+/// Refer to the field-level documentation for more information about each individual options.
+typedef struct rr_connect_options {
+    /// The minimum time the SDK will wait during a flush before potentially
+    /// dropping data if progress is not being made.
+    ///
+    /// Passing a negative value indicates no timeout, and can cause a call to `flush` to block
+    /// indefinitely.
+    float flush_timeout_sec;
+
+    /// Upper limit on how many bytes of not-yet-sent data the SDK buffers for this connection.
+    ///
+    /// Unlimited if set to `0`.
+    uint64_t max_memory_bytes;
+
+    /// What to do once `max_memory_bytes` is reached.
+    ///
+    /// If true, the SDK waits until enough data has been sent, for at most `flush_timeout_sec`
+    /// without progress, after which the new data is dropped after all.
+    /// Otherwise, new data is dropped right away.
+    ///
+    /// Either way, logging calls never block: the waiting happens on a background thread, and
+    /// data that is waiting there piles up in the recording stream's own queues, which are not
+    /// bounded by `max_memory_bytes`.
+    bool blocking_when_full;
+} rr_connect_options;
+
 /// Recommended settings for the [`DataLoader`].
 ///
 /// The loader is free to ignore some or all of these.
@@ -180,6 +208,15 @@ typedef struct rr_store_info {
 
     /// `RR_STORE_KIND_RECORDING` or `RR_STORE_KIND_BLUEPRINT`
     rr_store_kind store_kind;
+
+    /// The fully-qualified id of the store being logged to.
+    ///
+    /// Use this if several processes should contribute to the same store.
+    /// Takes precedence over `recording_id` if set.
+    /// Must not be empty if set.
+    ///
+    /// Unspecified (null) by default.
+    rr_string store_id;
 } rr_store_info;
 
 /// Definition of a component descriptor that can be registered.
@@ -286,6 +323,17 @@ typedef struct rr_timeline {
     rr_time_type type;
 } rr_timeline;
 
+/// A single time value on a given timeline.
+typedef struct rr_time_point_entry {
+    /// The timeline this time value belongs to.
+    rr_timeline timeline;
+
+    /// The time value.
+    ///
+    /// Nanoseconds for `RR_TIME_TYPE_TIME` timelines, sequence number for `RR_TIME_TYPE_SEQUENCE`.
+    int64_t time;
+} rr_time_point_entry;
+
 /// A column of timestamps for a given timeline.
 typedef struct rr_time_column {
     /// The timeline this column belongs to.
@@ -323,6 +371,7 @@ enum {
     RR_ERROR_CODE_RECORDING_STREAM_STDOUT_FAILURE,
     RR_ERROR_CODE_RECORDING_STREAM_SPAWN_FAILURE,
     RR_ERROR_CODE_RECORDING_STREAM_CHUNK_VALIDATION_FAILURE,
+    RR_ERROR_CODE_RECORDING_STREAM_MEMORY_SINK_FAILURE,
 
     // Arrow data processing errors.
     _RR_ERROR_CODE_CATEGORY_ARROW = 0x00001000,
@@ -377,6 +426,14 @@ extern rr_component_type_handle rr_register_component_type(
     rr_component_type component_type, rr_error* error
 );
 
+/// Looks up the handle of an already registered component type by its descriptor.
+///
+/// Returns `RR_COMPONENT_TYPE_HANDLE_INVALID` if no component type with exactly this descriptor
+/// has been registered yet. This is not an error.
+extern rr_component_type_handle rr_lookup_component_type(
+    rr_component_descriptor descriptor, rr_error* error
+);
+
 /// Creates a new recording stream to log to.
 ///
 /// You must call this at least once to enable logging.
@@ -397,8 +454,17 @@ extern rr_recording_stream rr_recording_stream_new(
 /// Does nothing for `RR_REC_STREAM_CURRENT_RECORDING` and `RR_REC_STREAM_CURRENT_BLUEPRINT`.
 ///
 /// No-op for destroyed/non-existing streams.
+///
+/// The handle may be reused by a recording stream created later on, but only once all other
+/// handles have been handed out.
 extern void rr_recording_stream_free(rr_recording_stream stream);
 
+/// Returns the number of recording streams that have been created but not yet freed.
+///
+/// Does not count `RR_REC_STREAM_CURRENT_RECORDING` and `RR_REC_STREAM_CURRENT_BLUEPRINT`.
+/// Useful to detect leaked recording streams.
+extern uint32_t rr_recording_stream_count(void);
+
 /// Replaces the currently active recording of the specified type in the global scope with
 /// the specified one.
 extern void rr_recording_stream_set_global(rr_recording_stream stream, rr_store_kind store_kind);
@@ -412,6 +478,16 @@ extern void rr_recording_stream_set_thread_local(
 /// Check whether the recording stream is enabled.
 extern bool rr_recording_stream_is_enabled(rr_recording_stream stream, rr_error* error);
 
+/// Returns the id of the recording the stream logs to, as a new null-terminated string.
+///
+/// This is the id passed in `rr_store_info` on creation, or the random id that was chosen
+/// if none was specified.
+///
+/// The returned string must be freed with `_rr_free_string`.
+///
+/// Returns `nullptr` on failure (e.g. invalid handle or disabled stream).
+extern char* rr_recording_stream_recording_id(rr_recording_stream stream, rr_error* error);
+
 /// Connect to a remote Rerun Viewer on the given ip:port.
 ///
 /// Requires that you first start a Rerun Viewer by typing 'rerun' in a terminal.
@@ -427,6 +503,19 @@ extern void rr_recording_stream_connect(
     rr_recording_stream stream, rr_string tcp_addr, float flush_timeout_sec, rr_error* error
 );
 
+/// Connect to a remote Rerun Viewer on the given ip:port, with additional connection options.
+///
+/// Like `rr_recording_stream_connect`, but allows bounding how much data is buffered while
+/// the connection can't keep up. Refer to `rr_connect_options` for details.
+/// Passing null for `connect_opts` is valid and will result in the recommended defaults.
+///
+/// This function returns immediately and will only raise an error for argument parsing errors,
+/// not for connection errors as these happen asynchronously.
+extern void rr_recording_stream_connect_with_options(
+    rr_recording_stream stream, rr_string tcp_addr, const rr_connect_options* connect_opts,
+    rr_error* error
+);
+
 /// Spawns a new Rerun Viewer process from an executable available in PATH, then connects to it
 /// over TCP.
 ///
@@ -464,12 +553,51 @@ extern void rr_recording_stream_save(rr_recording_stream stream, rr_string path,
 /// This function returns immediately.
 extern void rr_recording_stream_stdout(rr_recording_stream stream, rr_error* error);
 
+/// Keep all log-data in memory, until retrieved with `rr_recording_stream_drain_memory`.
+///
+/// This function returns immediately.
+extern void rr_recording_stream_memory_sink(rr_recording_stream stream, rr_error* error);
+
+/// Flushes the stream and retrieves all log-data accumulated in its memory sink since the
+/// last drain, encoded as a self-contained `.rrd`.
+///
+/// The stream must have been switched to a memory sink with `rr_recording_stream_memory_sink`,
+/// and not to another sink since.
+///
+/// The returned bytes must be freed with `rr_bytes_free`.
+extern void rr_recording_stream_drain_memory(
+    rr_recording_stream stream, rr_bytes* out_bytes, rr_error* error
+);
+
+/// Frees bytes returned by `rr_recording_stream_drain_memory`.
+extern void rr_bytes_free(rr_bytes bytes);
+
 /// Initiates a flush the batching pipeline and waits for it to propagate.
 ///
 /// See `rr_recording_stream` docs for ordering semantics and multithreading guarantees.
+/// The stream remains valid after this call.
 /// No-op for destroyed/non-existing streams.
 extern void rr_recording_stream_flush_blocking(rr_recording_stream stream);
 
+/// Callback invoked once an asynchronous flush has completed.
+typedef void (*rr_flush_callback)(void* user_data);
+
+/// Initiates a flush the batching pipeline without blocking the calling thread.
+///
+/// The flush is carried out on a background thread, which invokes `callback` with `user_data`
+/// once the flush has propagated.
+/// The callback is therefore called from a different thread than the one calling this function,
+/// so anything `user_data` points to must be safe to access from there.
+///
+/// See `rr_recording_stream` docs for ordering semantics and multithreading guarantees:
+/// the flush covers all data previously sent by the thread calling this function.
+///
+/// The stream remains valid after this call and may be freed before the callback fired,
+/// in which case the pending data is still flushed.
+extern void rr_recording_stream_flush_async(
+    rr_recording_stream stream, rr_flush_callback callback, void* user_data, rr_error* error
+);
+
 /// Set the current time of the recording, for the current calling thread.
 ///
 /// Used for all subsequent logging performed from this same thread, until the next call
@@ -503,6 +631,22 @@ extern void rr_recording_stream_set_time_nanos(
     rr_recording_stream stream, rr_string timeline_name, int64_t ns, rr_error* error
 );
 
+/// Set the current time of the recording on several timelines at once, for the current calling thread.
+///
+/// `timelines` and `values` must both point to `count` elements.
+/// Each value is interpreted according to the type of its timeline,
+/// i.e. as a sequence number or as nanoseconds.
+///
+/// Timelines not mentioned keep their current time.
+/// If any of the timelines is invalid, an error is reported and none of the times are set.
+///
+/// Used for all subsequent logging performed from this same thread, until the next call
+/// to one of the time setting methods.
+extern void rr_recording_stream_set_time_point(
+    rr_recording_stream stream, const rr_timeline* timelines, const int64_t* values,
+    uint32_t count, rr_error* error
+);
+
 /// Stops logging to the specified timeline for subsequent log calls.
 ///
 /// The timeline is still there, but will not be updated with any new data.
@@ -532,6 +676,25 @@ extern void rr_recording_stream_log(
     rr_recording_stream stream, rr_data_row data_row, bool inject_time, rr_error* error
 );
 
+/// Log the given data to the given stream at an explicit point in time.
+///
+/// Unlike `rr_recording_stream_log`, this ignores any time set on the recording stream via
+/// the `rr_recording_stream_set_time_sequence`/`rr_recording_stream_set_time_nanos`/etc. APIs,
+/// and does _not_ inject the default `log_tick` and `log_time` timelines.
+/// Instead, the row is logged at exactly the given time point.
+///
+/// If the same timeline is specified several times in `time_point_entries`, the last one wins.
+/// Passing no entries at all logs the data as static.
+///
+/// Takes ownership of the passed data component batches and will release underlying
+/// arrow data once it is no longer needed.
+/// Any pointers passed via `rr_string` can be safely freed after this call.
+extern void rr_recording_stream_log_with_time(
+    rr_recording_stream stream, rr_data_row data_row,
+    const rr_time_point_entry* time_point_entries, uint32_t num_time_point_entries,
+    rr_error* error
+);
+
 /// Logs the file at the given `path` using all `DataLoader`s available.
 ///
 /// A single `path` might be handled by more than one loader.
@@ -609,7 +772,21 @@ extern char* _rr_escape_entity_path_part(rr_string part);
 
 /// PRIVATE FUNCTION: do not use.
 ///
-/// Must only be called with the results from `_rr_escape_entity_path_part`.
+/// Decode an encoded `.rrd`, e.g. from `rr_recording_stream_drain_memory`, into a description of
+/// the chunks it contains, for testing.
+///
+/// Each chunk is described on its own line, as its entity path, followed by the times of its rows
+/// on each timeline (e.g. `frame=[1, 2]`), and the names of its components.
+///
+/// The returned string must be freed with `_rr_free_string`.
+///
+/// Returns `nullptr` on failure (e.g. the bytes aren't a valid `.rrd`).
+extern char* _rr_describe_rrd(rr_bytes bytes);
+
+/// PRIVATE FUNCTION: do not use.
+///
+/// Must only be called with the results from `_rr_escape_entity_path_part`, `_rr_describe_rrd`,
+/// or `rr_recording_stream_recording_id`.
 extern void _rr_free_string(char* string);
 
 // ----------------------------------------------------------------------------
@@ -51,6 +51,7 @@ namespace rerun {
         RecordingStreamStdoutFailure,
         RecordingStreamSpawnFailure,
         RecordingStreamChunkValidationFailure,
+        RecordingStreamMemorySinkFailure,
 
         // Arrow data processing errors.
         _CategoryArrow = 0x0000'1000,
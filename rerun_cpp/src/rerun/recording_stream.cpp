@@ -39,6 +39,7 @@ namespace rerun {
         rr_store_info store_info;
         store_info.application_id = detail::to_rr_string(app_id);
         store_info.recording_id = detail::to_rr_string(recording_id);
+        store_info.store_id = detail::to_rr_string(std::nullopt);
         store_info.store_kind = store_kind_to_c(store_kind);
 
         rr_error status = {};
@@ -1,5 +1,6 @@
 #include <catch2/catch_test_macros.hpp>
 #include <rerun/component_type.hpp>
+#include <rerun/string_utils.hpp>
 
 #include <rerun/c/rerun.h>
 
@@ -32,4 +33,38 @@ SCENARIO("Component type registration" TEST_TAG) {
             }
         }
     }
+
+    GIVEN("A component type that was registered once") {
+        rerun::ComponentType type("test_lookup", arrow::float64());
+        auto registered = type.register_component();
+        REQUIRE(registered.is_ok());
+
+        rr_component_descriptor descriptor = {};
+        descriptor.component_name = rerun::detail::to_rr_string(type.descriptor.component_name);
+
+        WHEN("it is looked up by its descriptor") {
+            rr_error error = {};
+            auto handle = rr_lookup_component_type(descriptor, &error);
+
+            THEN("the same handle is returned") {
+                CHECK(error.code == RR_ERROR_CODE_OK);
+                CHECK(handle == registered.value);
+            }
+        }
+    }
+
+    GIVEN("A descriptor that was never registered") {
+        rr_component_descriptor descriptor = {};
+        descriptor.component_name = rerun::detail::to_rr_string(std::string_view("test_never_registered"));
+
+        WHEN("it is looked up") {
+            rr_error error = {};
+            auto handle = rr_lookup_component_type(descriptor, &error);
+
+            THEN("the invalid handle is returned without an error") {
+                CHECK(error.code == RR_ERROR_CODE_OK);
+                CHECK(handle == RR_COMPONENT_TYPE_HANDLE_INVALID);
+            }
+        }
+    }
 }
@@ -1,11 +1,17 @@
 #include <array>
+#include <atomic>
+#include <chrono>
+#include <cstring>
 #include <filesystem>
 #include <optional>
+#include <string>
+#include <thread>
 #include <vector>
 
 #include <arrow/buffer.h>
 #include <catch2/catch_test_macros.hpp>
 #include <catch2/generators/catch_generators.hpp>
+#include <catch2/matchers/catch_matchers_string.hpp>
 #include <rerun.hpp>
 
 #include <rerun/c/rerun.h>
@@ -140,6 +146,574 @@ SCENARIO("RecordingStream can be set as global and thread local", TEST_TAG) {
     }
 }
 
+/// Creates an `rr_string` from a null-terminated string, for calling the C API directly.
+static rr_string c_string(const char* str) {
+    rr_string result;
+    result.utf8 = str;
+    result.length_in_bytes = str ? static_cast<uint32_t>(std::strlen(str)) : 0;
+    return result;
+}
+
+/// Drains the memory sink of a stream, and describes the chunks it contained.
+///
+/// See `_rr_describe_rrd` for the format.
+static std::string drain_and_describe(rr_recording_stream stream) {
+    rr_error error = {};
+    rr_bytes bytes = {};
+    rr_recording_stream_drain_memory(stream, &bytes, &error);
+    REQUIRE(error.code == RR_ERROR_CODE_OK);
+
+    char* c_description = _rr_describe_rrd(bytes);
+    rr_bytes_free(bytes);
+    REQUIRE(c_description != nullptr);
+
+    const std::string description = c_description;
+    _rr_free_string(c_description);
+    return description;
+}
+
+SCENARIO("RecordingStream can be created with an explicit store id via the C API", TEST_TAG) {
+    const auto kind = GENERATE(RR_STORE_KIND_RECORDING, RR_STORE_KIND_BLUEPRINT);
+
+    GIVEN("store info with application id and store kind " << kind) {
+        rr_store_info store_info = {};
+        store_info.application_id = c_string("rerun_example_test");
+        store_info.recording_id = c_string(nullptr);
+        store_info.store_kind = static_cast<rr_store_kind>(kind);
+
+        AND_GIVEN("a valid store id, and a recording id it takes precedence over") {
+            store_info.store_id = c_string("my-shared-store");
+            store_info.recording_id = c_string("my-recording");
+
+            THEN("the new stream logs to that store") {
+                rr_error error = {};
+                const auto stream = rr_recording_stream_new(&store_info, true, &error);
+                REQUIRE(error.code == RR_ERROR_CODE_OK);
+
+                char* id = rr_recording_stream_recording_id(stream, &error);
+                REQUIRE(error.code == RR_ERROR_CODE_OK);
+                REQUIRE(id != nullptr);
+                CHECK(std::string(id) == "my-shared-store");
+
+                _rr_free_string(id);
+                rr_recording_stream_free(stream);
+            }
+        }
+        AND_GIVEN("an empty store id") {
+            store_info.store_id = c_string("");
+
+            THEN("creating a new stream fails with an invalid string argument error") {
+                rr_error error = {};
+                rr_recording_stream_new(&store_info, true, &error);
+                CHECK(error.code == RR_ERROR_CODE_INVALID_STRING_ARGUMENT);
+            }
+        }
+        AND_GIVEN("a store id with invalid utf8") {
+            store_info.store_id = c_string("\xc3\x28");
+
+            THEN("creating a new stream fails with an invalid string argument error") {
+                rr_error error = {};
+                rr_recording_stream_new(&store_info, true, &error);
+                CHECK(error.code == RR_ERROR_CODE_INVALID_STRING_ARGUMENT);
+            }
+        }
+    }
+}
+
+SCENARIO("RecordingStream reports its recording id via the C API", TEST_TAG) {
+    GIVEN("store info without a recording id") {
+        rr_store_info store_info = {};
+        store_info.application_id = c_string("rerun_example_test");
+        store_info.recording_id = c_string(nullptr);
+        store_info.store_id = c_string(nullptr);
+        store_info.store_kind = RR_STORE_KIND_RECORDING;
+
+        WHEN("creating a new stream") {
+            rr_error error = {};
+            const auto stream = rr_recording_stream_new(&store_info, true, &error);
+            REQUIRE(error.code == RR_ERROR_CODE_OK);
+
+            THEN("a non-empty recording id can be read back") {
+                char* recording_id = rr_recording_stream_recording_id(stream, &error);
+                CHECK(error.code == RR_ERROR_CODE_OK);
+                REQUIRE(recording_id != nullptr);
+                CHECK(std::strlen(recording_id) > 0);
+                _rr_free_string(recording_id);
+            }
+
+            rr_recording_stream_free(stream);
+        }
+    }
+    GIVEN("store info with an explicit recording id") {
+        rr_store_info store_info = {};
+        store_info.application_id = c_string("rerun_example_test");
+        store_info.recording_id = c_string("my-recording");
+        store_info.store_id = c_string(nullptr);
+        store_info.store_kind = RR_STORE_KIND_RECORDING;
+
+        WHEN("creating a new stream") {
+            rr_error error = {};
+            const auto stream = rr_recording_stream_new(&store_info, true, &error);
+            REQUIRE(error.code == RR_ERROR_CODE_OK);
+
+            THEN("the same recording id is read back") {
+                char* recording_id = rr_recording_stream_recording_id(stream, &error);
+                REQUIRE(recording_id != nullptr);
+                CHECK(std::string(recording_id) == "my-recording");
+                _rr_free_string(recording_id);
+            }
+
+            rr_recording_stream_free(stream);
+        }
+    }
+    GIVEN("an invalid recording stream handle") {
+        THEN("reading the recording id fails") {
+            rr_error error = {};
+            CHECK(rr_recording_stream_recording_id(0xDEADBEEF, &error) == nullptr);
+            CHECK(error.code == RR_ERROR_CODE_INVALID_RECORDING_STREAM_HANDLE);
+        }
+    }
+}
+
+SCENARIO("RecordingStream handles are not reused right after being freed", TEST_TAG) {
+    GIVEN("two new recording streams") {
+        rr_store_info store_info = {};
+        store_info.application_id = c_string("rerun_example_test");
+        store_info.recording_id = c_string(nullptr);
+        store_info.store_id = c_string(nullptr);
+        store_info.store_kind = RR_STORE_KIND_RECORDING;
+
+        rr_error error = {};
+        const auto num_streams_before = rr_recording_stream_count();
+        const auto first = rr_recording_stream_new(&store_info, true, &error);
+        REQUIRE(error.code == RR_ERROR_CODE_OK);
+        const auto second = rr_recording_stream_new(&store_info, true, &error);
+        REQUIRE(error.code == RR_ERROR_CODE_OK);
+        CHECK(rr_recording_stream_count() == num_streams_before + 2);
+
+        WHEN("freeing the second one") {
+            rr_recording_stream_free(second);
+
+            THEN("it is no longer counted") {
+                CHECK(rr_recording_stream_count() == num_streams_before + 1);
+            }
+            AND_WHEN("freeing it again") {
+                rr_recording_stream_free(second);
+
+                THEN("nothing happens") {
+                    CHECK(rr_recording_stream_count() == num_streams_before + 1);
+                }
+            }
+            AND_WHEN("creating a third stream") {
+                const auto third = rr_recording_stream_new(&store_info, true, &error);
+                REQUIRE(error.code == RR_ERROR_CODE_OK);
+
+                THEN("it gets a handle of its own") {
+                    CHECK(third != first);
+                    CHECK(third != second);
+                }
+                THEN("the freed handle is still reported as invalid") {
+                    rr_recording_stream_set_time_sequence(second, c_string("frame"), 1, &error);
+                    CHECK(error.code == RR_ERROR_CODE_INVALID_RECORDING_STREAM_HANDLE);
+                }
+
+                rr_recording_stream_free(third);
+            }
+        }
+
+        rr_recording_stream_free(first);
+        rr_recording_stream_free(second);
+        CHECK(rr_recording_stream_count() == num_streams_before);
+    }
+}
+
+SCENARIO("RecordingStream can be flushed asynchronously via the C API", TEST_TAG) {
+    GIVEN("a new recording stream") {
+        rr_store_info store_info = {};
+        store_info.application_id = c_string("rerun_example_test");
+        store_info.recording_id = c_string(nullptr);
+        store_info.store_id = c_string(nullptr);
+        store_info.store_kind = RR_STORE_KIND_RECORDING;
+
+        rr_error error = {};
+        const auto stream = rr_recording_stream_new(&store_info, true, &error);
+        REQUIRE(error.code == RR_ERROR_CODE_OK);
+
+        WHEN("flushing it asynchronously") {
+            std::atomic<bool> flushed = false;
+            rr_recording_stream_flush_async(
+                stream,
+                [](void* user_data) { static_cast<std::atomic<bool>*>(user_data)->store(true); },
+                &flushed,
+                &error
+            );
+            REQUIRE(error.code == RR_ERROR_CODE_OK);
+
+            THEN("the callback is eventually invoked") {
+                const auto deadline =
+                    std::chrono::steady_clock::now() + std::chrono::seconds(10);
+                while (!flushed && std::chrono::steady_clock::now() < deadline) {
+                    std::this_thread::sleep_for(std::chrono::milliseconds(1));
+                }
+                CHECK(flushed);
+            }
+        }
+        WHEN("flushing it asynchronously without a callback") {
+            rr_recording_stream_flush_async(stream, nullptr, nullptr, &error);
+
+            THEN("an unexpected null argument error is reported") {
+                CHECK(error.code == RR_ERROR_CODE_UNEXPECTED_NULL_ARGUMENT);
+            }
+        }
+        WHEN("flushing it blocking") {
+            rr_recording_stream_flush_blocking(stream);
+
+            THEN("the stream is still valid afterwards") {
+                CHECK(rr_recording_stream_is_enabled(stream, &error));
+                CHECK(error.code == RR_ERROR_CODE_OK);
+            }
+        }
+
+        rr_recording_stream_free(stream);
+    }
+}
+
+SCENARIO("RecordingStream can connect with a memory limit via the C API", TEST_TAG) {
+    const auto blocking = GENERATE(false, true);
+
+    GIVEN("a new recording stream") {
+        rr_store_info store_info = {};
+        store_info.application_id = c_string("rerun_example_test");
+        store_info.recording_id = c_string(nullptr);
+        store_info.store_id = c_string(nullptr);
+        store_info.store_kind = RR_STORE_KIND_RECORDING;
+
+        rr_error error = {};
+        const auto stream = rr_recording_stream_new(&store_info, true, &error);
+        REQUIRE(error.code == RR_ERROR_CODE_OK);
+
+        AND_GIVEN("connect options with a tiny memory limit, blocking when full: " << blocking) {
+            rr_connect_options connect_opts = {};
+            connect_opts.flush_timeout_sec = 0.1f;
+            connect_opts.max_memory_bytes = 1;
+            connect_opts.blocking_when_full = blocking;
+
+            WHEN("connecting to a valid address") {
+                rr_recording_stream_connect_with_options(
+                    stream,
+                    c_string("127.0.0.1:9876"),
+                    &connect_opts,
+                    &error
+                );
+
+                THEN("no error is reported") {
+                    CHECK(error.code == RR_ERROR_CODE_OK);
+                }
+                THEN("logging more data than the limit neither fails nor blocks") {
+                    const auto batch =
+                        rerun::ComponentBatch::from_loggable(rerun::components::Position2D(1.0f, 2.0f))
+                            .value_or_throw();
+
+                    // Nothing is listening, so nothing ever gets sent and the limit is reached
+                    // right away. Only the background thread waits, not the logging calls.
+                    const auto start = std::chrono::steady_clock::now();
+                    for (int i = 0; i < 100; ++i) {
+                        rr_component_batch c_batch;
+                        REQUIRE(batch.to_c_ffi_struct(c_batch).is_ok());
+
+                        rr_data_row data_row;
+                        data_row.entity_path = c_string("points");
+                        data_row.num_component_batches = 1;
+                        data_row.component_batches = &c_batch;
+
+                        rr_recording_stream_log(stream, data_row, true, &error);
+                        REQUIRE(error.code == RR_ERROR_CODE_OK);
+                    }
+                    CHECK(std::chrono::steady_clock::now() - start < std::chrono::seconds(1));
+
+                    // Waiting for the full client gives up after the flush timeout.
+                    rr_recording_stream_flush_blocking(stream);
+                }
+            }
+            WHEN("connecting to an invalid address") {
+                rr_recording_stream_connect_with_options(
+                    stream,
+                    c_string("definitely not an address"),
+                    &connect_opts,
+                    &error
+                );
+
+                THEN("an invalid socket address error is reported") {
+                    CHECK(error.code == RR_ERROR_CODE_INVALID_SOCKET_ADDRESS);
+                }
+            }
+        }
+        WHEN("connecting with null options") {
+            rr_recording_stream_connect_with_options(
+                stream,
+                c_string("127.0.0.1:9876"),
+                nullptr,
+                &error
+            );
+
+            THEN("the defaults are used and no error is reported") {
+                CHECK(error.code == RR_ERROR_CODE_OK);
+            }
+        }
+
+        rr_recording_stream_free(stream);
+    }
+}
+
+SCENARIO("RecordingStream can log with an explicit time point via the C API", TEST_TAG) {
+    GIVEN("a new recording stream with a memory sink") {
+        rr_store_info store_info = {};
+        store_info.application_id = c_string("rerun_example_test");
+        store_info.recording_id = c_string(nullptr);
+        store_info.store_id = c_string(nullptr);
+        store_info.store_kind = RR_STORE_KIND_RECORDING;
+
+        rr_error error = {};
+        const auto stream = rr_recording_stream_new(&store_info, true, &error);
+        REQUIRE(error.code == RR_ERROR_CODE_OK);
+        rr_recording_stream_memory_sink(stream, &error);
+        REQUIRE(error.code == RR_ERROR_CODE_OK);
+
+        AND_GIVEN("a data row with a single component batch") {
+            const auto batch =
+                rerun::ComponentBatch::from_loggable(rerun::components::Position2D(1.0f, 2.0f))
+                    .value_or_throw();
+            rr_component_batch c_batch;
+            REQUIRE(batch.to_c_ffi_struct(c_batch).is_ok());
+
+            rr_data_row data_row;
+            data_row.entity_path = c_string("points");
+            data_row.num_component_batches = 1;
+            data_row.component_batches = &c_batch;
+
+            WHEN("logging it at a time point spanning two timelines") {
+                rr_time_point_entry entries[2];
+                entries[0].timeline.name = c_string("frame");
+                entries[0].timeline.type = RR_TIME_TYPE_SEQUENCE;
+                entries[0].time = 42;
+                entries[1].timeline.name = c_string("sim_time");
+                entries[1].timeline.type = RR_TIME_TYPE_TIME;
+                entries[1].time = 1000000000;
+
+                rr_recording_stream_log_with_time(stream, data_row, entries, 2, &error);
+
+                THEN("no error is reported") {
+                    CHECK(error.code == RR_ERROR_CODE_OK);
+                }
+                THEN("the data is logged at that time on both timelines") {
+                    using Catch::Matchers::ContainsSubstring;
+                    using Catch::Matchers::StartsWith;
+
+                    const auto description = drain_and_describe(stream);
+                    CHECK_THAT(description, StartsWith("/points "));
+                    CHECK_THAT(description, ContainsSubstring(" frame=[42] "));
+                    CHECK_THAT(description, ContainsSubstring(" sim_time=[1000000000] "));
+                }
+            }
+            WHEN("logging it with a null time point") {
+                rr_recording_stream_log_with_time(stream, data_row, nullptr, 1, &error);
+
+                THEN("an unexpected null argument error is reported") {
+                    CHECK(error.code == RR_ERROR_CODE_UNEXPECTED_NULL_ARGUMENT);
+                }
+            }
+        }
+
+        rr_recording_stream_free(stream);
+    }
+}
+
+SCENARIO("RecordingStream can set several timelines at once via the C API", TEST_TAG) {
+    GIVEN("a new recording stream with a memory sink") {
+        rr_store_info store_info = {};
+        store_info.application_id = c_string("rerun_example_test");
+        store_info.recording_id = c_string(nullptr);
+        store_info.store_id = c_string(nullptr);
+        store_info.store_kind = RR_STORE_KIND_RECORDING;
+
+        rr_error error = {};
+        const auto stream = rr_recording_stream_new(&store_info, true, &error);
+        REQUIRE(error.code == RR_ERROR_CODE_OK);
+        rr_recording_stream_memory_sink(stream, &error);
+        REQUIRE(error.code == RR_ERROR_CODE_OK);
+
+        rr_timeline timelines[2];
+        timelines[0].name = c_string("frame");
+        timelines[0].type = RR_TIME_TYPE_SEQUENCE;
+        timelines[1].name = c_string("sim_time");
+        timelines[1].type = RR_TIME_TYPE_TIME;
+        const int64_t values[2] = {42, 1000000000};
+
+        WHEN("setting the time on two timelines in one call") {
+            rr_recording_stream_set_time_point(stream, timelines, values, 2, &error);
+
+            THEN("no error is reported") {
+                CHECK(error.code == RR_ERROR_CODE_OK);
+            }
+            AND_WHEN("logging data") {
+                const auto batch =
+                    rerun::ComponentBatch::from_loggable(rerun::components::Position2D(1.0f, 2.0f))
+                        .value_or_throw();
+                rr_component_batch c_batch;
+                REQUIRE(batch.to_c_ffi_struct(c_batch).is_ok());
+
+                rr_data_row data_row;
+                data_row.entity_path = c_string("points");
+                data_row.num_component_batches = 1;
+                data_row.component_batches = &c_batch;
+
+                rr_recording_stream_log(stream, data_row, true, &error);
+                REQUIRE(error.code == RR_ERROR_CODE_OK);
+
+                THEN("the data is logged at that time on both timelines") {
+                    using Catch::Matchers::ContainsSubstring;
+                    using Catch::Matchers::StartsWith;
+
+                    const auto description = drain_and_describe(stream);
+                    CHECK_THAT(description, StartsWith("/points "));
+                    CHECK_THAT(description, ContainsSubstring(" frame=[42] "));
+                    CHECK_THAT(description, ContainsSubstring(" sim_time=[1000000000] "));
+                }
+            }
+            AND_WHEN("disabling both timelines") {
+                rr_recording_stream_disable_timeline(stream, timelines[0].name, &error);
+                CHECK(error.code == RR_ERROR_CODE_OK);
+                rr_recording_stream_disable_timeline(stream, timelines[1].name, &error);
+
+                THEN("no error is reported") {
+                    CHECK(error.code == RR_ERROR_CODE_OK);
+                }
+            }
+        }
+        WHEN("one of the timelines has no name") {
+            timelines[1].name = c_string(nullptr);
+            rr_recording_stream_set_time_point(stream, timelines, values, 2, &error);
+
+            THEN("an unexpected null argument error is reported") {
+                CHECK(error.code == RR_ERROR_CODE_UNEXPECTED_NULL_ARGUMENT);
+            }
+        }
+        WHEN("passing null values") {
+            rr_recording_stream_set_time_point(stream, timelines, nullptr, 2, &error);
+
+            THEN("an unexpected null argument error is reported") {
+                CHECK(error.code == RR_ERROR_CODE_UNEXPECTED_NULL_ARGUMENT);
+            }
+        }
+
+        rr_recording_stream_free(stream);
+    }
+}
+
+SCENARIO("RecordingStream truncates long errors on a character boundary via the C API", TEST_TAG) {
+    GIVEN("a new recording stream") {
+        rr_store_info store_info = {};
+        store_info.application_id = c_string("rerun_example_test");
+        store_info.recording_id = c_string(nullptr);
+        store_info.store_id = c_string(nullptr);
+        store_info.store_kind = RR_STORE_KIND_RECORDING;
+
+        rr_error error = {};
+        const auto stream = rr_recording_stream_new(&store_info, true, &error);
+        REQUIRE(error.code == RR_ERROR_CODE_OK);
+
+        WHEN("saving to an invalid path of multi-byte characters, too long for an error") {
+            const std::string prefix = "does/not/exist/";
+            std::string path = prefix;
+            for (size_t i = 0; i < sizeof(error.description); ++i) {
+                path += "日本語";
+            }
+            rr_recording_stream_save(stream, c_string(path.c_str()), &error);
+
+            THEN("the error description is truncated on a character boundary") {
+                REQUIRE(error.code == RR_ERROR_CODE_RECORDING_STREAM_SAVE_FAILURE);
+
+                const auto length = strnlen(error.description, sizeof(error.description));
+                REQUIRE(length < sizeof(error.description));
+                const std::string description(error.description, length);
+
+                const auto path_start = description.find(prefix);
+                REQUIRE(path_start != std::string::npos);
+                const auto path_end = path_start + prefix.size();
+
+                // Each of `日本語` is 3 bytes, and only those that don't fit are dropped.
+                CHECK((length - path_end) % 3 == 0);
+                CHECK(length + 3 >= sizeof(error.description));
+                CHECK(path.compare(0, length - path_start, description, path_start) == 0);
+            }
+        }
+
+        rr_recording_stream_free(stream);
+    }
+}
+
+SCENARIO("RecordingStream can keep its data in memory via the C API", TEST_TAG) {
+    GIVEN("a new recording stream") {
+        rr_store_info store_info = {};
+        store_info.application_id = c_string("rerun_example_test");
+        store_info.recording_id = c_string(nullptr);
+        store_info.store_id = c_string(nullptr);
+        store_info.store_kind = RR_STORE_KIND_RECORDING;
+
+        rr_error error = {};
+        const auto stream = rr_recording_stream_new(&store_info, true, &error);
+        REQUIRE(error.code == RR_ERROR_CODE_OK);
+
+        THEN("there is nothing to drain before switching to a memory sink") {
+            rr_bytes bytes = {};
+            rr_recording_stream_drain_memory(stream, &bytes, &error);
+            CHECK(error.code == RR_ERROR_CODE_RECORDING_STREAM_MEMORY_SINK_FAILURE);
+            CHECK(bytes.bytes == nullptr);
+        }
+        WHEN("switching to a memory sink and logging some data") {
+            rr_recording_stream_memory_sink(stream, &error);
+            REQUIRE(error.code == RR_ERROR_CODE_OK);
+
+            const auto batch =
+                rerun::ComponentBatch::from_loggable(rerun::components::Position2D(1.0f, 2.0f))
+                    .value_or_throw();
+            rr_component_batch c_batch;
+            REQUIRE(batch.to_c_ffi_struct(c_batch).is_ok());
+
+            rr_data_row data_row;
+            data_row.entity_path = c_string("points");
+            data_row.num_component_batches = 1;
+            data_row.component_batches = &c_batch;
+            rr_recording_stream_log(stream, data_row, false, &error);
+            REQUIRE(error.code == RR_ERROR_CODE_OK);
+
+            THEN("the data can be drained, and only once") {
+                using Catch::Matchers::StartsWith;
+
+                CHECK_THAT(drain_and_describe(stream), StartsWith("/points "));
+                CHECK(drain_and_describe(stream).empty());
+            }
+            AND_WHEN("switching to another sink") {
+                const char* test_path = "build/test_output";
+                fs::create_directories(test_path);
+                const std::string test_rrd = std::string(test_path) + "/test-drain-memory.rrd";
+
+                rr_recording_stream_save(stream, c_string(test_rrd.c_str()), &error);
+                REQUIRE(error.code == RR_ERROR_CODE_OK);
+
+                THEN("there is nothing to drain anymore") {
+                    rr_bytes bytes = {};
+                    rr_recording_stream_drain_memory(stream, &bytes, &error);
+                    CHECK(error.code == RR_ERROR_CODE_RECORDING_STREAM_MEMORY_SINK_FAILURE);
+                    CHECK(bytes.bytes == nullptr);
+                }
+            }
+        }
+
+        rr_recording_stream_free(stream);
+    }
+}
+
 SCENARIO("RecordingStream can be used for logging archetypes and components", TEST_TAG) {
     for (auto kind : std::array{rerun::StoreKind::Recording, rerun::StoreKind::Blueprint}) {
         GIVEN("a store kind" << kind) {